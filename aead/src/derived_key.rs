@@ -0,0 +1,162 @@
+//! Per-message key derivation.
+
+use crate::{scope::SecretScope, AeadInPlace, Buffer, Error, Key, NewAead, Nonce, Tag};
+use core::{fmt, marker::PhantomData};
+
+/// Derive per-message keys for an underlying AEAD `A`.
+///
+/// Implementations hold a master key and derive a fresh key for each
+/// message from it and the message nonce, e.g. using a keyed PRF or a
+/// block cipher as in the key derivation of AES-GCM-SIV. Combine it with an
+/// AEAD using [`DerivedKeyAead`].
+///
+/// Derivation must be deterministic: decryption derives the key again from
+/// the same nonce.
+pub trait KeyDerivation<A: NewAead + AeadInPlace> {
+    /// Derive the key used to encrypt or decrypt the message with the given
+    /// nonce.
+    fn derive_key(&self, nonce: &Nonce<A::NonceSize>) -> Key<A>;
+}
+
+/// AEAD which encrypts each message with a key derived from a master key
+/// and the message nonce, using the underlying AEAD `A`.
+///
+/// Since each key is only used for messages with a single nonce, usage
+/// limits of the underlying AEAD (e.g. the number of messages which can
+/// safely be encrypted under one key) apply per nonce rather than to the
+/// master key.
+///
+/// Implements [`AeadInPlace`] (and therefore all other AEAD traits) with the
+/// same parameters as `A`. Derived keys are wiped after use if the `zeroize`
+/// feature is enabled.
+pub struct DerivedKeyAead<A, K> {
+    derivation: K,
+    aead: PhantomData<fn() -> A>,
+}
+
+impl<A, K> DerivedKeyAead<A, K>
+where
+    A: NewAead + AeadInPlace,
+    K: KeyDerivation<A>,
+{
+    /// Create AEAD using the given key derivation.
+    pub fn new(derivation: K) -> Self {
+        Self {
+            derivation,
+            aead: PhantomData,
+        }
+    }
+
+    /// Get reference to the key derivation.
+    pub fn derivation(&self) -> &K {
+        &self.derivation
+    }
+
+    /// Instantiate the underlying AEAD for the given nonce.
+    fn aead(&self, nonce: &Nonce<A::NonceSize>) -> A {
+        let mut key = self.derivation.derive_key(nonce);
+        let key = SecretScope::new(&mut key);
+        A::new(&key)
+    }
+}
+
+impl<A, K: Clone> Clone for DerivedKeyAead<A, K> {
+    fn clone(&self) -> Self {
+        Self {
+            derivation: self.derivation.clone(),
+            aead: PhantomData,
+        }
+    }
+}
+
+impl<A, K: fmt::Debug> fmt::Debug for DerivedKeyAead<A, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivedKeyAead")
+            .field("derivation", &self.derivation)
+            .finish()
+    }
+}
+
+impl<A, K> AeadInPlace for DerivedKeyAead<A, K>
+where
+    A: NewAead + AeadInPlace,
+    K: KeyDerivation<A>,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.aead(nonce)
+            .encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self::TagSize>, Error> {
+        self.aead(nonce)
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.aead(nonce)
+            .decrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self::TagSize>,
+    ) -> Result<(), Error> {
+        self.aead(nonce)
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::XorAead;
+
+    struct XorKdf(u8);
+
+    impl KeyDerivation<XorAead> for XorKdf {
+        fn derive_key(&self, nonce: &Nonce<crate::consts::U1>) -> Key<XorAead> {
+            [self.0 ^ nonce.as_slice()[0]].into()
+        }
+    }
+
+    #[test]
+    fn derived_key_aead() {
+        let aead = DerivedKeyAead::new(XorKdf(0x0f));
+        let (n1, n2) = (Nonce::new([1].into()), Nonce::new([2].into()));
+
+        let mut buf = [0u8; 4];
+        let tag = aead.encrypt_in_place_detached(&n1, b"", &mut buf).unwrap();
+        assert_eq!(buf, [0x0e; 4]);
+        assert!(aead
+            .decrypt_in_place_detached(&n2, b"", &mut buf, &tag)
+            .is_err());
+        aead.decrypt_in_place_detached(&n1, b"", &mut buf, &tag)
+            .unwrap();
+        assert_eq!(buf, [0; 4]);
+
+        aead.encrypt_in_place_detached(&n2, b"", &mut buf).unwrap();
+        assert_eq!(buf, [0x0d; 4]);
+    }
+}
@@ -0,0 +1,184 @@
+//! Deterministic AEADs which take no nonce.
+
+use crate::{consts::U0, AeadInPlace, Buffer, Error, Nonce, Tag};
+
+#[cfg(feature = "alloc")]
+use crate::{Aead, Payload};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Marker trait for AEADs which provide misuse-resistant authenticated
+/// encryption (MRAE), e.g. AES-SIV or AES-GCM-SIV.
+///
+/// Encrypting several messages with the same key and nonce (or, for
+/// [`DeterministicAead`]s, with no nonce at all) only reveals whether the
+/// messages and their associated data are identical.
+///
+/// Only implement this trait for algorithms which have been proven to be
+/// misuse resistant: [`DeterministicAead`] relies on it to decide whether an
+/// AEAD instance may be used to encrypt more than one message.
+pub trait MisuseResistant {}
+
+/// Deterministic AEAD which takes no nonce, e.g. a key wrapping scheme.
+///
+/// This trait is implemented for every [`AeadInPlace`] with a `NonceSize`
+/// of [`U0`], and provides methods which don't take a nonce argument.
+///
+/// Since encryption is deterministic, only algorithms which are
+/// [`MisuseResistant`] may encrypt more than one message under the same key:
+/// the `seal*` methods taking `&self` require that marker, while all other
+/// algorithms must use [`DeterministicAead::seal_once_in_place`], which
+/// consumes the AEAD instance.
+///
+/// This only stops a single instance from encrypting twice. Nothing prevents
+/// the same key from being used to instantiate the algorithm again, so
+/// making sure keys of algorithms which aren't [`MisuseResistant`] encrypt a
+/// single message remains the responsibility of the caller.
+///
+/// Calling [`AeadInPlace`] methods with an empty [`Nonce`] bypasses these
+/// checks, so code using nonce-less algorithms should go through this trait
+/// instead.
+pub trait DeterministicAead: AeadInPlace<NonceSize = U0> {
+    /// Encrypt the given buffer containing a plaintext message in-place.
+    ///
+    /// See [`AeadInPlace::encrypt_in_place`].
+    fn seal_in_place(&self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error>
+    where
+        Self: MisuseResistant,
+    {
+        self.encrypt_in_place(&Nonce::default(), associated_data, buffer)
+    }
+
+    /// Encrypt the data in-place, returning the authentication tag.
+    ///
+    /// See [`AeadInPlace::encrypt_in_place_detached`].
+    fn seal_in_place_detached(
+        &self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self::TagSize>, Error>
+    where
+        Self: MisuseResistant,
+    {
+        self.encrypt_in_place_detached(&Nonce::default(), associated_data, buffer)
+    }
+
+    /// Encrypt a single message in-place, consuming the AEAD instance so the
+    /// key can't be used to encrypt another message.
+    ///
+    /// Use this method with algorithms which aren't [`MisuseResistant`]. The
+    /// key must not be used to instantiate the algorithm for encryption
+    /// again.
+    fn seal_once_in_place(
+        self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        self.encrypt_in_place(&Nonce::default(), associated_data, buffer)
+    }
+
+    /// Decrypt the message in-place, returning an error in the event the
+    /// provided authentication tag does not match the given ciphertext.
+    ///
+    /// See [`AeadInPlace::decrypt_in_place`].
+    fn open_in_place(&self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<(), Error> {
+        self.decrypt_in_place(&Nonce::default(), associated_data, buffer)
+    }
+
+    /// Decrypt the message in-place, returning an error in the event the
+    /// provided authentication tag does not match the given ciphertext.
+    ///
+    /// See [`AeadInPlace::decrypt_in_place_detached`].
+    fn open_in_place_detached(
+        &self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self::TagSize>,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place_detached(&Nonce::default(), associated_data, buffer, tag)
+    }
+
+    /// Encrypt the given plaintext payload, and return the resulting
+    /// ciphertext as a vector of bytes.
+    ///
+    /// See [`Aead::encrypt`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn seal<'msg, 'aad>(&self, plaintext: impl Into<Payload<'msg, 'aad>>) -> Result<Vec<u8>, Error>
+    where
+        Self: MisuseResistant + Sized,
+    {
+        self.encrypt(&Nonce::default(), plaintext)
+    }
+
+    /// Decrypt the given ciphertext payload, and return the resulting
+    /// plaintext as a vector of bytes.
+    ///
+    /// See [`Aead::decrypt`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn open<'msg, 'aad>(&self, ciphertext: impl Into<Payload<'msg, 'aad>>) -> Result<Vec<u8>, Error>
+    where
+        Self: Sized,
+    {
+        self.decrypt(&Nonce::default(), ciphertext)
+    }
+}
+
+impl<A: AeadInPlace<NonceSize = U0>> DeterministicAead for A {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{consts::U4, test_helpers::XorAead, BoundedFrame};
+
+    /// Nonce-less variant of [`XorAead`]
+    type DetXorAead = XorAead<U0>;
+
+    #[test]
+    fn deterministic_aead() {
+        let aead = DetXorAead::with_key(0x0f);
+
+        let mut buf = [0u8; 4];
+        let tag = aead.seal_in_place_detached(b"", &mut buf).unwrap();
+        assert_eq!(buf, [0x0f; 4]);
+        assert!(aead
+            .open_in_place_detached(b"", &mut buf, &Tag::new([0].into()))
+            .is_err());
+        aead.open_in_place_detached(b"", &mut buf, &tag).unwrap();
+        assert_eq!(buf, [0; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn deterministic_aead_seal_once() {
+        let mut buf = alloc::vec![0u8; 4];
+        DetXorAead::with_key(0x0f)
+            .seal_once_in_place(b"", &mut buf)
+            .unwrap();
+        assert_eq!(buf, [0x0f; 5]);
+
+        let aead = DetXorAead::with_key(0x0f);
+        assert_eq!(aead.seal(&[0u8; 4][..]).unwrap(), buf);
+        assert_eq!(aead.open(&buf[..]).unwrap(), [0; 4]);
+    }
+
+    #[test]
+    fn deterministic_aead_frame() {
+        type Frame = BoundedFrame<DetXorAead, U4>;
+        let aead = DetXorAead::with_key(0x0f);
+
+        // Frames of nonce-less AEADs consist of the ciphertext and tag only
+        let mut frame = Frame::seal_deterministic(&aead, b"", &[0; 3]).unwrap();
+        assert_eq!(frame.as_bytes(), [0x0f; 4]);
+        assert!(Frame::seal_deterministic(&aead, b"", &[0; 5]).is_err());
+
+        let mut received = Frame::from_bytes(frame.as_bytes()).unwrap();
+        assert_eq!(received.open(&aead, b"").unwrap(), [0; 3]);
+        assert!(Frame::from_bytes(&[]).is_err());
+        assert_eq!(frame.open(&aead, b"").unwrap(), [0; 3]);
+    }
+}
@@ -1,6 +1,9 @@
 //! Development-related functionality
 pub use blobby;
 
+#[cfg(feature = "alloc")]
+use crate::{generic_array::typenum::Unsigned, Aead, Nonce, Payload};
+
 /// Define AEAD test
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
@@ -10,7 +13,7 @@ macro_rules! new_test {
         fn $name() {
             use aead::dev::blobby::Blob6Iterator;
             use aead::generic_array::typenum::Unsigned;
-            use aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+            use aead::{generic_array::GenericArray, Aead, NewAead, Nonce, Payload};
             use core::convert::TryInto;
 
             fn run_test(
@@ -23,7 +26,7 @@ macro_rules! new_test {
             ) -> Result<(), &'static str> {
                 let key = key.try_into().map_err(|_| "wrong key size")?;
                 let cipher = <$cipher>::new(key);
-                let nonce = nonce.try_into().map_err(|_| "wrong nonce size")?;
+                let nonce = &Nonce::from_slice(nonce).map_err(|_| "wrong nonce size")?;
 
                 if !pass {
                     let res = cipher.decrypt(nonce, Payload { aad: aad, msg: ct });
@@ -75,3 +78,105 @@ macro_rules! new_test {
         }
     };
 }
+
+/// Check encryption round-trip and tamper detection of an [`Aead`] for the
+/// provided inputs.
+///
+/// This function is intended to be called from fuzz targets and property
+/// tests with arbitrary nonces, associated data and plaintexts. It checks that:
+///
+/// - decryption of the encrypted message returns the original plaintext;
+/// - flipping any bit of the ciphertext (including authentication tag) or
+///   associated data causes decryption to fail;
+/// - truncating ciphertext to any shorter length or extending it by a byte
+///   causes decryption to fail.
+///
+/// Returns a description of the first failed check.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn check_roundtrip_and_tamper<A: Aead>(
+    cipher: &A,
+    nonce: &Nonce<A::NonceSize>,
+    aad: &[u8],
+    pt: &[u8],
+) -> Result<(), &'static str> {
+    let ct = cipher
+        .encrypt(nonce, Payload { msg: pt, aad })
+        .map_err(|_| "encryption failure")?;
+    if ct.len() < pt.len() + A::TagSize::to_usize() {
+        return Err("ciphertext is too short to contain authentication tag");
+    }
+
+    let res = cipher
+        .decrypt(nonce, Payload { msg: &ct, aad })
+        .map_err(|_| "decryption failure")?;
+    if res != pt {
+        return Err("decrypted data is different from original plaintext");
+    }
+
+    let mut tampered = ct.clone();
+    for i in 0..tampered.len() {
+        for bit in 0..8 {
+            tampered[i] ^= 1 << bit;
+            let res = cipher.decrypt(
+                nonce,
+                Payload {
+                    msg: &tampered,
+                    aad,
+                },
+            );
+            tampered[i] ^= 1 << bit;
+            if res.is_ok() {
+                return Err("decryption of tampered ciphertext must return error");
+            }
+        }
+    }
+
+    let mut tampered = aad.to_vec();
+    for i in 0..tampered.len() {
+        for bit in 0..8 {
+            tampered[i] ^= 1 << bit;
+            let res = cipher.decrypt(
+                nonce,
+                Payload {
+                    msg: &ct,
+                    aad: &tampered,
+                },
+            );
+            tampered[i] ^= 1 << bit;
+            if res.is_ok() {
+                return Err("decryption with tampered associated data must return error");
+            }
+        }
+    }
+
+    for len in 0..ct.len() {
+        let res = cipher.decrypt(
+            nonce,
+            Payload {
+                msg: &ct[..len],
+                aad,
+            },
+        );
+        if res.is_ok() {
+            return Err("decryption of truncated ciphertext must return error");
+        }
+    }
+
+    let mut extended = ct;
+    extended.push(0);
+    if cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: &extended,
+                aad,
+            },
+        )
+        .is_ok()
+    {
+        return Err("decryption of extended ciphertext must return error");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,345 @@
+//! Fixed-capacity sealed frames for `no_std` environments.
+
+use crate::{consts::U0, scope::SecretScope, AeadInPlace, Buffer, Error, MisuseResistant, Nonce};
+use core::{fmt, ops::Add};
+use generic_array::{
+    typenum::{Sum, Unsigned},
+    ArrayLength, GenericArray,
+};
+
+/// Total size of a [`BoundedFrame`] holding at most `N` bytes of plaintext
+/// encrypted with the AEAD `A`, including its `CiphertextOverhead`.
+pub type FrameSize<A, N> = Sum<
+    Sum<Sum<<A as AeadInPlace>::NonceSize, N>, <A as AeadInPlace>::CiphertextOverhead>,
+    <A as AeadInPlace>::TagSize,
+>;
+
+/// Sealed frame consisting of the nonce, the ciphertext and the
+/// authentication tag, i.e. `nonce || ciphertext || tag`.
+///
+/// For AEADs with a `NonceSize` of `U0` the frame is `ciphertext || tag`.
+/// The ciphertext may be longer than the plaintext by up to the AEAD's
+/// `CiphertextOverhead`, which the frame capacity accounts for.
+///
+/// The frame is stored inline in a buffer sized at compile time to fit at
+/// most `N` bytes of plaintext, so it can be used without `alloc`. With the
+/// `zeroize` feature enabled, the buffer is wiped when the frame is dropped.
+pub struct BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    bytes: GenericArray<u8, FrameSize<A, N>>,
+    len: usize,
+}
+
+impl<A, N> BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    N: Unsigned,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    /// Encrypt the given plaintext into a new frame.
+    ///
+    /// Returns an error if the plaintext is longer than `N`.
+    pub fn seal(
+        aead: &A,
+        nonce: &Nonce<A::NonceSize>,
+        associated_data: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Self, Error> {
+        if plaintext.len() > N::to_usize() {
+            return Err(Error);
+        }
+
+        let nonce_len = A::NonceSize::to_usize();
+        let mut frame = Self {
+            bytes: GenericArray::default(),
+            len: 0,
+        };
+        frame.bytes[..nonce_len].copy_from_slice(nonce.as_slice());
+
+        // If encryption fails, dropping the frame wipes the plaintext when
+        // the `zeroize` feature is enabled
+        let (_, msg) = frame.bytes.split_at_mut(nonce_len);
+        msg[..plaintext.len()].copy_from_slice(plaintext);
+        let mut buffer = FrameBuffer {
+            bytes: msg,
+            len: plaintext.len(),
+        };
+        aead.encrypt_in_place(nonce, associated_data, &mut buffer)?;
+
+        frame.len = nonce_len + buffer.len;
+        Ok(frame)
+    }
+
+    /// Parse a frame received from the wire.
+    ///
+    /// Returns an error if the frame is too short to contain a nonce and a
+    /// tag, or if it is longer than [`FrameSize`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let overhead = A::NonceSize::to_usize() + A::TagSize::to_usize();
+
+        if bytes.len() < overhead || bytes.len() > FrameSize::<A, N>::to_usize() {
+            return Err(Error);
+        }
+
+        let mut frame = Self {
+            bytes: GenericArray::default(),
+            len: bytes.len(),
+        };
+        frame.bytes[..bytes.len()].copy_from_slice(bytes);
+        Ok(frame)
+    }
+
+    /// Get the encoded frame.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Get the nonce of this frame.
+    pub fn nonce(&self) -> Nonce<A::NonceSize> {
+        Nonce::new(GenericArray::clone_from_slice(
+            &self.bytes[..A::NonceSize::to_usize()],
+        ))
+    }
+
+    /// Decrypt the frame in-place, returning the plaintext on success.
+    ///
+    /// The ciphertext is overwritten with the plaintext, so on success the
+    /// frame can no longer be decrypted again, and [`BoundedFrame::as_bytes`]
+    /// returns the nonce followed by the plaintext. On failure the message
+    /// part of the frame may hold partially decrypted data, so it is wiped
+    /// when the `zeroize` feature is enabled.
+    pub fn open(&mut self, aead: &A, associated_data: &[u8]) -> Result<&[u8], Error> {
+        let nonce_len = A::NonceSize::to_usize();
+
+        let (nonce, msg) = self.bytes.split_at_mut(nonce_len);
+        let mut buffer = FrameBuffer {
+            bytes: msg,
+            len: self.len - nonce_len,
+        };
+        let mut buffer = SecretScope::new(&mut buffer);
+        aead.decrypt_in_place(&Nonce::from_slice(nonce)?, associated_data, &mut *buffer)?;
+        let msg_len = buffer.keep().len;
+
+        // Drop the tag and any overhead which follow the plaintext
+        self.len = nonce_len + msg_len;
+        Ok(&self.bytes[nonce_len..self.len])
+    }
+}
+
+impl<A, N> BoundedFrame<A, N>
+where
+    A: AeadInPlace<NonceSize = U0> + MisuseResistant,
+    N: Unsigned,
+    U0: Add<N>,
+    Sum<U0, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<U0, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    /// Encrypt the given plaintext into a new frame using a nonce-less
+    /// [`DeterministicAead`][crate::DeterministicAead].
+    ///
+    /// The frame consists of the ciphertext and the tag only.
+    pub fn seal_deterministic(
+        aead: &A,
+        associated_data: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Self, Error> {
+        Self::seal(aead, &Nonce::default(), associated_data, plaintext)
+    }
+}
+
+impl<A, N> Clone for BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            len: self.len,
+        }
+    }
+}
+
+/// Opened frames hold plaintext, so frames are wiped when dropped if the
+/// `zeroize` feature is enabled.
+#[cfg(feature = "zeroize")]
+impl<A, N> Drop for BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    fn drop(&mut self) {
+        SecretScope::wipe(&mut self.bytes);
+    }
+}
+
+impl<A, N> AsRef<[u8]> for BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    N: Unsigned,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Frames may hold plaintext once opened, so only their length is shown.
+impl<A, N> fmt::Debug for BoundedFrame<A, N>
+where
+    A: AeadInPlace,
+    A::NonceSize: Add<N>,
+    Sum<A::NonceSize, N>: Add<A::CiphertextOverhead>,
+    Sum<Sum<A::NonceSize, N>, A::CiphertextOverhead>: Add<A::TagSize>,
+    FrameSize<A, N>: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedFrame")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// [`Buffer`] over the message part of a frame, i.e. everything after the
+/// nonce, which can grow up to the frame capacity.
+struct FrameBuffer<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl AsRef<[u8]> for FrameBuffer<'_> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl AsMut<[u8]> for FrameBuffer<'_> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+}
+
+impl Buffer for FrameBuffer<'_> {
+    fn extend_from_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        let end = self.len + other.len();
+        if end > self.bytes.len() {
+            return Err(Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(other);
+        self.len = end;
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        consts::{U1, U2, U4},
+        test_helpers::{XorAead, PADDING},
+    };
+
+    type Frame = BoundedFrame<XorAead, U4>;
+
+    #[test]
+    fn frame_round_trip() {
+        let aead = XorAead::with_key(0x0f);
+        let nonce = Nonce::new([1].into());
+
+        let frame = Frame::seal(&aead, &nonce, b"", b"abc").unwrap();
+        assert_eq!(
+            frame.as_bytes(),
+            [1, b'a' ^ 0x0f, b'b' ^ 0x0f, b'c' ^ 0x0f, 0x0f]
+        );
+        assert_eq!(frame.as_ref(), frame.as_bytes());
+        assert_eq!(frame.nonce(), nonce);
+
+        let mut received = Frame::from_bytes(frame.as_bytes()).unwrap();
+        assert_eq!(received.open(&aead, b"").unwrap(), b"abc");
+        assert_eq!(received.as_bytes(), b"\x01abc");
+        assert!(received.open(&aead, b"").is_err());
+
+        let mut empty = Frame::seal(&aead, &nonce, b"", b"").unwrap();
+        assert_eq!(empty.as_bytes(), [1, 0x0f]);
+        assert_eq!(empty.clone().open(&aead, b"").unwrap(), b"");
+        assert!(empty.open(&XorAead::with_key(0), b"").is_err());
+    }
+
+    #[test]
+    fn frame_bounds() {
+        let aead = XorAead::with_key(0x0f);
+        let nonce = Nonce::new([1].into());
+
+        assert!(Frame::seal(&aead, &nonce, b"", &[0; 4]).is_ok());
+        assert!(Frame::seal(&aead, &nonce, b"", &[0; 5]).is_err());
+
+        // nonce and tag are required, and at most 4 bytes of ciphertext
+        assert!(Frame::from_bytes(&[1]).is_err());
+        assert!(Frame::from_bytes(&[1, 0x0f]).is_ok());
+        assert!(Frame::from_bytes(&[0; 6]).is_ok());
+        assert!(Frame::from_bytes(&[0; 7]).is_err());
+    }
+
+    #[test]
+    fn frame_ciphertext_overhead() {
+        type PaddedFrame = BoundedFrame<XorAead<U1, U2>, U4>;
+        let aead = XorAead::with_key(0x0f);
+        let nonce = Nonce::new([1].into());
+
+        // nonce, 4 bytes of ciphertext, 2 bytes of padding and the tag
+        let frame = PaddedFrame::seal(&aead, &nonce, b"", b"abcd").unwrap();
+        assert_eq!(frame.as_bytes().len(), 8);
+        assert_eq!(frame.as_bytes()[5..], [PADDING, PADDING, 0x0f]);
+
+        let mut received = PaddedFrame::from_bytes(frame.as_bytes()).unwrap();
+        assert_eq!(received.open(&aead, b"").unwrap(), b"abcd");
+        assert_eq!(received.as_bytes(), b"\x01abcd");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn frame_debug_hides_contents() {
+        let nonce = Nonce::new([1].into());
+        let frame = Frame::seal(&XorAead::with_key(0x0f), &nonce, b"", b"abc").unwrap();
+        assert_eq!(alloc::format!("{:?}", frame), "BoundedFrame { len: 5 }");
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn frame_wiped_on_failed_open() {
+        let aead = XorAead::with_key(0x0f).leaky();
+        let nonce = Nonce::new([1].into());
+        let frame = Frame::seal(&aead, &nonce, b"", b"abc").unwrap();
+
+        let mut bytes = [0u8; 5];
+        bytes.copy_from_slice(frame.as_bytes());
+        bytes[4] ^= 1;
+
+        let mut received = Frame::from_bytes(&bytes).unwrap();
+        assert!(received.open(&aead, b"").is_err());
+        assert_eq!(received.as_bytes(), [1, 0, 0, 0, 0]);
+    }
+}
@@ -32,14 +32,39 @@ extern crate std;
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 pub mod dev;
 
+mod derived_key;
+mod deterministic;
+mod frame;
+mod nonce;
+mod scope;
+mod sizes;
+
+#[cfg(test)]
+mod test_helpers;
+
+pub use crate::derived_key::{DerivedKeyAead, KeyDerivation};
+pub use crate::deterministic::{DeterministicAead, MisuseResistant};
+pub use crate::frame::{BoundedFrame, FrameSize};
+pub use crate::nonce::{
+    CounterNonce, DerivedNonceAead, GeneratedNonceAead, NonceDerivation, NonceGenerator,
+};
 pub use generic_array::{self, typenum::consts};
 
 #[cfg(feature = "heapless")]
 pub use heapless;
 
+#[cfg(feature = "rand_core")]
+pub use crate::nonce::RandomNonce;
+#[cfg(feature = "rand_core")]
+pub use rand_core;
+#[cfg(feature = "zeroize")]
+pub use zeroize;
+
 use core::fmt;
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 
+#[cfg(feature = "alloc")]
+use crate::scope::SecretScope;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -64,10 +89,124 @@ impl std::error::Error for Error {}
 pub type Key<A> = GenericArray<u8, <A as NewAead>::KeySize>;
 
 /// Nonce: single-use value for ensuring ciphertexts are unique
-pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
+///
+/// This is a distinct type from [`Tag`] (rather than a bare [`GenericArray`])
+/// so nonce and tag arguments can't be swapped by accident. It can only be
+/// constructed explicitly, using [`Nonce::new`], [`Nonce::from_slice`], or
+/// the [`From`] impls for `[u8; N]` arrays of matching size (for sizes up to
+/// 32 bytes).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Nonce<NonceSize: ArrayLength<u8>>(GenericArray<u8, NonceSize>);
 
 /// Tag: authentication code which ensures ciphertexts are authentic
-pub type Tag<TagSize> = GenericArray<u8, TagSize>;
+///
+/// Like [`Nonce`], tags can only be constructed explicitly, using
+/// [`Tag::new`], [`Tag::from_slice`], or from `[u8; N]` arrays. Tags are
+/// compared in constant time.
+#[derive(Clone, Debug, Default)]
+pub struct Tag<TagSize: ArrayLength<u8>>(GenericArray<u8, TagSize>);
+
+impl<TagSize: ArrayLength<u8>> PartialEq for Tag<TagSize> {
+    fn eq(&self, other: &Self) -> bool {
+        let diff = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+        diff == 0
+    }
+}
+
+impl<TagSize: ArrayLength<u8>> Eq for Tag<TagSize> {}
+
+/// Implement constructors and accessors for byte array newtypes.
+macro_rules! impl_byte_array {
+    ($name:ident, $size:ident) => {
+        impl<$size: ArrayLength<u8>> $name<$size> {
+            /// Create from a byte array.
+            pub fn new(bytes: GenericArray<u8, $size>) -> Self {
+                Self(bytes)
+            }
+
+            /// Create from a byte slice.
+            ///
+            /// Returns an error if the slice has an incorrect length.
+            pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+                if bytes.len() == $size::to_usize() {
+                    Ok(Self(GenericArray::clone_from_slice(bytes)))
+                } else {
+                    Err(Error)
+                }
+            }
+
+            /// Borrow the inner byte array.
+            pub fn as_array(&self) -> &GenericArray<u8, $size> {
+                &self.0
+            }
+
+            /// Borrow the inner byte array mutably.
+            pub fn as_mut_array(&mut self) -> &mut GenericArray<u8, $size> {
+                &mut self.0
+            }
+
+            /// Borrow the bytes as a slice.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Convert into the inner byte array.
+            pub fn into_array(self) -> GenericArray<u8, $size> {
+                self.0
+            }
+        }
+
+        impl<$size: ArrayLength<u8>> AsRef<[u8]> for $name<$size> {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+    };
+}
+
+impl_byte_array!(Nonce, NonceSize);
+impl_byte_array!(Tag, TagSize);
+
+/// Implement conversions between byte array newtypes and `[u8; N]` arrays,
+/// for interop with APIs based on const generics.
+macro_rules! impl_array_conversions {
+    ($($n:expr => $size:ident),+) => {
+        $(
+            impl_array_conversions!(@impl Nonce, $n, $size);
+            impl_array_conversions!(@impl Tag, $n, $size);
+        )+
+    };
+    (@impl $name:ident, $n:expr, $size:ident) => {
+        impl From<[u8; $n]> for $name<consts::$size> {
+            fn from(bytes: [u8; $n]) -> Self {
+                Self(bytes.into())
+            }
+        }
+
+        impl From<&[u8; $n]> for $name<consts::$size> {
+            fn from(bytes: &[u8; $n]) -> Self {
+                Self((*bytes).into())
+            }
+        }
+
+        impl From<$name<consts::$size>> for [u8; $n] {
+            fn from(bytes: $name<consts::$size>) -> [u8; $n] {
+                bytes.0.into()
+            }
+        }
+    };
+}
+
+impl_array_conversions! {
+    1 => U1, 2 => U2, 3 => U3, 4 => U4, 5 => U5, 6 => U6, 7 => U7, 8 => U8,
+    9 => U9, 10 => U10, 11 => U11, 12 => U12, 13 => U13, 14 => U14, 15 => U15, 16 => U16,
+    17 => U17, 18 => U18, 19 => U19, 20 => U20, 21 => U21, 22 => U22, 23 => U23, 24 => U24,
+    25 => U25, 26 => U26, 27 => U27, 28 => U28, 29 => U29, 30 => U30, 31 => U31, 32 => U32
+}
 
 /// Instantiate either a stateless [`Aead`] or stateful [`AeadMut`] algorithm.
 pub trait NewAead {
@@ -160,6 +299,30 @@ pub trait Aead {
         nonce: &Nonce<Self::NonceSize>,
         ciphertext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>, Error>;
+
+    /// Encrypt the given plaintext payload using a nonce of any type which
+    /// converts into [`Nonce`], e.g. a `[u8; 12]` array.
+    ///
+    /// See [`Aead::encrypt()`].
+    fn encrypt_with<'msg, 'aad>(
+        &self,
+        nonce: impl Into<Nonce<Self::NonceSize>>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.encrypt(&nonce.into(), plaintext)
+    }
+
+    /// Decrypt the given ciphertext slice using a nonce of any type which
+    /// converts into [`Nonce`], e.g. a `[u8; 12]` array.
+    ///
+    /// See [`Aead::decrypt()`].
+    fn decrypt_with<'msg, 'aad>(
+        &self,
+        nonce: impl Into<Nonce<Self::NonceSize>>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.decrypt(&nonce.into(), ciphertext)
+    }
 }
 
 /// Stateful Authenticated Encryption with Associated Data algorithm.
@@ -212,7 +375,7 @@ macro_rules! impl_decrypt_in_place {
 
         let tag_pos = $buffer.len() - Self::TagSize::to_usize();
         let (msg, tag) = $buffer.as_mut().split_at_mut(tag_pos);
-        $aead.decrypt_in_place_detached($nonce, $aad, msg, Tag::from_slice(tag))?;
+        $aead.decrypt_in_place_detached($nonce, $aad, msg, &Tag::from_slice(tag)?)?;
         $buffer.truncate(tag_pos);
         Ok(())
     }};
@@ -284,6 +447,38 @@ pub trait AeadInPlace {
         buffer: &mut [u8],
         tag: &Tag<Self::TagSize>,
     ) -> Result<(), Error>;
+
+    /// Encrypt the given buffer in-place using a nonce of any type which
+    /// converts into [`Nonce`], e.g. a `[u8; 12]` array.
+    ///
+    /// See [`AeadInPlace::encrypt_in_place()`].
+    fn encrypt_in_place_with(
+        &self,
+        nonce: impl Into<Nonce<Self::NonceSize>>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        self.encrypt_in_place(&nonce.into(), associated_data, buffer)
+    }
+
+    /// Decrypt the given buffer in-place using a nonce of any type which
+    /// converts into [`Nonce`], e.g. a `[u8; 12]` array.
+    ///
+    /// See [`AeadInPlace::decrypt_in_place()`].
+    fn decrypt_in_place_with(
+        &self,
+        nonce: impl Into<Nonce<Self::NonceSize>>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        self.decrypt_in_place(&nonce.into(), associated_data, buffer)
+    }
 }
 
 /// In-place stateful AEAD trait.
@@ -366,10 +561,10 @@ impl<Alg: AeadInPlace> Aead for Alg {
         plaintext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>, Error> {
         let payload = plaintext.into();
-        let mut buffer = Vec::with_capacity(payload.msg.len() + Self::TagSize::to_usize());
+        let mut buffer = Vec::with_capacity(encrypt_capacity::<Self>(payload.msg.len()));
         buffer.extend_from_slice(payload.msg);
-        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
-        Ok(buffer)
+        let res = self.encrypt_in_place(nonce, payload.aad, &mut buffer);
+        wipe_on_error(buffer, res)
     }
 
     fn decrypt<'msg, 'aad>(
@@ -379,8 +574,8 @@ impl<Alg: AeadInPlace> Aead for Alg {
     ) -> Result<Vec<u8>, Error> {
         let payload = ciphertext.into();
         let mut buffer = Vec::from(payload.msg);
-        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
-        Ok(buffer)
+        let res = self.decrypt_in_place(nonce, payload.aad, &mut buffer);
+        wipe_on_error(buffer, res)
     }
 }
 
@@ -396,10 +591,10 @@ impl<Alg: AeadMutInPlace> AeadMut for Alg {
         plaintext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>, Error> {
         let payload = plaintext.into();
-        let mut buffer = Vec::with_capacity(payload.msg.len() + Self::TagSize::to_usize());
+        let mut buffer = Vec::with_capacity(encrypt_capacity::<Self>(payload.msg.len()));
         buffer.extend_from_slice(payload.msg);
-        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
-        Ok(buffer)
+        let res = self.encrypt_in_place(nonce, payload.aad, &mut buffer);
+        wipe_on_error(buffer, res)
     }
 
     fn decrypt<'msg, 'aad>(
@@ -409,8 +604,8 @@ impl<Alg: AeadMutInPlace> AeadMut for Alg {
     ) -> Result<Vec<u8>, Error> {
         let payload = ciphertext.into();
         let mut buffer = Vec::from(payload.msg);
-        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
-        Ok(buffer)
+        let res = self.decrypt_in_place(nonce, payload.aad, &mut buffer);
+        wipe_on_error(buffer, res)
     }
 }
 
@@ -457,6 +652,25 @@ impl<Alg: AeadInPlace> AeadMutInPlace for Alg {
     }
 }
 
+/// Capacity of a buffer which fits the ciphertext for a plaintext of
+/// `msg_len` bytes without reallocation, so no copies of the plaintext are
+/// left behind in freed memory.
+#[cfg(feature = "alloc")]
+fn encrypt_capacity<A: AeadMutInPlace>(msg_len: usize) -> usize {
+    msg_len + A::TagSize::to_usize() + A::CiphertextOverhead::to_usize()
+}
+
+/// Return the buffer on success. On failure, the buffer may contain
+/// plaintext (or partially decrypted ciphertext), so it is wiped when the
+/// `zeroize` feature is enabled.
+#[cfg(feature = "alloc")]
+fn wipe_on_error(mut buffer: Vec<u8>, res: Result<(), Error>) -> Result<Vec<u8>, Error> {
+    let scratch = SecretScope::new(&mut buffer);
+    res?;
+    scratch.keep();
+    Ok(buffer)
+}
+
 /// AEAD payloads are a combination of a message (plaintext or ciphertext)
 /// and "additional associated data" (AAD) to be authenticated (in cleartext)
 /// along with the message.
@@ -534,6 +748,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::XorAead;
 
     /// Ensure that `AeadInPlace` is object-safe
     #[allow(dead_code)]
@@ -544,4 +759,49 @@ mod tests {
     #[allow(dead_code)]
     type DynAeadMutInPlace<N, T, O> =
         dyn AeadMutInPlace<NonceSize = N, TagSize = T, CiphertextOverhead = O>;
+
+    assert_key_size!(XorAead, 1);
+    assert_nonce_size!(XorAead, 1);
+    assert_tag_size!(XorAead, 1);
+
+    #[cfg(all(feature = "alloc", feature = "zeroize"))]
+    #[test]
+    fn wipe_on_error_keeps_buffer_on_success() {
+        let buffer = alloc::vec![1u8; 4];
+        assert_eq!(wipe_on_error(buffer.clone(), Ok(())).unwrap(), buffer);
+        assert!(wipe_on_error(buffer, Err(Error)).is_err());
+    }
+
+    #[test]
+    fn array_conversions() {
+        let nonce = Nonce::<consts::U12>::from([7; 12]);
+        assert_eq!(<[u8; 12]>::from(nonce.clone()), [7; 12]);
+        assert_eq!(Nonce::from(&[7; 12]), nonce);
+
+        let tag = Tag::<consts::U16>::from([9; 16]);
+        assert_eq!(<[u8; 16]>::from(tag), [9; 16]);
+    }
+
+    #[test]
+    fn tag_eq() {
+        let tag = Tag::<consts::U4>::from([1, 2, 3, 4]);
+        assert_eq!(tag, Tag::from([1, 2, 3, 4]));
+        assert_ne!(tag, Tag::from([1, 2, 3, 5]));
+        assert_ne!(tag, Tag::from([0, 2, 3, 4]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encrypt_with_array_nonce() {
+        let aead: XorAead = XorAead::with_key(0x0f);
+
+        let mut buf = alloc::vec![0u8; 4];
+        aead.encrypt_in_place_with([1], b"", &mut buf).unwrap();
+        assert_eq!(buf, [0x0f; 5]);
+        aead.decrypt_in_place_with([1], b"", &mut buf).unwrap();
+        assert_eq!(buf, [0; 4]);
+
+        let ciphertext = aead.encrypt_with([1], &[0u8; 4][..]).unwrap();
+        assert_eq!(aead.decrypt_with([1], &ciphertext[..]).unwrap(), [0; 4]);
+    }
 }
@@ -0,0 +1,367 @@
+//! Pluggable nonce derivation and generation strategies.
+
+use crate::{scope::SecretScope, AeadInPlace, Buffer, Error, Nonce, Tag};
+use core::ops::Add;
+use generic_array::{
+    sequence::Concat,
+    typenum::{Sum, Unsigned},
+    ArrayLength,
+};
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+/// Derive nonces for an underlying AEAD from caller-provided nonces.
+///
+/// This is the hook used by schemes which conceal or synthesize the nonce
+/// actually passed to the underlying AEAD, e.g. by applying a keyed PRF to
+/// the caller-provided nonce, the associated data and the plaintext as done
+/// by SIV-style schemes. Combine it with an [`AeadInPlace`] implementation
+/// using [`DerivedNonceAead`].
+///
+/// Derivation must be deterministic: decryption derives the nonce again
+/// from the decrypted plaintext and rejects the message unless it matches
+/// the derived nonce used for encryption.
+pub trait NonceDerivation {
+    /// Size of nonces provided by the caller.
+    type NonceSize: ArrayLength<u8>;
+
+    /// Size of nonces passed to the underlying AEAD.
+    type DerivedNonceSize: ArrayLength<u8>;
+
+    /// Derive the nonce for the underlying AEAD used to encrypt `plaintext`.
+    fn derive_nonce(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        plaintext: &[u8],
+    ) -> Nonce<Self::DerivedNonceSize>;
+}
+
+/// AEAD which passes nonces through a [`NonceDerivation`] before using the
+/// underlying AEAD `A`.
+///
+/// Implements [`AeadInPlace`] (and therefore all other AEAD traits) with
+/// `NonceSize` of the derivation strategy. Since the recipient can't derive
+/// a nonce from a plaintext it doesn't have yet, the derived nonce is
+/// transmitted as the first part of the tag, followed by the tag of the
+/// underlying AEAD. After decrypting, the nonce is derived again from the
+/// plaintext and compared with the transmitted one, as in [RFC 5297]. On
+/// mismatch an error is returned, and the buffer is wiped if the `zeroize`
+/// feature is enabled.
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+#[derive(Clone, Debug)]
+pub struct DerivedNonceAead<A, D> {
+    aead: A,
+    derivation: D,
+}
+
+impl<A, D> DerivedNonceAead<A, D>
+where
+    A: AeadInPlace,
+    D: NonceDerivation<DerivedNonceSize = A::NonceSize>,
+{
+    /// Combine the given AEAD with a nonce derivation strategy.
+    pub fn new(aead: A, derivation: D) -> Self {
+        Self { aead, derivation }
+    }
+
+    /// Get reference to the underlying AEAD.
+    pub fn aead(&self) -> &A {
+        &self.aead
+    }
+
+    /// Get reference to the nonce derivation strategy.
+    pub fn derivation(&self) -> &D {
+        &self.derivation
+    }
+}
+
+impl<A, D> AeadInPlace for DerivedNonceAead<A, D>
+where
+    A: AeadInPlace,
+    D: NonceDerivation<DerivedNonceSize = A::NonceSize>,
+    A::NonceSize: Add<A::TagSize>,
+    Sum<A::NonceSize, A::TagSize>: ArrayLength<u8>,
+{
+    type NonceSize = D::NonceSize;
+    type TagSize = Sum<A::NonceSize, A::TagSize>;
+    type CiphertextOverhead = A::CiphertextOverhead;
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self::TagSize>, Error> {
+        let derived = self.derivation.derive_nonce(nonce, associated_data, buffer);
+        let tag = self
+            .aead
+            .encrypt_in_place_detached(&derived, associated_data, buffer)?;
+        Ok(Tag::new(derived.into_array().concat(tag.into_array())))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self::TagSize>,
+    ) -> Result<(), Error> {
+        let (derived, tag) = tag.as_slice().split_at(A::NonceSize::to_usize());
+        let derived = Nonce::from_slice(derived)?;
+        self.aead.decrypt_in_place_detached(
+            &derived,
+            associated_data,
+            buffer,
+            &Tag::from_slice(tag)?,
+        )?;
+
+        let buffer = SecretScope::new(buffer);
+        let expected = self
+            .derivation
+            .derive_nonce(nonce, associated_data, &buffer);
+        let diff = expected
+            .as_slice()
+            .iter()
+            .zip(derived.as_slice())
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+        if diff != 0 {
+            return Err(Error);
+        }
+
+        buffer.keep();
+        Ok(())
+    }
+}
+
+/// Source of fresh nonces for encryption.
+///
+/// Implementations must never return the same nonce twice. Combine with an
+/// [`AeadInPlace`] implementation using [`GeneratedNonceAead`].
+pub trait NonceGenerator {
+    /// Size of generated nonces.
+    type NonceSize: ArrayLength<u8>;
+
+    /// Generate a fresh nonce.
+    ///
+    /// Returns an error if no more nonces can be generated, e.g. if a
+    /// counter is exhausted.
+    fn generate_nonce(&mut self) -> Result<Nonce<Self::NonceSize>, Error>;
+}
+
+/// Nonce generator which returns consecutive values of a big-endian counter.
+///
+/// Returns an error once the counter wraps around.
+#[derive(Clone, Debug)]
+pub struct CounterNonce<N: ArrayLength<u8>> {
+    next: Option<Nonce<N>>,
+}
+
+impl<N: ArrayLength<u8>> CounterNonce<N> {
+    /// Create counter starting at zero.
+    pub fn new() -> Self {
+        Self::from_nonce(Default::default())
+    }
+
+    /// Create counter starting at the given value.
+    pub fn from_nonce(start: Nonce<N>) -> Self {
+        Self { next: Some(start) }
+    }
+}
+
+impl<N: ArrayLength<u8>> Default for CounterNonce<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: ArrayLength<u8>> NonceGenerator for CounterNonce<N> {
+    type NonceSize = N;
+
+    fn generate_nonce(&mut self) -> Result<Nonce<N>, Error> {
+        let nonce = self.next.take().ok_or(Error)?;
+        let mut next = nonce.clone();
+
+        for byte in next.as_mut_array().iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                self.next = Some(next);
+                break;
+            }
+        }
+
+        Ok(nonce)
+    }
+}
+
+/// Nonce generator which returns random nonces.
+///
+/// Only use this generator with nonces large enough to make collisions
+/// negligible (e.g. 192-bit XChaCha20Poly1305 nonces).
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+#[derive(Clone, Debug)]
+pub struct RandomNonce<N: ArrayLength<u8>, R: CryptoRng + RngCore> {
+    rng: R,
+    nonce_size: core::marker::PhantomData<N>,
+}
+
+#[cfg(feature = "rand_core")]
+impl<N: ArrayLength<u8>, R: CryptoRng + RngCore> RandomNonce<N, R> {
+    /// Create random nonce generator using the given RNG.
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            nonce_size: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<N: ArrayLength<u8>, R: CryptoRng + RngCore> NonceGenerator for RandomNonce<N, R> {
+    type NonceSize = N;
+
+    fn generate_nonce(&mut self) -> Result<Nonce<N>, Error> {
+        let mut nonce = Nonce::<N>::default();
+        self.rng
+            .try_fill_bytes(nonce.as_mut_array())
+            .map_err(|_| Error)?;
+        Ok(nonce)
+    }
+}
+
+/// AEAD which uses a [`NonceGenerator`] to pick nonces for encryption.
+///
+/// Encryption methods return the generated nonce, which must be passed to
+/// the recipient along with the ciphertext.
+#[derive(Clone, Debug)]
+pub struct GeneratedNonceAead<A, G> {
+    aead: A,
+    generator: G,
+}
+
+impl<A, G> GeneratedNonceAead<A, G>
+where
+    A: AeadInPlace,
+    G: NonceGenerator<NonceSize = A::NonceSize>,
+{
+    /// Combine the given AEAD with a nonce generator.
+    pub fn new(aead: A, generator: G) -> Self {
+        Self { aead, generator }
+    }
+
+    /// Get reference to the underlying AEAD.
+    pub fn aead(&self) -> &A {
+        &self.aead
+    }
+
+    /// Encrypt the given buffer in-place using a freshly generated nonce,
+    /// and return the nonce.
+    ///
+    /// See [`AeadInPlace::encrypt_in_place`].
+    pub fn encrypt_in_place(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<Nonce<A::NonceSize>, Error> {
+        let nonce = self.generator.generate_nonce()?;
+        self.aead
+            .encrypt_in_place(&nonce, associated_data, buffer)?;
+        Ok(nonce)
+    }
+
+    /// Decrypt the message in-place using the nonce it was encrypted with.
+    ///
+    /// See [`AeadInPlace::decrypt_in_place`].
+    pub fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.aead.decrypt_in_place(nonce, associated_data, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        consts::{U1, U2},
+        test_helpers::XorAead,
+    };
+
+    #[test]
+    fn counter_nonce() {
+        let mut counter = CounterNonce::<U2>::from_nonce(Nonce::new([0xff, 0xfe].into()));
+        assert_eq!(counter.generate_nonce().unwrap().as_slice(), [0xff, 0xfe]);
+        assert_eq!(counter.generate_nonce().unwrap().as_slice(), [0xff, 0xff]);
+        assert!(counter.generate_nonce().is_err());
+    }
+
+    /// SIV-style derivation: the synthetic nonce depends on the nonce, the
+    /// associated data and the plaintext
+    struct ToySiv;
+
+    impl NonceDerivation for ToySiv {
+        type NonceSize = U1;
+        type DerivedNonceSize = U1;
+
+        fn derive_nonce(
+            &self,
+            nonce: &Nonce<U1>,
+            associated_data: &[u8],
+            plaintext: &[u8],
+        ) -> Nonce<U1> {
+            let siv = nonce
+                .as_slice()
+                .iter()
+                .chain(associated_data)
+                .chain(plaintext)
+                .fold(0u8, |acc, b| acc.rotate_left(1) ^ b);
+            Nonce::new([siv].into())
+        }
+    }
+
+    #[test]
+    fn synthetic_nonce() {
+        let aead = DerivedNonceAead::new(XorAead::<U1>::with_key(0x0f), ToySiv);
+        let nonce = Nonce::new([1].into());
+
+        let mut ciphertext = *b"siv";
+        let tag = aead
+            .encrypt_in_place_detached(&nonce, b"ad", &mut ciphertext)
+            .unwrap();
+        let siv = ToySiv.derive_nonce(&nonce, b"ad", b"siv");
+        assert_eq!(tag.as_slice(), [siv.as_slice()[0], 0x0f]);
+
+        let mut other = *b"sjv";
+        let other_tag = aead
+            .encrypt_in_place_detached(&nonce, b"ad", &mut other)
+            .unwrap();
+        assert_ne!(other_tag.as_slice()[0], tag.as_slice()[0]);
+
+        let mut buffer = ciphertext;
+        aead.decrypt_in_place_detached(&nonce, b"ad", &mut buffer, &tag)
+            .unwrap();
+        assert_eq!(&buffer, b"siv");
+
+        // The inner AEAD ignores the nonce, so only the check of the
+        // re-derived synthetic nonce catches these
+        let mut tampered = tag.clone();
+        tampered.as_mut_array()[0] ^= 1;
+        let mut buffer = ciphertext;
+        assert!(aead
+            .decrypt_in_place_detached(&nonce, b"ad", &mut buffer, &tampered)
+            .is_err());
+        #[cfg(feature = "zeroize")]
+        assert_eq!(buffer, [0; 3]);
+
+        let mut buffer = ciphertext;
+        assert!(aead
+            .decrypt_in_place_detached(&nonce, b"other", &mut buffer, &tag)
+            .is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Scope guard for wiping buffers which may hold plaintext.
+
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Guard which wipes a borrowed buffer when it goes out of scope, including
+/// on early returns via `?`, unless it is released with [`SecretScope::keep`].
+///
+/// Buffers are only wiped when the `zeroize` feature is enabled; otherwise the
+/// guard does nothing.
+pub(crate) struct SecretScope<'a, T: AsMut<[u8]> + ?Sized> {
+    buffer: Option<&'a mut T>,
+}
+
+impl<'a, T: AsMut<[u8]> + ?Sized> SecretScope<'a, T> {
+    /// Register `buffer` to be wiped when the returned guard is dropped.
+    pub(crate) fn new(buffer: &'a mut T) -> Self {
+        Self {
+            buffer: Some(buffer),
+        }
+    }
+
+    /// Wipe `buffer` immediately.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn wipe(buffer: &'a mut T) {
+        drop(Self::new(buffer));
+    }
+
+    /// Release the buffer without wiping it.
+    pub(crate) fn keep(mut self) -> &'a mut T {
+        match self.buffer.take() {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> Deref for SecretScope<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.buffer {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> DerefMut for SecretScope<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.buffer {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> Drop for SecretScope<'_, T> {
+    #[allow(unused_variables)]
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            #[cfg(feature = "zeroize")]
+            buffer.as_mut().zeroize();
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Compile-time size assertions for [`NewAead`] and [`AeadInPlace`] types.
+//!
+//! A mismatched nonce otherwise surfaces as a unification error between two
+//! `typenum` integers. With an assertion the compiler reports the sizes in
+//! bytes instead:
+//!
+//! ```rust
+//! # use aead::{consts::{U0, U12, U16, U32}, AeadInPlace, Error, Key, NewAead, Nonce, Tag};
+//! # struct Aes256Gcm;
+//! # impl NewAead for Aes256Gcm {
+//! #     type KeySize = U32;
+//! #     fn new(_: &Key<Self>) -> Self { Aes256Gcm }
+//! # }
+//! # impl AeadInPlace for Aes256Gcm {
+//! #     type NonceSize = U12;
+//! #     type TagSize = U16;
+//! #     type CiphertextOverhead = U0;
+//! #     fn encrypt_in_place_detached(&self, _: &Nonce<U12>, _: &[u8], _: &mut [u8]) -> Result<Tag<U16>, Error> { unimplemented!() }
+//! #     fn decrypt_in_place_detached(&self, _: &Nonce<U12>, _: &[u8], _: &mut [u8], _: &Tag<U16>) -> Result<(), Error> { unimplemented!() }
+//! # }
+//! aead::assert_key_size!(Aes256Gcm as NewAead, 32);
+//! aead::assert_nonce_size!(Aes256Gcm, 12);
+//! aead::assert_tag_size!(Aes256Gcm, 16);
+//! ```
+//!
+//! ```rust,compile_fail,E0308
+//! # use aead::{consts::{U0, U12, U16}, AeadInPlace, Error, Nonce, Tag};
+//! # struct Aes256Gcm;
+//! # impl AeadInPlace for Aes256Gcm {
+//! #     type NonceSize = U12;
+//! #     type TagSize = U16;
+//! #     type CiphertextOverhead = U0;
+//! #     fn encrypt_in_place_detached(&self, _: &Nonce<U12>, _: &[u8], _: &mut [u8]) -> Result<Tag<U16>, Error> { unimplemented!() }
+//! #     fn decrypt_in_place_detached(&self, _: &Nonce<U12>, _: &[u8], _: &mut [u8], _: &Tag<U16>) -> Result<(), Error> { unimplemented!() }
+//! # }
+//! // error[E0308]: expected an array with a size of 12, found one with a size of 24
+//! aead::assert_nonce_size!(Aes256Gcm, 24);
+//! ```
+//!
+//! The macros take the same arguments as their counterparts in the `cipher`
+//! crate: the trait providing the size can optionally be named, e.g.
+//! `assert_nonce_size!(Aes256Gcm as AeadInPlace, 12)`.
+//!
+//! The traits themselves can't customize these errors: the
+//! `#[diagnostic::on_unimplemented]` attribute is only stable since
+//! Rust 1.78, above the MSRV of 1.41.
+//!
+//! [`NewAead`]: crate::NewAead
+//! [`AeadInPlace`]: crate::AeadInPlace
+
+/// Assert at compile time that the key size of the given [`NewAead`]
+/// algorithm is `$size` bytes.
+///
+/// [`NewAead`]: crate::NewAead
+#[macro_export]
+macro_rules! assert_key_size {
+    ($aead:ty, $size:expr) => {
+        $crate::assert_key_size!($aead as NewAead, $size);
+    };
+    ($aead:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$aead as $crate::$trait>::KeySize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
+
+/// Assert at compile time that the nonce size of the given [`AeadInPlace`]
+/// algorithm is `$size` bytes.
+///
+/// [`AeadInPlace`]: crate::AeadInPlace
+#[macro_export]
+macro_rules! assert_nonce_size {
+    ($aead:ty, $size:expr) => {
+        $crate::assert_nonce_size!($aead as AeadInPlace, $size);
+    };
+    ($aead:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$aead as $crate::$trait>::NonceSize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
+
+/// Assert at compile time that the tag size of the given [`AeadInPlace`]
+/// algorithm is `$size` bytes.
+///
+/// [`AeadInPlace`]: crate::AeadInPlace
+#[macro_export]
+macro_rules! assert_tag_size {
+    ($aead:ty, $size:expr) => {
+        $crate::assert_tag_size!($aead as AeadInPlace, $size);
+    };
+    ($aead:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$aead as $crate::$trait>::TagSize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
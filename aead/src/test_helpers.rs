@@ -0,0 +1,134 @@
+//! Toy AEAD shared by the unit tests.
+
+use crate::{
+    consts::{U0, U1},
+    AeadInPlace, Buffer, Error, Key, MisuseResistant, NewAead, Nonce, Tag,
+};
+use core::marker::PhantomData;
+use generic_array::ArrayLength;
+
+/// Byte appended to ciphertexts of AEADs with a `CiphertextOverhead`.
+pub(crate) const PADDING: u8 = 0xaa;
+
+/// AEAD which XORs data with the key and uses the key as tag.
+///
+/// Nonces of size `N` are accepted and ignored. If `O` is nonzero, `O`
+/// bytes of [`PADDING`] are appended to the ciphertext, and therefore only
+/// the buffer-based methods are supported.
+pub(crate) struct XorAead<N = U1, O = U0> {
+    key: u8,
+    leaky: bool,
+    sizes: PhantomData<(N, O)>,
+}
+
+impl<N, O> XorAead<N, O> {
+    /// Create AEAD with the given key.
+    pub(crate) fn with_key(key: u8) -> Self {
+        Self {
+            key,
+            leaky: false,
+            sizes: PhantomData,
+        }
+    }
+
+    /// Decrypt before checking the tag, so the buffer holds plaintext when
+    /// decryption fails.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn leaky(mut self) -> Self {
+        self.leaky = true;
+        self
+    }
+
+    fn decrypt(&self, buffer: &mut [u8], tag: u8) -> Result<(), Error> {
+        if self.leaky {
+            buffer.iter_mut().for_each(|b| *b ^= self.key);
+        }
+        if tag != self.key {
+            return Err(Error);
+        }
+        if !self.leaky {
+            buffer.iter_mut().for_each(|b| *b ^= self.key);
+        }
+        Ok(())
+    }
+}
+
+impl<N, O> NewAead for XorAead<N, O>
+where
+    N: ArrayLength<u8>,
+    O: ArrayLength<u8>,
+{
+    type KeySize = U1;
+
+    fn new(key: &Key<Self>) -> Self {
+        Self::with_key(key[0])
+    }
+}
+
+impl<N, O> AeadInPlace for XorAead<N, O>
+where
+    N: ArrayLength<u8>,
+    O: ArrayLength<u8>,
+{
+    type NonceSize = N;
+    type TagSize = U1;
+    type CiphertextOverhead = O;
+
+    fn encrypt_in_place(
+        &self,
+        _: &Nonce<N>,
+        _: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        buffer.as_mut().iter_mut().for_each(|b| *b ^= self.key);
+        for _ in 0..O::to_usize() {
+            buffer.extend_from_slice(&[PADDING])?;
+        }
+        buffer.extend_from_slice(&[self.key])
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        _: &Nonce<N>,
+        _: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<U1>, Error> {
+        if O::to_usize() != 0 {
+            return Err(Error);
+        }
+        buffer.iter_mut().for_each(|b| *b ^= self.key);
+        Ok(Tag::new([self.key].into()))
+    }
+
+    fn decrypt_in_place(
+        &self,
+        _: &Nonce<N>,
+        _: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len().checked_sub(O::to_usize() + 1).ok_or(Error)?;
+        let (msg, trailer) = buffer.as_mut().split_at_mut(msg_len);
+        let (padding, tag) = trailer.split_at(O::to_usize());
+        if padding.iter().any(|&b| b != PADDING) {
+            return Err(Error);
+        }
+        self.decrypt(msg, tag[0])?;
+        buffer.truncate(msg_len);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _: &Nonce<N>,
+        _: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<U1>,
+    ) -> Result<(), Error> {
+        if O::to_usize() != 0 {
+            return Err(Error);
+        }
+        self.decrypt(buffer, tag.as_slice()[0])
+    }
+}
+
+impl<N, O> MisuseResistant for XorAead<N, O> {}
@@ -15,7 +15,14 @@ pub mod dev;
 
 use crate::errors::InvalidLength;
 use core::convert::TryInto;
-use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use generic_array::{
+    typenum::{
+        Gr, IsGreater, IsGreaterOrEqual, IsLess, Le, NonZero, True, Unsigned, U0, U16, U256,
+    },
+    ArrayLength, GenericArray,
+};
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
 
 /// Key for an algorithm that implements [`NewBlockCipher`].
 pub type Key<B> = GenericArray<u8, <B as NewBlockCipher>::KeySize>;
@@ -47,10 +54,58 @@ pub trait NewBlockCipher: Sized {
     }
 }
 
+/// Valid block sizes, i.e. numbers from 1 to 255.
+///
+/// Block sizes are used as `u8` in keystream position computations (see
+/// [`SeekNum`][crate::stream::SeekNum]), so bounding on this trait makes
+/// invalid block sizes fail at compile time instead of causing panics or
+/// truncation at runtime.
+///
+/// Block sizes other than 16 bytes, e.g. 8-byte blocks (3DES), are handled
+/// by all block and stream traits in this crate, and so are block sizes which
+/// aren't powers of two, e.g. 24 bytes.
+/// Helper constants and methods provided by this trait can be used by mode
+/// implementations which need to reason about the block size.
+///
+/// This trait is sealed and implemented for all valid sizes.
+pub trait BlockSizes: ArrayLength<u8> + sealed::BlockSizes {
+    /// Block size in bits.
+    const BITS: usize = 8 * Self::USIZE;
+
+    /// Whether block size is a power of two.
+    ///
+    /// Modes which process blocks using bit masks instead of division
+    /// should check this constant.
+    const IS_POWER_OF_TWO: bool = Self::USIZE.is_power_of_two();
+
+    /// Check that a block counter with the given width in bits (e.g. the
+    /// 32-bit counter used by GCM) can be stored in a single block.
+    #[inline]
+    fn counter_fits(counter_bits: usize) -> bool {
+        counter_bits != 0 && counter_bits <= Self::BITS
+    }
+}
+
+impl<T: ArrayLength<u8> + sealed::BlockSizes> BlockSizes for T {}
+
+mod sealed {
+    use super::*;
+
+    pub trait BlockSizes {}
+
+    impl<T: Unsigned> BlockSizes for T
+    where
+        Self: IsLess<U256> + IsGreater<U0>,
+        Le<Self, U256>: NonZero,
+        Gr<Self, U0>: NonZero,
+    {
+    }
+}
+
 /// Trait which marks a type as being a block cipher.
 pub trait BlockCipher {
     /// Size of the block in bytes
-    type BlockSize: ArrayLength<u8>;
+    type BlockSize: BlockSizes;
 
     /// Number of blocks which can be processed in parallel by
     /// cipher implementation
@@ -160,3 +215,239 @@ impl<Alg: BlockDecrypt> BlockDecryptMut for Alg {
         self.decrypt_block(block);
     }
 }
+
+/// Marker trait for block ciphers with 128-bit blocks.
+///
+/// Modes whose specification or security analysis assumes 128-bit blocks
+/// (e.g. GCM, GCM-SIV, CCM or XTS) should bound on this trait, so using them
+/// with a 64-bit block cipher fails to compile:
+///
+/// ```rust,compile_fail
+/// # use cipher::{block::{Block, Block128}, consts::{U1, U8}, BlockCipher};
+/// struct Gcm<C: Block128>(C);
+///
+/// struct Des;
+/// impl BlockCipher for Des {
+///     type BlockSize = U8;
+///     type ParBlocks = U1;
+/// }
+///
+/// // error[E0271]: type mismatch resolving `<Des as BlockCipher>::BlockSize == UInt<...>`
+/// let gcm = Gcm(Des);
+/// ```
+///
+/// Blanket implemented for all block ciphers with a `BlockSize` of [`U16`].
+pub trait Block128: BlockCipher<BlockSize = U16> {}
+
+impl<C: BlockCipher<BlockSize = U16>> Block128 for C {}
+
+/// Marker trait for block ciphers which can generate keystream in counter
+/// and output feedback based modes, e.g. CTR.
+///
+/// Keystream generated by a block cipher with `n`-bit blocks becomes
+/// distinguishable from random after about `2^(n/2)` blocks, which is only
+/// 32 GiB of data for 64-bit block ciphers (see [Sweet32]). This trait is
+/// therefore only implemented for ciphers with blocks of at least 128 bits,
+/// and new generic counter modes should bound on it instead of
+/// [`BlockEncrypt`].
+///
+/// Blanket implemented for all such [`BlockEncrypt`] implementations.
+///
+/// ```rust,compile_fail
+/// # use cipher::{block::{Block, CtrCompatible}, consts::{U1, U8}, BlockCipher, BlockEncrypt};
+/// struct Ctr<C: CtrCompatible>(C);
+///
+/// struct Des;
+/// impl BlockCipher for Des {
+///     type BlockSize = U8;
+///     type ParBlocks = U1;
+/// }
+/// impl BlockEncrypt for Des {
+///     fn encrypt_block(&self, _: &mut Block<Self>) {}
+/// }
+///
+/// // error[E0271]: type mismatch resolving `<... as IsGreaterOrEqual<...>>::Output == B1`
+/// let ctr = Ctr(Des);
+/// ```
+///
+/// [Sweet32]: https://sweet32.info/
+pub trait CtrCompatible: BlockEncrypt {}
+
+impl<C> CtrCompatible for C
+where
+    C: BlockEncrypt,
+    C::BlockSize: IsGreaterOrEqual<U16, Output = True>,
+{
+}
+
+/// Initialization vector for block modes such as CBC, which require IVs to
+/// be unpredictable rather than merely unique.
+///
+/// Nonces of stream ciphers and the IVs of counter and feedback modes only
+/// have to be unique, so they are often counters or timestamps. Used as a
+/// CBC IV, such a value allows chosen-plaintext attacks (e.g. [BEAST]).
+/// Modes which need unpredictable IVs should take an `Iv` rather than a bare
+/// block, so passing a stream-oriented nonce fails to compile:
+///
+/// ```rust,compile_fail
+/// # use cipher::{block::{Block, Iv}, consts::{U1, U16}, generic_array::GenericArray, BlockCipher};
+/// fn cbc_encrypt<C: BlockCipher>(iv: &Iv<C>, buffer: &mut [u8]) {}
+///
+/// struct Aes;
+/// impl BlockCipher for Aes {
+///     type BlockSize = U16;
+///     type ParBlocks = U1;
+/// }
+///
+/// let nonce = GenericArray::<u8, U16>::default();
+/// // error[E0308]: mismatched types
+/// cbc_encrypt::<Aes>(&nonce, &mut []);
+/// ```
+///
+/// [BEAST]: https://en.wikipedia.org/wiki/Transport_Layer_Security#BEAST_attack
+pub struct Iv<C: BlockCipher>(Block<C>);
+
+impl<C: BlockCipher> Iv<C> {
+    /// Generate a random IV.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut iv = Block::<C>::default();
+        rng.fill_bytes(&mut iv);
+        Self(iv)
+    }
+
+    /// Derive an IV by encrypting a unique nonce with the mode's cipher, as
+    /// described in [NIST SP 800-38A] appendix C.
+    ///
+    /// [NIST SP 800-38A]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+    pub fn from_encrypted_nonce(cipher: &C, mut nonce: Block<C>) -> Self
+    where
+        C: BlockEncrypt,
+    {
+        cipher.encrypt_block(&mut nonce);
+        Self(nonce)
+    }
+
+    /// Use a block which the caller guarantees to be unpredictable to an
+    /// adversary as IV.
+    pub fn from_unpredictable(block: Block<C>) -> Self {
+        Self(block)
+    }
+
+    /// Get the IV as a block.
+    pub fn as_block(&self) -> &Block<C> {
+        &self.0
+    }
+}
+
+impl<C: BlockCipher> Clone for Iv<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C: BlockCipher> AsRef<[u8]> for Iv<C> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generic_array::typenum::{U1, U16, U2, U24, U3, U7, U8};
+
+    /// Toy cipher which XORs blocks with the key
+    struct XorCipher<N: BlockSizes>(GenericArray<u8, N>);
+
+    impl<N: BlockSizes> BlockCipher for XorCipher<N> {
+        type BlockSize = N;
+        type ParBlocks = U3;
+    }
+
+    impl<N: BlockSizes> BlockEncrypt for XorCipher<N> {
+        fn encrypt_block(&self, block: &mut Block<Self>) {
+            for (b, k) in block.iter_mut().zip(self.0.iter()) {
+                *b ^= k;
+            }
+        }
+    }
+
+    impl<N: BlockSizes> BlockDecrypt for XorCipher<N> {
+        fn decrypt_block(&self, block: &mut Block<Self>) {
+            self.encrypt_block(block);
+        }
+    }
+
+    fn check_blocks<N: BlockSizes>() {
+        let mut key = GenericArray::<u8, N>::default();
+        for (i, k) in key.iter_mut().enumerate() {
+            *k = i as u8 + 1;
+        }
+        let cipher = XorCipher(key);
+
+        // 7 blocks: two parallel chunks and one block in the remainder
+        let mut blocks = GenericArray::<GenericArray<u8, N>, U7>::default();
+        for (i, block) in blocks.iter_mut().enumerate() {
+            block[0] = i as u8;
+        }
+        let pt = blocks.clone();
+
+        cipher.encrypt_blocks(&mut blocks);
+        for (b, p) in blocks.iter().zip(pt.iter()) {
+            let mut expected = p.clone();
+            cipher.encrypt_block(&mut expected);
+            assert_eq!(b, &expected);
+        }
+        cipher.decrypt_blocks(&mut blocks);
+        assert_eq!(blocks, pt);
+    }
+
+    #[test]
+    fn power_of_two_block_sizes() {
+        check_blocks::<U1>();
+        check_blocks::<U8>();
+        check_blocks::<U16>();
+    }
+
+    #[test]
+    fn odd_block_sizes() {
+        check_blocks::<U3>();
+        check_blocks::<U24>();
+    }
+
+    #[test]
+    fn block_size_helpers() {
+        assert_eq!(<U24 as BlockSizes>::BITS, 192);
+        let pow2 = [
+            <U3 as BlockSizes>::IS_POWER_OF_TWO,
+            <U8 as BlockSizes>::IS_POWER_OF_TWO,
+            <U24 as BlockSizes>::IS_POWER_OF_TWO,
+        ];
+        assert_eq!(pow2, [false, true, false]);
+        assert!(<U8 as BlockSizes>::counter_fits(32));
+        assert!(<U8 as BlockSizes>::counter_fits(64));
+        assert!(!<U8 as BlockSizes>::counter_fits(65));
+        assert!(!<U2 as BlockSizes>::counter_fits(0));
+    }
+
+    #[test]
+    fn mode_markers() {
+        fn block128<C: Block128>() {}
+        fn ctr<C: CtrCompatible>() {}
+
+        block128::<XorCipher<U16>>();
+        ctr::<XorCipher<U16>>();
+        ctr::<XorCipher<U24>>();
+    }
+
+    #[test]
+    fn iv_from_encrypted_nonce() {
+        let cipher = XorCipher::<U16>(GenericArray::clone_from_slice(&[0x5a; 16]));
+        let nonce = GenericArray::clone_from_slice(&[1; 16]);
+        let iv = Iv::from_encrypted_nonce(&cipher, nonce);
+        assert_eq!(iv.as_block()[..], [0x5b; 16]);
+        assert_eq!(iv.clone().as_ref(), &[0x5b; 16]);
+    }
+}
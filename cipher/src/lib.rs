@@ -20,10 +20,23 @@ pub mod block;
 pub mod errors;
 pub mod stream;
 
+mod scope;
+mod sizes;
+
 pub use crate::{
     block::{
-        BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, NewBlockCipher,
+        Block128, BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut,
+        BlockSizes, CtrCompatible, NewBlockCipher,
     },
+    scope::SecretScope,
     stream::{NewStreamCipher, StreamCipher, SyncStreamCipher, SyncStreamCipherSeek},
 };
 pub use generic_array::{self, typenum::consts};
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub use rand_core;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize;
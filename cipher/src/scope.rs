@@ -0,0 +1,134 @@
+//! Scope guards for wiping secret scratch buffers.
+
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Guard which wipes a scratch buffer holding secret data (e.g. keystream,
+/// plaintext or intermediate cipher state) when it goes out of scope.
+///
+/// The buffer is registered by borrowing it into the guard and is accessed
+/// through it. When the guard is dropped, including on early returns via `?`
+/// and panics, the buffer is zeroized. If the contents of the buffer turn out
+/// not to be secret, e.g. because it now holds ciphertext, it can be released
+/// unchanged with [`SecretScope::keep`].
+///
+/// Buffers are only wiped when the `zeroize` feature is enabled; otherwise the
+/// guard does nothing, so helpers can use it unconditionally.
+///
+/// ```
+/// use cipher::SecretScope;
+///
+/// let mut buffer = [0x42u8; 16];
+/// {
+///     let mut scratch = SecretScope::new(&mut buffer[..]);
+///     scratch[0] = 0x24;
+/// }
+/// # #[cfg(feature = "zeroize")]
+/// assert_eq!(buffer, [0u8; 16]);
+/// ```
+pub struct SecretScope<'a, T: AsMut<[u8]> + ?Sized> {
+    buffer: Option<&'a mut T>,
+}
+
+impl<'a, T: AsMut<[u8]> + ?Sized> SecretScope<'a, T> {
+    /// Register `buffer` to be wiped when the returned guard is dropped.
+    pub fn new(buffer: &'a mut T) -> Self {
+        Self {
+            buffer: Some(buffer),
+        }
+    }
+
+    /// Wipe `buffer` immediately.
+    pub fn wipe(buffer: &'a mut T) {
+        drop(Self::new(buffer));
+    }
+
+    /// Release the buffer without wiping it.
+    pub fn keep(mut self) -> &'a mut T {
+        match self.buffer.take() {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> Deref for SecretScope<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.buffer {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> DerefMut for SecretScope<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.buffer {
+            Some(buffer) => buffer,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: AsMut<[u8]> + ?Sized> Drop for SecretScope<'_, T> {
+    #[allow(unused_variables)]
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            #[cfg(feature = "zeroize")]
+            buffer.as_mut().zeroize();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "zeroize"))]
+mod tests {
+    use super::SecretScope;
+
+    #[test]
+    fn wipe_on_drop() {
+        let mut buffer = [1u8; 8];
+        {
+            let mut scratch = SecretScope::new(&mut buffer[..]);
+            scratch[..4].copy_from_slice(&[2; 4]);
+            assert_eq!(&scratch[..], &[2, 2, 2, 2, 1, 1, 1, 1]);
+        }
+        assert_eq!(buffer, [0; 8]);
+    }
+
+    #[test]
+    fn wipe_on_early_return() {
+        fn process(buffer: &mut [u8], fail: bool) -> Result<(), ()> {
+            let mut scratch = SecretScope::new(buffer);
+            scratch[0] = 2;
+            if fail {
+                return Err(());
+            }
+            scratch.keep();
+            Ok(())
+        }
+
+        let mut buffer = [1u8; 8];
+        assert!(process(&mut buffer, false).is_ok());
+        assert_eq!(buffer, [2, 1, 1, 1, 1, 1, 1, 1]);
+        assert!(process(&mut buffer, true).is_err());
+        assert_eq!(buffer, [0; 8]);
+    }
+
+    #[test]
+    fn keep() {
+        let mut buffer = [1u8; 8];
+        SecretScope::new(&mut buffer[..]).keep()[0] = 2;
+        assert_eq!(buffer, [2, 1, 1, 1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn wipe() {
+        let mut buffer = [1u8; 8];
+        SecretScope::wipe(&mut buffer[..]);
+        assert_eq!(buffer, [0; 8]);
+    }
+}
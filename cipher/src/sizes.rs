@@ -0,0 +1,100 @@
+//! Compile-time assertions on key, nonce and block sizes.
+//!
+//! Sizes in this crate are `typenum` types, so passing e.g. a 24-byte nonce
+//! to a stream cipher with a 12-byte nonce results in an error about
+//! mismatched `UInt<UInt<...>>` types. Asserting the expected size up front
+//! turns this into an error naming both sizes in bytes:
+//!
+//! ```rust
+//! # use cipher::{consts::{U12, U32}, stream::{Key, Nonce}, NewStreamCipher};
+//! # struct ChaCha20;
+//! # impl NewStreamCipher for ChaCha20 {
+//! #     type KeySize = U32;
+//! #     type NonceSize = U12;
+//! #     fn new(_: &Key<Self>, _: &Nonce<Self>) -> Self { ChaCha20 }
+//! # }
+//! cipher::assert_key_size!(ChaCha20 as NewStreamCipher, 32);
+//! cipher::assert_nonce_size!(ChaCha20, 12);
+//! ```
+//!
+//! ```rust,compile_fail,E0308
+//! # use cipher::{consts::{U12, U32}, stream::{Key, Nonce}, NewStreamCipher};
+//! # struct ChaCha20;
+//! # impl NewStreamCipher for ChaCha20 {
+//! #     type KeySize = U32;
+//! #     type NonceSize = U12;
+//! #     fn new(_: &Key<Self>, _: &Nonce<Self>) -> Self { ChaCha20 }
+//! # }
+//! // error[E0308]: expected an array with a size of 12, found one with a size of 24
+//! cipher::assert_nonce_size!(ChaCha20, 24);
+//! ```
+//!
+//! The assertions can be used both at item level and inside function bodies.
+//! All of them accept the trait providing the size as `Cipher as Trait`,
+//! which is required for key sizes, as in the `aead` crate's assertions.
+//!
+//! The traits themselves can't customize these errors: the
+//! `#[diagnostic::on_unimplemented]` attribute is only stable since
+//! Rust 1.78, above the MSRV of 1.41.
+
+/// Assert at compile time that the key size of the given cipher is `$size`
+/// bytes.
+///
+/// The trait providing the key size, either [`NewBlockCipher`] or
+/// [`NewStreamCipher`], must be named explicitly since a type can implement
+/// both.
+///
+/// [`NewBlockCipher`]: crate::NewBlockCipher
+/// [`NewStreamCipher`]: crate::NewStreamCipher
+#[macro_export]
+macro_rules! assert_key_size {
+    ($cipher:ty, $size:expr) => {
+        compile_error!(concat!(
+            "name the trait providing the key size, e.g. `assert_key_size!(",
+            stringify!($cipher),
+            " as NewBlockCipher, ",
+            stringify!($size),
+            ")`"
+        ));
+    };
+    ($cipher:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$cipher as $crate::$trait>::KeySize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
+
+/// Assert at compile time that the nonce size of the given
+/// [`NewStreamCipher`] is `$size` bytes.
+///
+/// [`NewStreamCipher`]: crate::NewStreamCipher
+#[macro_export]
+macro_rules! assert_nonce_size {
+    ($cipher:ty, $size:expr) => {
+        $crate::assert_nonce_size!($cipher as NewStreamCipher, $size);
+    };
+    ($cipher:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$cipher as $crate::$trait>::NonceSize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
+
+/// Assert at compile time that the block size of the given [`BlockCipher`]
+/// is `$size` bytes.
+///
+/// [`BlockCipher`]: crate::BlockCipher
+#[macro_export]
+macro_rules! assert_block_size {
+    ($cipher:ty, $size:expr) => {
+        $crate::assert_block_size!($cipher as BlockCipher, $size);
+    };
+    ($cipher:ty as $trait:ident, $size:expr) => {
+        const _: () = {
+            use $crate::generic_array::typenum::Unsigned;
+            let _: [(); <<$cipher as $crate::$trait>::BlockSize as Unsigned>::USIZE] = [(); $size];
+        };
+    };
+}
@@ -9,6 +9,16 @@ mod dev;
 #[cfg(feature = "dev")]
 pub use blobby;
 
+mod modes;
+#[cfg(feature = "rand_core")]
+mod rng;
+
+pub use modes::{Cfb1, Cfb8, Ofb};
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub use rng::StreamCipherRng;
+
 use crate::{
     block::{BlockCipher, NewBlockCipher},
     errors::{InvalidLength, LoopError, OverflowError},
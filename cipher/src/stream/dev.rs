@@ -89,6 +89,199 @@ macro_rules! stream_cipher_seek_test {
     };
 }
 
+/// Test keystream integrity of synchronous stream cipher around counter
+/// boundaries
+///
+/// `$block_size` is the keystream block size in bytes and `$keystream_len`
+/// is the total keystream length in bytes, e.g. `64 << 32` for ChaCha20 with
+/// a 32-bit block counter. The keystream must be at least 128 blocks long.
+/// The test checks that:
+///
+/// - keystream blocks at the beginning and at the end of the keystream don't
+///   repeat each other, i.e. the block counter doesn't wrap around;
+/// - applying keystream after seeking to positions around block boundaries
+///   near the end of the keystream is consistent with applying it in one go;
+/// - reaching the end of the keystream results in an error, without
+///   modifying the provided data.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! stream_cipher_keystream_test {
+    ($name:ident, $cipher:ty, $block_size:expr, $keystream_len:expr) => {
+        #[test]
+        fn $name() {
+            use cipher::stream::{NewStreamCipher, SyncStreamCipher, SyncStreamCipherSeek};
+
+            const BLOCKS: usize = 64;
+            let bs: usize = $block_size;
+            let len: u128 = $keystream_len;
+            assert!(len >= (2 * BLOCKS * bs) as u128, "keystream is too short");
+
+            fn get_cipher() -> $cipher {
+                <$cipher>::new(&Default::default(), &Default::default())
+            }
+
+            let mut ks = vec![0u8; BLOCKS * bs];
+            get_cipher().apply_keystream(&mut ks);
+
+            let tail_start = len - (BLOCKS * bs) as u128;
+            let mut tail = vec![0u8; BLOCKS * bs];
+            let mut cipher = get_cipher();
+            cipher.seek(tail_start);
+            cipher.apply_keystream(&mut tail);
+            assert_eq!(cipher.current_pos::<u128>(), len);
+
+            let blocks: Vec<&[u8]> = ks.chunks(bs).chain(tail.chunks(bs)).collect();
+            for (i, a) in blocks.iter().enumerate() {
+                for (j, b) in blocks.iter().enumerate().skip(i + 1) {
+                    assert!(a != b, "keystream blocks {} and {} are equal", i, j);
+                }
+            }
+
+            for offset in 0..2 * bs {
+                let pos = len - (2 * bs + 1) as u128 + offset as u128;
+                let start = (pos - tail_start) as usize;
+                let mut cipher = get_cipher();
+                cipher.seek(pos);
+                let mut buf = vec![0u8; (len - pos) as usize];
+                cipher.apply_keystream(&mut buf);
+                assert_eq!(&buf[..], &tail[start..], "seek to {} is inconsistent", pos);
+            }
+
+            let mut cipher = get_cipher();
+            cipher.seek(len - 1);
+            let mut buf = [0u8; 2];
+            assert!(cipher.try_apply_keystream(&mut buf).is_err());
+            assert_eq!(buf, [0u8; 2], "data was modified on error");
+            assert!(cipher.try_apply_keystream(&mut buf[..1]).is_ok());
+            assert!(cipher.try_apply_keystream(&mut buf[..1]).is_err());
+            assert!(get_cipher().try_seek(len + 1).is_err());
+        }
+    };
+}
+
+/// Test generic properties of stream cipher implementations which don't
+/// depend on test vectors
+///
+/// The following properties are checked for several keys, nonces and
+/// message lengths:
+///
+/// - decrypting encrypted data with a fresh cipher instance recovers the
+///   plaintext, and encrypting it again produces the same ciphertext;
+/// - processing data in chunks of arbitrary sizes produces the same result
+///   as processing it at once;
+/// - processing empty data doesn't change the cipher state.
+///
+/// With the `seek` argument the cipher must implement `SyncStreamCipher` and
+/// `SyncStreamCipherSeek`, and the following is checked in addition:
+///
+/// - applying the keystream to data is equal to XOR-ing the data with the
+///   keystream, i.e. with the output of applying it to zeros;
+/// - seeking to a position and applying the keystream is equal to applying
+///   it from the start and skipping the bytes before that position.
+///
+/// The cipher keystream must be at least 1 KiB long.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! stream_cipher_property_test {
+    ($name:ident, $cipher:ty) => {
+        $crate::stream_cipher_property_test!(@test $name, $cipher, |_ciphers, _data| {});
+    };
+    ($name:ident, $cipher:ty, seek) => {
+        $crate::stream_cipher_property_test!(@test $name, $cipher, |ciphers, data| {
+            use cipher::stream::{SyncStreamCipher, SyncStreamCipherSeek};
+
+            let len = data.len();
+            let mut keystream = vec![0u8; len];
+            ciphers().apply_keystream(&mut keystream);
+
+            let mut buf = data.to_vec();
+            ciphers().apply_keystream(&mut buf);
+            for i in 0..len {
+                assert_eq!(buf[i], data[i] ^ keystream[i], "keystream is not XOR-ed");
+            }
+
+            let positions = [0, 1, len / 3, len / 2, len.saturating_sub(1), len];
+            for pos in positions.iter().cloned().filter(|&pos| pos <= len) {
+                let mut cipher = ciphers();
+                cipher.seek(pos as u64);
+                assert_eq!(cipher.current_pos::<u64>(), pos as u64);
+                let mut buf = data[pos..].to_vec();
+                cipher.apply_keystream(&mut buf);
+                assert_eq!(cipher.current_pos::<u64>(), len as u64);
+                for (i, b) in buf.iter().enumerate() {
+                    assert_eq!(*b, data[pos + i] ^ keystream[pos + i], "seek to {}", pos);
+                }
+            }
+        });
+    };
+    (@test $name:ident, $cipher:ty, |$ciphers:ident, $data:ident| $extra:block) => {
+        #[test]
+        fn $name() {
+            use cipher::generic_array::GenericArray;
+            use cipher::stream::{NewStreamCipher, StreamCipher};
+
+            const LENGTHS: [usize; 8] = [0, 1, 15, 16, 17, 63, 255, 512];
+
+            // Deterministic pseudo-random bytes
+            fn fill(buf: &mut [u8], seed: u32) {
+                let mut state = seed.wrapping_mul(0x9E37_79B9) | 1;
+                for b in buf.iter_mut() {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    *b = state as u8;
+                }
+            }
+
+            for seed in 0..3u32 {
+                let mut key = GenericArray::default();
+                let mut nonce = GenericArray::default();
+                if seed != 0 {
+                    fill(&mut key, seed);
+                    fill(&mut nonce, seed + 100);
+                }
+                let $ciphers = || -> $cipher { <$cipher>::new(&key, &nonce) };
+
+                for &len in LENGTHS.iter() {
+                    let mut pt = vec![0u8; len];
+                    fill(&mut pt, seed + len as u32);
+                    let $data: &[u8] = &pt;
+
+                    let mut ct = pt.clone();
+                    $ciphers().encrypt(&mut ct);
+                    assert_eq!(ct.len(), len);
+
+                    let mut buf = ct.clone();
+                    $ciphers().decrypt(&mut buf);
+                    assert!(buf == pt, "decryption doesn't recover plaintext");
+
+                    $ciphers().encrypt(&mut buf);
+                    assert!(buf == ct, "encryption isn't deterministic");
+
+                    for chunk in [1, 3, 16, 17, 64].iter().cloned() {
+                        let mut cipher = $ciphers();
+                        let mut buf = pt.clone();
+                        for c in buf.chunks_mut(chunk) {
+                            cipher.encrypt(&mut []);
+                            cipher.encrypt(c);
+                        }
+                        assert!(buf == ct, "encryption in chunks of {} differs", chunk);
+
+                        let mut cipher = $ciphers();
+                        for c in buf.chunks_mut(chunk) {
+                            cipher.decrypt(c);
+                            cipher.decrypt(&mut []);
+                        }
+                        assert!(buf == pt, "decryption in chunks of {} differs", chunk);
+                    }
+
+                    $extra
+                }
+            }
+        }
+    };
+}
+
 /// Test core functionality of asynchronous stream cipher
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
@@ -150,6 +343,84 @@ macro_rules! stream_cipher_async_test {
     };
 }
 
+/// Test the generic OFB, CFB8 and CFB1 modes against the AES-128 vectors
+/// from [NIST SP 800-38A][1], Appendix F.
+///
+/// [1]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! stream_cipher_feedback_modes_test {
+    ($name:ident, $aes128:ty) => {
+        #[test]
+        fn $name() {
+            use cipher::block::NewBlockCipher;
+            use cipher::generic_array::GenericArray;
+            use cipher::stream::{Cfb1, Cfb8, FromBlockCipher, Ofb, StreamCipher};
+
+            const KEY: [u8; 16] = [
+                0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+                0x4f, 0x3c,
+            ];
+            const IV: [u8; 16] = [
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f,
+            ];
+            const PLAINTEXT: [u8; 64] = [
+                0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+                0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+                0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
+                0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
+                0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+            ];
+            const OFB: [u8; 64] = [
+                0x3b, 0x3f, 0xd9, 0x2e, 0xb7, 0x2d, 0xad, 0x20, 0x33, 0x34, 0x49, 0xf8, 0xe8, 0x3c,
+                0xfb, 0x4a, 0x77, 0x89, 0x50, 0x8d, 0x16, 0x91, 0x8f, 0x03, 0xf5, 0x3c, 0x52, 0xda,
+                0xc5, 0x4e, 0xd8, 0x25, 0x97, 0x40, 0x05, 0x1e, 0x9c, 0x5f, 0xec, 0xf6, 0x43, 0x44,
+                0xf7, 0xa8, 0x22, 0x60, 0xed, 0xcc, 0x30, 0x4c, 0x65, 0x28, 0xf6, 0x59, 0xc7, 0x78,
+                0x66, 0xa5, 0x10, 0xd9, 0xc1, 0xd6, 0xae, 0x5e,
+            ];
+            const CFB8: [u8; 18] = [
+                0x3b, 0x79, 0x42, 0x4c, 0x9c, 0x0d, 0xd4, 0x36, 0xba, 0xce, 0x9e, 0x0e, 0xd4, 0x58,
+                0x6a, 0x4f, 0x32, 0xb9,
+            ];
+            const CFB1: [u8; 2] = [0x68, 0xb3];
+
+            fn run_test<M>(desc: &str, plaintext: &[u8], ciphertext: &[u8])
+            where
+                M: FromBlockCipher<BlockCipher = $aes128> + StreamCipher,
+            {
+                let new_mode = || {
+                    let cipher = <$aes128>::new(GenericArray::from_slice(&KEY));
+                    M::from_block_cipher(cipher, GenericArray::from_slice(&IV))
+                };
+
+                for n in 1..=plaintext.len() {
+                    let mut mode = new_mode();
+                    let mut buf = plaintext.to_vec();
+                    for chunk in buf.chunks_mut(n) {
+                        mode.encrypt(chunk);
+                    }
+                    if buf != ciphertext {
+                        panic!("Failed {} encrypt, chunk size: {}", desc, n);
+                    }
+
+                    let mut mode = new_mode();
+                    for chunk in buf.chunks_mut(n) {
+                        mode.decrypt(chunk);
+                    }
+                    if buf != plaintext {
+                        panic!("Failed {} decrypt, chunk size: {}", desc, n);
+                    }
+                }
+            }
+
+            run_test::<Ofb<$aes128>>("OFB", &PLAINTEXT, &OFB);
+            run_test::<Cfb8<$aes128>>("CFB8", &PLAINTEXT[..18], &CFB8);
+            run_test::<Cfb1<$aes128>>("CFB1", &PLAINTEXT[..2], &CFB1);
+        }
+    };
+}
+
 /// Create synchronous stream cipher benchmarks
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
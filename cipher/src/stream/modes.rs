@@ -0,0 +1,428 @@
+//! Generic stream cipher modes of operation built on block ciphers.
+//!
+//! These are provided for interoperability with legacy protocols and file
+//! formats. None of them provides integrity, and new designs should use an
+//! AEAD or a counter-based stream cipher instead.
+//!
+//! Like counter modes, these modes are only secure for well below
+//! 2<sup>n/2</sup> blocks per key, where `n` is the block size in bits. Their
+//! [`FromBlockCipher`] impls are therefore bounded on [`CtrCompatible`], and
+//! legacy formats which do use 64-bit block ciphers (e.g. 3DES-CFB) have to
+//! opt in explicitly with the `from_legacy_block_cipher` constructors.
+
+use super::{FromBlockCipher, StreamCipher, SyncStreamCipher};
+use crate::{
+    block::{Block, BlockEncrypt, CtrCompatible},
+    errors::LoopError,
+    SecretScope,
+};
+use generic_array::GenericArray;
+
+/// [Output feedback][1] (OFB) mode.
+///
+/// The keystream is produced by repeatedly encrypting the IV, so OFB is a
+/// synchronous stream cipher: encryption and decryption are the same
+/// operation. The IV must never be reused with the same key.
+///
+/// Block ciphers with blocks shorter than 128 bits can only be used with
+/// [`Ofb::from_legacy_block_cipher`]:
+///
+/// ```rust,compile_fail
+/// # use cipher::{block::Block, consts::{U1, U8}, stream::{FromBlockCipher, Ofb}, BlockCipher, BlockEncrypt};
+/// struct Des;
+/// impl BlockCipher for Des {
+///     type BlockSize = U8;
+///     type ParBlocks = U1;
+/// }
+/// impl BlockEncrypt for Des {
+///     fn encrypt_block(&self, _: &mut Block<Self>) {}
+/// }
+///
+/// // error[E0271]: type mismatch resolving `<... as IsGreaterOrEqual<...>>::Output == B1`
+/// let ofb = Ofb::from_block_cipher(Des, &Default::default());
+/// ```
+///
+/// With the `zeroize` feature enabled, buffered keystream is wiped when the
+/// cipher is dropped.
+///
+/// [1]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+pub struct Ofb<C: BlockEncrypt> {
+    cipher: C,
+    block: Block<C>,
+    pos: usize,
+}
+
+impl<C: BlockEncrypt> Ofb<C> {
+    /// Create an OFB instance over a block cipher of any block size, including
+    /// the 64-bit block ciphers rejected by [`FromBlockCipher`].
+    ///
+    /// For interoperability with legacy formats only. The amount of data
+    /// processed per key should be limited to well below 2<sup>32</sup>
+    /// blocks for 64-bit ciphers (see [`CtrCompatible`]).
+    pub fn from_legacy_block_cipher(cipher: C, iv: &Block<C>) -> Self {
+        let block = iv.clone();
+        let pos = block.len();
+        Self { cipher, block, pos }
+    }
+}
+
+impl<C: CtrCompatible> FromBlockCipher for Ofb<C> {
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher(cipher: C, iv: &GenericArray<u8, C::BlockSize>) -> Self {
+        Self::from_legacy_block_cipher(cipher, iv)
+    }
+}
+
+impl<C: BlockEncrypt> SyncStreamCipher for Ofb<C> {
+    fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+        let bs = self.block.len();
+
+        for byte in data.iter_mut() {
+            if self.pos == bs {
+                self.cipher.encrypt_block(&mut self.block);
+                self.pos = 0;
+            }
+
+            *byte ^= self.block[self.pos];
+            self.pos += 1;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockEncrypt> Drop for Ofb<C> {
+    fn drop(&mut self) {
+        SecretScope::wipe(&mut self.block);
+    }
+}
+
+/// [Cipher feedback][1] mode with 8-bit feedback (CFB8).
+///
+/// Every byte requires a full block cipher invocation: the block cipher
+/// encrypts a shift register holding the last block-size bytes of
+/// ciphertext (initially the IV), and the first byte of its output is XOR-ed
+/// with the next byte of data.
+///
+/// Block ciphers with blocks shorter than 128 bits can only be used with
+/// [`Cfb8::from_legacy_block_cipher`].
+///
+/// With the `zeroize` feature enabled, the shift register is wiped when the
+/// cipher is dropped.
+///
+/// [1]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+pub struct Cfb8<C: BlockEncrypt> {
+    cipher: C,
+    register: Block<C>,
+}
+
+impl<C: BlockEncrypt> Cfb8<C> {
+    /// Create a CFB8 instance over a block cipher of any block size, including
+    /// the 64-bit block ciphers rejected by [`FromBlockCipher`].
+    ///
+    /// For interoperability with legacy formats only. The amount of data
+    /// processed per key should be limited to well below 2<sup>32</sup>
+    /// blocks for 64-bit ciphers (see [`CtrCompatible`]).
+    pub fn from_legacy_block_cipher(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            register: iv.clone(),
+        }
+    }
+
+    /// Compute the next keystream byte.
+    fn keystream_byte(&self) -> u8 {
+        let mut block = self.register.clone();
+        let mut block = SecretScope::new(&mut block);
+        self.cipher.encrypt_block(&mut block);
+        block[0]
+    }
+
+    /// Shift the register by one byte, appending a ciphertext byte.
+    fn shift(&mut self, ciphertext: u8) {
+        let bs = self.register.len();
+        self.register.copy_within(1.., 0);
+        self.register[bs - 1] = ciphertext;
+    }
+}
+
+impl<C: CtrCompatible> FromBlockCipher for Cfb8<C> {
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher(cipher: C, iv: &GenericArray<u8, C::BlockSize>) -> Self {
+        Self::from_legacy_block_cipher(cipher, iv)
+    }
+}
+
+impl<C: BlockEncrypt> StreamCipher for Cfb8<C> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            *byte ^= self.keystream_byte();
+            self.shift(*byte);
+        }
+    }
+
+    fn decrypt(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            let ciphertext = *byte;
+            *byte ^= self.keystream_byte();
+            self.shift(ciphertext);
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockEncrypt> Drop for Cfb8<C> {
+    fn drop(&mut self) {
+        SecretScope::wipe(&mut self.register);
+    }
+}
+
+/// [Cipher feedback][1] mode with 1-bit feedback (CFB1).
+///
+/// Like [`Cfb8`], but the shift register advances by a single bit, so every
+/// *bit* of data requires a full block cipher invocation. Bits are processed
+/// starting from the most significant bit of each byte.
+///
+/// Block ciphers with blocks shorter than 128 bits can only be used with
+/// [`Cfb1::from_legacy_block_cipher`].
+///
+/// With the `zeroize` feature enabled, the shift register is wiped when the
+/// cipher is dropped.
+///
+/// [1]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+pub struct Cfb1<C: BlockEncrypt> {
+    cipher: C,
+    register: Block<C>,
+}
+
+impl<C: BlockEncrypt> Cfb1<C> {
+    /// Create a CFB1 instance over a block cipher of any block size, including
+    /// the 64-bit block ciphers rejected by [`FromBlockCipher`].
+    ///
+    /// For interoperability with legacy formats only. The amount of data
+    /// processed per key should be limited to well below 2<sup>32</sup>
+    /// blocks for 64-bit ciphers (see [`CtrCompatible`]).
+    pub fn from_legacy_block_cipher(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            register: iv.clone(),
+        }
+    }
+
+    /// Compute the next keystream bit, as `0` or `1`.
+    fn keystream_bit(&self) -> u8 {
+        let mut block = self.register.clone();
+        let mut block = SecretScope::new(&mut block);
+        self.cipher.encrypt_block(&mut block);
+        block[0] >> 7
+    }
+
+    /// Shift the register by one bit, appending a ciphertext bit.
+    fn shift(&mut self, ciphertext: u8) {
+        let bs = self.register.len();
+        for i in 0..bs - 1 {
+            self.register[i] = (self.register[i] << 1) | (self.register[i + 1] >> 7);
+        }
+        self.register[bs - 1] = (self.register[bs - 1] << 1) | ciphertext;
+    }
+}
+
+impl<C: CtrCompatible> FromBlockCipher for Cfb1<C> {
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher(cipher: C, iv: &GenericArray<u8, C::BlockSize>) -> Self {
+        Self::from_legacy_block_cipher(cipher, iv)
+    }
+}
+
+impl<C: BlockEncrypt> StreamCipher for Cfb1<C> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            for i in (0..8).rev() {
+                *byte ^= self.keystream_bit() << i;
+                self.shift((*byte >> i) & 1);
+            }
+        }
+    }
+
+    fn decrypt(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            for i in (0..8).rev() {
+                let ciphertext = (*byte >> i) & 1;
+                *byte ^= self.keystream_bit() << i;
+                self.shift(ciphertext);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockEncrypt> Drop for Cfb1<C> {
+    fn drop(&mut self) {
+        SecretScope::wipe(&mut self.register);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cfb1, Cfb8, Ofb};
+    use crate::{
+        block::{Block, BlockCipher, BlockEncrypt, BlockSizes},
+        consts::{U1, U16, U8},
+        stream::{FromBlockCipher, StreamCipher, SyncStreamCipher},
+    };
+    use core::marker::PhantomData;
+    use generic_array::GenericArray;
+
+    /// Toy block cipher: not secure, but every output byte depends on the key
+    /// and all input bytes. 64-bit by default.
+    struct Toy<N = U8>(u8, PhantomData<N>);
+
+    fn toy<N>() -> Toy<N> {
+        Toy(0x5a, PhantomData)
+    }
+
+    impl<N: BlockSizes> BlockCipher for Toy<N> {
+        type BlockSize = N;
+        type ParBlocks = U1;
+    }
+
+    impl<N: BlockSizes> BlockEncrypt for Toy<N> {
+        fn encrypt_block(&self, block: &mut Block<Self>) {
+            for round in 0..8u8 {
+                let sum = block.iter().fold(round, |acc, b| acc.wrapping_add(*b));
+                for (i, b) in block.iter_mut().enumerate() {
+                    *b = (*b ^ self.0 ^ sum).rotate_left(i as u32 + 1);
+                }
+            }
+        }
+    }
+
+    /// Modes which accept 64-bit block ciphers via `from_legacy_block_cipher`.
+    trait Legacy<N: BlockSizes>: Sized {
+        fn from_legacy(cipher: Toy<N>, iv: &Block<Toy<N>>) -> Self;
+    }
+
+    macro_rules! impl_legacy {
+        ($($mode:ident),*) => {$(
+            impl<N: BlockSizes> Legacy<N> for $mode<Toy<N>> {
+                fn from_legacy(cipher: Toy<N>, iv: &Block<Toy<N>>) -> Self {
+                    Self::from_legacy_block_cipher(cipher, iv)
+                }
+            }
+        )*};
+    }
+
+    impl_legacy!(Ofb, Cfb8, Cfb1);
+
+    const IV: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+
+    fn new<M: Legacy<U8>>() -> M {
+        M::from_legacy(toy(), GenericArray::from_slice(&IV))
+    }
+
+    fn message() -> [u8; 67] {
+        let mut msg = [0u8; 67];
+        for (i, b) in msg.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(31);
+        }
+        msg
+    }
+
+    /// Check round trip and that processing data in chunks doesn't change
+    /// the result.
+    fn check_async<M: Legacy<U8> + StreamCipher>() -> [u8; 67] {
+        let msg = message();
+        let mut ct = msg;
+        new::<M>().encrypt(&mut ct);
+        assert_ne!(ct[..], msg[..]);
+
+        for n in 1..=msg.len() {
+            let mut buf = msg;
+            let mut mode = new::<M>();
+            buf.chunks_mut(n).for_each(|chunk| mode.encrypt(chunk));
+            assert_eq!(buf[..], ct[..]);
+
+            let mut mode = new::<M>();
+            buf.chunks_mut(n).for_each(|chunk| mode.decrypt(chunk));
+            assert_eq!(buf[..], msg[..]);
+        }
+
+        ct
+    }
+
+    /// Check that the checked constructor of a mode over a 128-bit cipher
+    /// matches the legacy one.
+    fn check_from_block_cipher<M>()
+    where
+        M: Legacy<U16> + FromBlockCipher<BlockCipher = Toy<U16>, NonceSize = U16> + StreamCipher,
+    {
+        let iv = GenericArray::from_slice(&[0x24; 16]);
+        let mut expected = message();
+        M::from_legacy(toy(), iv).encrypt(&mut expected);
+
+        let mut ct = message();
+        M::from_block_cipher(toy(), iv).encrypt(&mut ct);
+        assert_eq!(ct[..], expected[..]);
+    }
+
+    fn encrypt_iv() -> Block<Toy> {
+        let mut block = GenericArray::clone_from_slice(&IV);
+        toy().encrypt_block(&mut block);
+        block
+    }
+
+    #[test]
+    fn ofb() {
+        let ct = check_async::<Ofb<Toy>>();
+
+        // Keystream is the IV encrypted repeatedly
+        let mut keystream = [0u8; 67];
+        new::<Ofb<Toy>>().apply_keystream(&mut keystream);
+        let mut block = GenericArray::clone_from_slice(&IV);
+        for chunk in keystream.chunks(8) {
+            toy::<U8>().encrypt_block(&mut block);
+            assert_eq!(chunk, &block[..chunk.len()]);
+        }
+
+        let msg = message();
+        for i in 0..msg.len() {
+            assert_eq!(ct[i], msg[i] ^ keystream[i]);
+        }
+    }
+
+    #[test]
+    fn cfb8() {
+        let ct = check_async::<Cfb8<Toy>>();
+        assert_eq!(ct[0], message()[0] ^ encrypt_iv()[0]);
+
+        // Corrupting a ciphertext byte garbles it and the following block
+        // size bytes, after which decryption resynchronizes
+        let mut buf = ct;
+        buf[10] ^= 1;
+        new::<Cfb8<Toy>>().decrypt(&mut buf);
+        let msg = message();
+        assert_eq!(buf[..10], msg[..10]);
+        assert_eq!(buf[10], msg[10] ^ 1);
+        assert_eq!(buf[19..], msg[19..]);
+    }
+
+    #[test]
+    fn cfb1() {
+        let ct = check_async::<Cfb1<Toy>>();
+        assert_eq!(ct[0] >> 7, (message()[0] ^ encrypt_iv()[0]) >> 7);
+    }
+
+    #[test]
+    fn from_block_cipher() {
+        check_from_block_cipher::<Ofb<Toy<U16>>>();
+        check_from_block_cipher::<Cfb8<Toy<U16>>>();
+        check_from_block_cipher::<Cfb1<Toy<U16>>>();
+    }
+}
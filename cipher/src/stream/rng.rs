@@ -0,0 +1,239 @@
+//! Random number generator adapter for synchronous stream ciphers.
+
+use super::{Key, NewStreamCipher, Nonce, SyncStreamCipher};
+use crate::SecretScope;
+use core::num::NonZeroU32;
+use rand_core::{CryptoRng, Error, RngCore, SeedableRng};
+
+/// Size of the internal keystream buffer in bytes.
+const BUFFER_SIZE: usize = 64;
+
+/// Error code returned by [`StreamCipherRng`] when end of a keystream
+/// has been reached.
+const LOOP_ERROR_CODE: u32 = Error::CUSTOM_START;
+
+/// Cryptographically secure random number generator which outputs keystream
+/// of a synchronous stream cipher.
+///
+/// Keystream is generated in chunks into an internal buffer, which allows
+/// [`RngCore::next_u32`] and [`RngCore::next_u64`] to avoid applying
+/// keystream byte-at-a-time. Large requests made via [`RngCore::fill_bytes`]
+/// bypass the buffer and apply keystream directly to the output.
+///
+/// Output of the generator does not depend on how it's requested, i.e. it's
+/// always equal to the cipher keystream starting from the current position.
+///
+/// With the `zeroize` feature enabled, buffered keystream (i.e. future output
+/// of the generator) is wiped when the generator is dropped.
+pub struct StreamCipherRng<C: SyncStreamCipher> {
+    cipher: C,
+    buffer: [u8; BUFFER_SIZE],
+    pos: usize,
+}
+
+impl<C: SyncStreamCipher> StreamCipherRng<C> {
+    /// Create new generator from the provided cipher instance.
+    pub fn from_cipher(cipher: C) -> Self {
+        Self {
+            cipher,
+            buffer: [0; BUFFER_SIZE],
+            pos: BUFFER_SIZE,
+        }
+    }
+
+    /// Create new generator from key and nonce.
+    pub fn new(key: &Key<C>, nonce: &Nonce<C>) -> Self
+    where
+        C: NewStreamCipher,
+    {
+        Self::from_cipher(C::new(key, nonce))
+    }
+
+    /// Refill internal buffer with the next chunk of keystream.
+    fn refill(&mut self) -> Result<(), Error> {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let mut buffer = SecretScope::new(&mut buffer[..]);
+        self.cipher
+            .try_apply_keystream(&mut buffer)
+            .map_err(|_| loop_error())?;
+        self.buffer.copy_from_slice(&buffer);
+        self.pos = 0;
+        Ok(())
+    }
+}
+
+impl<C: SyncStreamCipher> RngCore for StreamCipherRng<C> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    /// # Panics
+    /// If end of the keystream will be reached.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.try_fill_bytes(dest).is_err() {
+            panic!("stream cipher loop detected");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let n = core::cmp::min(dest.len(), BUFFER_SIZE - self.pos);
+        let (head, rest) = dest.split_at_mut(n);
+        head.copy_from_slice(&self.buffer[self.pos..self.pos + n]);
+        self.pos += n;
+
+        if rest.is_empty() {
+            return Ok(());
+        }
+
+        let bulk_len = rest.len() - rest.len() % BUFFER_SIZE;
+        let (bulk, tail) = rest.split_at_mut(bulk_len);
+        for b in bulk.iter_mut() {
+            *b = 0;
+        }
+        self.cipher
+            .try_apply_keystream(bulk)
+            .map_err(|_| loop_error())?;
+
+        if !tail.is_empty() {
+            self.refill()?;
+            tail.copy_from_slice(&self.buffer[..tail.len()]);
+            self.pos = tail.len();
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: SyncStreamCipher> CryptoRng for StreamCipherRng<C> {}
+
+#[cfg(feature = "zeroize")]
+impl<C: SyncStreamCipher> Drop for StreamCipherRng<C> {
+    fn drop(&mut self) {
+        SecretScope::wipe(&mut self.buffer[..]);
+    }
+}
+
+impl<C: NewStreamCipher + SyncStreamCipher> SeedableRng for StreamCipherRng<C> {
+    type Seed = Key<C>;
+
+    /// Create new generator using `seed` as a cipher key and all-zero nonce.
+    fn from_seed(seed: Key<C>) -> Self {
+        Self::new(&seed, &Nonce::<C>::default())
+    }
+}
+
+fn loop_error() -> Error {
+    NonZeroU32::new(LOOP_ERROR_CODE).unwrap().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamCipherRng, BUFFER_SIZE};
+    use crate::{
+        errors::{LoopError, OverflowError},
+        stream::{SeekNum, SyncStreamCipher, SyncStreamCipherSeek},
+    };
+    use rand_core::RngCore;
+
+    /// Length of the toy keystream in bytes.
+    const KEYSTREAM_LEN: usize = 1024;
+
+    /// Toy seekable stream cipher: not secure, but every keystream byte
+    /// depends on the key and its position.
+    struct Toy {
+        key: u8,
+        pos: usize,
+    }
+
+    fn keystream_byte(key: u8, pos: usize) -> u8 {
+        let pos = pos as u16;
+        key ^ (pos as u8).wrapping_mul(31) ^ (pos >> 8) as u8
+    }
+
+    impl SyncStreamCipher for Toy {
+        fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+            if data.len() > KEYSTREAM_LEN - self.pos {
+                return Err(LoopError);
+            }
+            for b in data {
+                *b ^= keystream_byte(self.key, self.pos);
+                self.pos += 1;
+            }
+            Ok(())
+        }
+    }
+
+    impl SyncStreamCipherSeek for Toy {
+        fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+            T::from_block_byte(self.pos, 0, 1)
+        }
+
+        fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+            let (pos, _): (usize, u8) = pos.to_block_byte(1)?;
+            if pos > KEYSTREAM_LEN {
+                return Err(LoopError);
+            }
+            self.pos = pos;
+            Ok(())
+        }
+    }
+
+    fn keystream(from: usize) -> [u8; KEYSTREAM_LEN] {
+        let mut keystream = [0u8; KEYSTREAM_LEN];
+        let mut cipher = Toy { key: 0x5a, pos: 0 };
+        cipher.seek(from);
+        cipher.apply_keystream(&mut keystream[..KEYSTREAM_LEN - from]);
+        keystream
+    }
+
+    #[test]
+    fn output_independent_of_requests() {
+        // Mix of word-sized, sub-buffer, bulk and unaligned requests
+        let sizes = [4, 8, 1, 63, 2 * BUFFER_SIZE, 3, BUFFER_SIZE + 5, 0, 100];
+        let mut rng = StreamCipherRng::from_cipher(Toy { key: 0x5a, pos: 0 });
+        let mut output = [0u8; KEYSTREAM_LEN];
+        let mut pos = 0;
+        for &n in sizes.iter().cycle().take(20) {
+            let dest = &mut output[pos..pos + n];
+            match n {
+                4 => dest.copy_from_slice(&rng.next_u32().to_le_bytes()),
+                8 => dest.copy_from_slice(&rng.next_u64().to_le_bytes()),
+                _ => rng.fill_bytes(dest),
+            }
+            pos += n;
+        }
+
+        assert_eq!(output[..pos], keystream(0)[..pos]);
+    }
+
+    #[test]
+    fn output_starts_at_cipher_position() {
+        for &from in &[0, 1, BUFFER_SIZE - 1, BUFFER_SIZE, 300] {
+            let mut cipher = Toy { key: 0x5a, pos: 0 };
+            cipher.seek(from);
+            let mut rng = StreamCipherRng::from_cipher(cipher);
+
+            let mut output = [0u8; 2 * BUFFER_SIZE + 7];
+            rng.fill_bytes(&mut output[..3]);
+            rng.fill_bytes(&mut output[3..]);
+            assert_eq!(output[..], keystream(from)[..output.len()]);
+        }
+    }
+
+    #[test]
+    fn end_of_keystream() {
+        let mut rng = StreamCipherRng::from_cipher(Toy { key: 0x5a, pos: 0 });
+        let mut output = [0u8; KEYSTREAM_LEN];
+        rng.fill_bytes(&mut output);
+        assert_eq!(output[..], keystream(0)[..]);
+        assert!(rng.try_fill_bytes(&mut [0u8; 1]).is_err());
+    }
+}
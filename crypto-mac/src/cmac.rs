@@ -0,0 +1,155 @@
+//! Generic [CMAC] (OMAC1) construction over block ciphers.
+//!
+//! [CMAC]: https://en.wikipedia.org/wiki/One-key_MAC
+
+use crate::{FromBlockCipher, Mac, Output};
+use cipher::block::{Block, BlockCipher, BlockEncrypt};
+use generic_array::typenum::{Unsigned, U16, U32, U64, U8};
+
+/// Block sizes supported by [`Cmac`], with the constant used for doubling
+/// in GF(2^n) when deriving subkeys, as specified in
+/// [NIST SP 800-38B] and [RFC 5297].
+///
+/// [NIST SP 800-38B]: https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38b.pdf
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub trait DblConstant: cipher::BlockSizes {
+    /// Low bits of the reduction polynomial for n-bit blocks.
+    const R: u16;
+}
+
+impl DblConstant for U8 {
+    const R: u16 = 0x1b;
+}
+
+impl DblConstant for U16 {
+    const R: u16 = 0x87;
+}
+
+impl DblConstant for U32 {
+    const R: u16 = 0x425;
+}
+
+impl DblConstant for U64 {
+    const R: u16 = 0x125;
+}
+
+/// Generic CMAC instance over the block cipher `C`.
+///
+/// CMAC is CBC-MAC with the final block tweaked by one of two subkeys
+/// derived from the cipher key, which makes it secure for messages of
+/// variable length. `Cmac<Aes128>` is AES-CMAC as defined in [RFC 4493].
+///
+/// [RFC 4493]: https://tools.ietf.org/html/rfc4493
+#[derive(Clone)]
+pub struct Cmac<C>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    C::BlockSize: DblConstant,
+{
+    cipher: C,
+    key1: Block<C>,
+    key2: Block<C>,
+    state: Block<C>,
+    buffer: Block<C>,
+    pos: usize,
+}
+
+impl<C> FromBlockCipher for Cmac<C>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    C::BlockSize: DblConstant,
+{
+    type Cipher = C;
+
+    fn from_cipher(cipher: C) -> Self {
+        let mut key1 = Block::<C>::default();
+        cipher.encrypt_block(&mut key1);
+        dbl::<C::BlockSize>(&mut key1);
+
+        let mut key2 = key1.clone();
+        dbl::<C::BlockSize>(&mut key2);
+
+        Self {
+            cipher,
+            key1,
+            key2,
+            state: Default::default(),
+            buffer: Default::default(),
+            pos: 0,
+        }
+    }
+}
+
+impl<C> Mac for Cmac<C>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    C::BlockSize: DblConstant,
+{
+    type OutputSize = C::BlockSize;
+
+    fn update(&mut self, mut data: &[u8]) {
+        let bs = C::BlockSize::to_usize();
+
+        while !data.is_empty() {
+            // The last block is kept in the buffer until more data arrives,
+            // since it has to be tweaked during finalization.
+            if self.pos == bs {
+                xor(&mut self.state, &self.buffer);
+                self.cipher.encrypt_block(&mut self.state);
+                self.pos = 0;
+            }
+
+            let n = core::cmp::min(bs - self.pos, data.len());
+            let (head, tail) = data.split_at(n);
+            self.buffer[self.pos..self.pos + n].copy_from_slice(head);
+            self.pos += n;
+            data = tail;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state = Default::default();
+        self.pos = 0;
+    }
+
+    fn finalize(mut self) -> Output<Self> {
+        let bs = C::BlockSize::to_usize();
+
+        if self.pos == bs {
+            xor(&mut self.buffer, &self.key1);
+        } else {
+            self.buffer[self.pos] = 0x80;
+            for b in self.buffer[self.pos + 1..].iter_mut() {
+                *b = 0;
+            }
+            xor(&mut self.buffer, &self.key2);
+        }
+
+        xor(&mut self.state, &self.buffer);
+        self.cipher.encrypt_block(&mut self.state);
+        Output::new(self.state)
+    }
+}
+
+/// Multiply block interpreted as a big-endian element of GF(2^n) by `x`
+/// in constant time.
+fn dbl<N: DblConstant>(block: &mut [u8]) {
+    let carry = block[0] >> 7;
+
+    for i in 0..block.len() - 1 {
+        block[i] = (block[i] << 1) | (block[i + 1] >> 7);
+    }
+
+    let n = block.len();
+    block[n - 1] <<= 1;
+
+    let r = N::R & 0u16.wrapping_sub(carry as u16);
+    block[n - 1] ^= r as u8;
+    block[n - 2] ^= (r >> 8) as u8;
+}
+
+fn xor(dst: &mut [u8], src: &[u8]) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        *d ^= *s;
+    }
+}
@@ -21,8 +21,13 @@ use cipher::{BlockCipher, NewBlockCipher};
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 pub mod dev;
 
+#[cfg(feature = "cipher")]
+mod cmac;
 mod errors;
 
+#[cfg(feature = "cipher")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+pub use crate::cmac::{Cmac, DblConstant};
 pub use crate::errors::{InvalidKeyLength, MacError};
 pub use generic_array::{self, typenum::consts};
 
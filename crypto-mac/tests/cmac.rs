@@ -0,0 +1,119 @@
+/// Tests for `Cmac`
+#[cfg(all(test, feature = "cipher", feature = "dev"))]
+mod tests {
+    use crypto_mac::{
+        cipher::{
+            block::{Block, BlockCipher, BlockEncrypt, Key, NewBlockCipher},
+            consts::{U1, U16},
+        },
+        new_test, Cmac,
+    };
+
+    /// Minimal (and not constant-time) AES-128 encryption as specified in
+    /// FIPS 197, so the RFC 4493 vectors can be checked without depending
+    /// on an AES crate built against a released `cipher`.
+    #[derive(Clone)]
+    struct Aes128 {
+        sbox: [u8; 256],
+        round_keys: [[u8; 16]; 11],
+    }
+
+    fn xtime(b: u8) -> u8 {
+        (b << 1) ^ if b & 0x80 != 0 { 0x1b } else { 0 }
+    }
+
+    fn sbox() -> [u8; 256] {
+        let mut sbox = [0x63; 256];
+        // `p` walks all non-zero field elements via multiplication by 3,
+        // while `q` tracks its inverse via division by 3
+        let (mut p, mut q) = (1u8, 1u8);
+        loop {
+            p ^= xtime(p);
+            q ^= q << 1;
+            q ^= q << 2;
+            q ^= q << 4;
+            if q & 0x80 != 0 {
+                q ^= 0x09;
+            }
+            sbox[p as usize] = 0x63
+                ^ q
+                ^ q.rotate_left(1)
+                ^ q.rotate_left(2)
+                ^ q.rotate_left(3)
+                ^ q.rotate_left(4);
+            if p == 1 {
+                return sbox;
+            }
+        }
+    }
+
+    impl NewBlockCipher for Aes128 {
+        type KeySize = U16;
+
+        fn new(key: &Key<Self>) -> Self {
+            let sbox = sbox();
+            let mut round_keys = [[0u8; 16]; 11];
+            round_keys[0].copy_from_slice(key);
+
+            let mut rcon = 1;
+            for round in 1..11 {
+                let prev = round_keys[round - 1];
+                let mut word = [prev[13], prev[14], prev[15], prev[12]];
+                word.iter_mut().for_each(|b| *b = sbox[*b as usize]);
+                word[0] ^= rcon;
+                rcon = xtime(rcon);
+
+                for i in 0..16 {
+                    word[i % 4] ^= prev[i];
+                    round_keys[round][i] = word[i % 4];
+                }
+            }
+
+            Self { sbox, round_keys }
+        }
+    }
+
+    impl BlockCipher for Aes128 {
+        type BlockSize = U16;
+        type ParBlocks = U1;
+    }
+
+    impl BlockEncrypt for Aes128 {
+        fn encrypt_block(&self, block: &mut Block<Self>) {
+            // bytes are stored column by column, i.e. `state[row + 4 * col]`
+            let mut state = [0u8; 16];
+            for ((s, b), k) in state.iter_mut().zip(block.iter()).zip(&self.round_keys[0]) {
+                *s = b ^ k;
+            }
+
+            for round in 1..11 {
+                let mut shifted = [0u8; 16];
+                for (i, byte) in shifted.iter_mut().enumerate() {
+                    let (row, col) = (i % 4, i / 4);
+                    *byte = self.sbox[state[row + 4 * ((col + row) % 4)] as usize];
+                }
+                state = shifted;
+
+                if round != 10 {
+                    for col in state.chunks_mut(4) {
+                        let all = col[0] ^ col[1] ^ col[2] ^ col[3];
+                        let first = col[0];
+                        for row in 0..4 {
+                            let next = if row == 3 { first } else { col[row + 1] };
+                            col[row] ^= all ^ xtime(col[row] ^ next);
+                        }
+                    }
+                }
+
+                for (s, k) in state.iter_mut().zip(&self.round_keys[round]) {
+                    *s ^= k;
+                }
+            }
+
+            block.copy_from_slice(&state);
+        }
+    }
+
+    // RFC 4493 section 4, examples 1-4
+    new_test!(aes128_cmac, "aes128_cmac", Cmac<Aes128>);
+}
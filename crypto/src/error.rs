@@ -0,0 +1,160 @@
+//! Error type shared by protocols composed from multiple trait crates.
+//!
+//! This lives in the facade because it converts from the errors of several
+//! trait crates, and only the facade depends on all of them.
+
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::boxed::Box;
+
+/// Kind of primitive which caused a [`ProtocolError`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Authenticated encryption failure, see `aead::Error`.
+    Aead,
+
+    /// Elliptic curve failure, see `elliptic_curve::Error`.
+    EllipticCurve,
+
+    /// MAC verification failure, see `mac::MacError`.
+    Mac,
+
+    /// Signature failure, see `signature::Error`.
+    Signature,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::Aead => "AEAD",
+            ErrorKind::EllipticCurve => "elliptic curve",
+            ErrorKind::Mac => "MAC",
+            ErrorKind::Signature => "signature",
+        })
+    }
+}
+
+/// Error type for protocols (e.g. handshakes) which combine several
+/// primitives.
+///
+/// Errors of the individual trait crates convert into this type using
+/// [`From`], so they can be propagated with `?` without wrapping them in a
+/// custom enum. The [`ErrorKind`] records which primitive failed, and when
+/// the `std` feature is enabled the original error is kept as the
+/// [`std::error::Error::source`].
+///
+/// ```
+/// # #[cfg(all(feature = "aead", feature = "signature"))]
+/// # {
+/// use crypto::{ErrorKind, ProtocolError};
+///
+/// fn handshake(sig_ok: bool) -> Result<(), ProtocolError> {
+///     if !sig_ok {
+///         Err(signature::Error::new())?;
+///     }
+///     Err(aead::Error)?
+/// }
+///
+/// assert_eq!(handshake(false).unwrap_err().kind(), ErrorKind::Signature);
+/// assert_eq!(handshake(true).unwrap_err().kind(), ErrorKind::Aead);
+/// # }
+/// ```
+pub struct ProtocolError {
+    kind: ErrorKind,
+
+    /// Original error (if available).
+    #[cfg(feature = "std")]
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl ProtocolError {
+    /// Create a new error of the given kind with no associated source.
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            #[cfg(feature = "std")]
+            source: None,
+        }
+    }
+
+    /// Create a new error of the given kind with an associated source.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_source(
+        kind: ErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    ) -> Self {
+        Self {
+            kind,
+            source: Some(source.into()),
+        }
+    }
+
+    /// Kind of primitive which caused this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Debug for ProtocolError {
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProtocolError")
+            .field("kind", &self.kind)
+            .finish()
+    }
+
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProtocolError")
+            .field("kind", &self.kind)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} error", self.kind)
+    }
+}
+
+impl From<ErrorKind> for ProtocolError {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtocolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Implement [`From`] for an error type of a trait crate.
+macro_rules! impl_from_error {
+    ($feature:literal, $error:ty, $kind:ident) => {
+        #[cfg(feature = $feature)]
+        impl From<$error> for ProtocolError {
+            #[cfg(not(feature = "std"))]
+            fn from(_: $error) -> Self {
+                Self::new(ErrorKind::$kind)
+            }
+
+            #[cfg(feature = "std")]
+            fn from(error: $error) -> Self {
+                Self::from_source(ErrorKind::$kind, error)
+            }
+        }
+    };
+}
+
+impl_from_error!("aead", aead::Error, Aead);
+impl_from_error!("elliptic-curve", elliptic_curve::Error, EllipticCurve);
+impl_from_error!("mac", mac::MacError, Mac);
+impl_from_error!("signature", signature::Error, Signature);
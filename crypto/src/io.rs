@@ -0,0 +1,654 @@
+//! I/O adapters for hashing, MAC-ing and encrypting data streams.
+//!
+//! This module provides reader and writer wrappers which feed all data passing
+//! through them into a hash function, MAC or stream cipher, so that streams can
+//! be processed without buffering whole payloads:
+//!
+//! | Adapter | Wraps | Processing |
+//! |---------|-------|------------|
+//! | [`HashReader`] / [`HashWriter`] | reader / writer | feeds data into a [`digest::Update`] hasher |
+//! | [`MacReader`] / [`MacWriter`] | reader / writer | feeds data into a [`mac::Mac`] |
+//! | [`DecryptReader`] | reader | decrypts data with a [`StreamCipher`] |
+//! | [`EncryptWriter`] | writer | encrypts data with a [`StreamCipher`] |
+//!
+//! Adapters implement `std::io` traits and, if the `async` feature is enabled,
+//! [`futures_io`] traits as well. They are constructed using the [`ReadExt`] and
+//! [`WriteExt`] extension traits (or [`AsyncReadExt`] and [`AsyncWriteExt`]
+//! respectively), or with the `new` methods of the adapters.
+//!
+//! Readers and writers which only observe data (hashing and MAC-ing adapters)
+//! process exactly the bytes which were read from or accepted by the wrapped
+//! stream, so partial reads and writes are handled transparently.
+//!
+//! With the `async` feature, the asynchronous hashing adapters of the
+//! `digest` crate are re-exported as well: [`DigestWriter`] and
+//! [`AsyncDigestRead`] for feeding streams into hashers, and [`HashTask`] for
+//! hashing a whole [`futures_io::AsyncRead`] stream in a cancellable and
+//! resumable way.
+//!
+//! [`StreamCipher`]: cipher::stream::StreamCipher
+
+#[cfg(feature = "async")]
+mod async_io;
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use self::async_io::{AsyncReadExt, AsyncWriteExt};
+
+#[cfg(all(feature = "async", feature = "digest"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "async", feature = "digest"))))]
+pub use digest::async_io::{AsyncDigestRead, DigestWriter, HashCheckpoint, HashTask, UpdateDigest};
+
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "digest")]
+use digest::Update;
+#[cfg(feature = "mac")]
+use mac::Mac;
+#[cfg(feature = "cipher")]
+use {cipher::stream::StreamCipher, std::vec::Vec};
+
+/// Define reader and writer adapters which pass data to a processor without
+/// modifying it.
+macro_rules! observing_adapters {
+    (
+        $feature:literal, $bound:path, $param:ident, $field:ident,
+        $reader:ident, $writer:ident, $desc:literal,
+        |$state:ident, $data:ident| $update:expr
+    ) => {
+        #[doc = "Reader which feeds all data read from the wrapped reader into"]
+        #[doc = $desc]
+        #[cfg(feature = $feature)]
+        #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
+        #[derive(Clone, Debug)]
+        pub struct $reader<R, $param> {
+            inner: R,
+            $field: $param,
+        }
+
+        #[doc = "Writer which feeds all data accepted by the wrapped writer into"]
+        #[doc = $desc]
+        #[cfg(feature = $feature)]
+        #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
+        #[derive(Clone, Debug)]
+        pub struct $writer<W, $param> {
+            inner: W,
+            $field: $param,
+        }
+
+        #[cfg(feature = $feature)]
+        observing_adapters!(@common $reader, R, $param, $field);
+
+        #[cfg(feature = $feature)]
+        observing_adapters!(@common $writer, W, $param, $field);
+
+        #[cfg(feature = $feature)]
+        impl<R: Read, $param: $bound> Read for $reader<R, $param> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = self.inner.read(buf)?;
+                let ($state, $data) = (&mut self.$field, &buf[..n]);
+                $update;
+                Ok(n)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<W: Write, $param: $bound> Write for $writer<W, $param> {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let n = self.inner.write(buf)?;
+                let ($state, $data) = (&mut self.$field, &buf[..n]);
+                $update;
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.inner.flush()
+            }
+        }
+
+        #[cfg(all(feature = "async", feature = $feature))]
+        impl<R, $param> futures_io::AsyncRead for $reader<R, $param>
+        where
+            R: futures_io::AsyncRead + Unpin,
+            $param: $bound + Unpin,
+        {
+            fn poll_read(
+                self: core::pin::Pin<&mut Self>,
+                cx: &mut core::task::Context<'_>,
+                buf: &mut [u8],
+            ) -> core::task::Poll<io::Result<usize>> {
+                let this = self.get_mut();
+                let res = core::pin::Pin::new(&mut this.inner).poll_read(cx, buf);
+                if let core::task::Poll::Ready(Ok(n)) = res {
+                    let ($state, $data) = (&mut this.$field, &buf[..n]);
+                    $update;
+                }
+                res
+            }
+        }
+
+        #[cfg(all(feature = "async", feature = $feature))]
+        impl<W, $param> futures_io::AsyncWrite for $writer<W, $param>
+        where
+            W: futures_io::AsyncWrite + Unpin,
+            $param: $bound + Unpin,
+        {
+            fn poll_write(
+                self: core::pin::Pin<&mut Self>,
+                cx: &mut core::task::Context<'_>,
+                buf: &[u8],
+            ) -> core::task::Poll<io::Result<usize>> {
+                let this = self.get_mut();
+                let res = core::pin::Pin::new(&mut this.inner).poll_write(cx, buf);
+                if let core::task::Poll::Ready(Ok(n)) = res {
+                    let ($state, $data) = (&mut this.$field, &buf[..n]);
+                    $update;
+                }
+                res
+            }
+
+            fn poll_flush(
+                self: core::pin::Pin<&mut Self>,
+                cx: &mut core::task::Context<'_>,
+            ) -> core::task::Poll<io::Result<()>> {
+                core::pin::Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+            }
+
+            fn poll_close(
+                self: core::pin::Pin<&mut Self>,
+                cx: &mut core::task::Context<'_>,
+            ) -> core::task::Poll<io::Result<()>> {
+                core::pin::Pin::new(&mut self.get_mut().inner).poll_close(cx)
+            }
+        }
+    };
+    (@common $name:ident, $inner:ident, $param:ident, $field:ident) => {
+        impl<$inner, $param> $name<$inner, $param> {
+            /// Create new adapter around the given stream.
+            pub fn new(inner: $inner, $field: $param) -> Self {
+                Self { inner, $field }
+            }
+
+            /// Get reference to the wrapped stream.
+            pub fn get_ref(&self) -> &$inner {
+                &self.inner
+            }
+
+            /// Get mutable reference to the wrapped stream.
+            ///
+            /// Data read from or written to the stream directly bypasses
+            /// processing.
+            pub fn get_mut(&mut self) -> &mut $inner {
+                &mut self.inner
+            }
+
+            /// Get reference to the processing state.
+            pub fn $field(&self) -> &$param {
+                &self.$field
+            }
+
+            /// Consume adapter and return the wrapped stream and processing
+            /// state.
+            pub fn into_inner(self) -> ($inner, $param) {
+                (self.inner, self.$field)
+            }
+        }
+    };
+}
+
+observing_adapters!(
+    "digest",
+    Update,
+    D,
+    hasher,
+    HashReader,
+    HashWriter,
+    "a hasher.",
+    |hasher, data| hasher.update(data)
+);
+
+observing_adapters!(
+    "mac",
+    Mac,
+    M,
+    mac,
+    MacReader,
+    MacWriter,
+    "a MAC.",
+    |mac, data| mac.update(data)
+);
+
+/// Reader which decrypts all data read from the wrapped reader using a
+/// stream cipher.
+#[cfg(feature = "cipher")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+#[derive(Clone, Debug)]
+pub struct DecryptReader<R, C> {
+    inner: R,
+    cipher: C,
+}
+
+#[cfg(feature = "cipher")]
+observing_adapters!(@common DecryptReader, R, C, cipher);
+
+#[cfg(feature = "cipher")]
+impl<R: Read, C: StreamCipher> Read for DecryptReader<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.cipher.decrypt(&mut buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Writer which encrypts all data using a stream cipher before passing it
+/// to the wrapped writer.
+///
+/// Encrypted data which was not yet accepted by the wrapped writer is kept in
+/// an internal buffer and written out by subsequent writes and flushes. Use
+/// [`flush`][Write::flush] before [`into_inner`][EncryptWriter::into_inner]
+/// to make sure no ciphertext is left behind.
+#[cfg(feature = "cipher")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+#[derive(Clone, Debug)]
+pub struct EncryptWriter<W, C> {
+    inner: W,
+    cipher: C,
+    buffer: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature = "cipher")]
+impl<W, C> EncryptWriter<W, C> {
+    /// Create new adapter around the given stream.
+    pub fn new(inner: W, cipher: C) -> Self {
+        Self {
+            inner,
+            cipher,
+            buffer: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Get reference to the wrapped stream.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get mutable reference to the wrapped stream.
+    ///
+    /// Data written to the stream directly bypasses encryption.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Get reference to the stream cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Get encrypted data which was not yet accepted by the wrapped stream.
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer[self.pos..]
+    }
+
+    /// Consume adapter and return the wrapped stream and the stream cipher.
+    ///
+    /// Returns the adapter back as an error if there is pending ciphertext.
+    pub fn into_inner(self) -> Result<(W, C), Self> {
+        if self.pending().is_empty() {
+            Ok((self.inner, self.cipher))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Encrypt `data` into the internal buffer, which must be empty.
+    fn encrypt_into_buffer(&mut self, data: &[u8])
+    where
+        C: StreamCipher,
+    {
+        debug_assert!(self.pending().is_empty());
+        self.buffer.clear();
+        self.buffer.extend_from_slice(data);
+        self.pos = 0;
+        self.cipher.encrypt(&mut self.buffer);
+    }
+
+    /// Write pending ciphertext to the wrapped stream.
+    fn write_pending(&mut self) -> io::Result<()>
+    where
+        W: Write,
+    {
+        while self.pos < self.buffer.len() {
+            match self.inner.write(&self.buffer[self.pos..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => self.pos += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cipher")]
+impl<W: Write, C: StreamCipher> Write for EncryptWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_pending()?;
+        self.encrypt_into_buffer(buf);
+
+        // The data is accepted at this point since the keystream was applied
+        // to it. Errors are reported by the next write or flush.
+        let _ = self.write_pending();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+        self.inner.flush()
+    }
+}
+
+/// Extension trait for wrapping [`Read`] streams into processing adapters.
+pub trait ReadExt: Read + Sized {
+    /// Feed all data read from this reader into `hasher`.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    fn hash_with<D: Update>(self, hasher: D) -> HashReader<Self, D> {
+        HashReader::new(self, hasher)
+    }
+
+    /// Feed all data read from this reader into `mac`.
+    #[cfg(feature = "mac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+    fn mac_with<M: Mac>(self, mac: M) -> MacReader<Self, M> {
+        MacReader::new(self, mac)
+    }
+
+    /// Decrypt all data read from this reader using `cipher`.
+    #[cfg(feature = "cipher")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+    fn decrypt_with<C: StreamCipher>(self, cipher: C) -> DecryptReader<Self, C> {
+        DecryptReader::new(self, cipher)
+    }
+}
+
+impl<R: Read> ReadExt for R {}
+
+/// Extension trait for wrapping [`Write`] streams into processing adapters.
+pub trait WriteExt: Write + Sized {
+    /// Feed all data accepted by this writer into `hasher`.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    fn hash_with<D: Update>(self, hasher: D) -> HashWriter<Self, D> {
+        HashWriter::new(self, hasher)
+    }
+
+    /// Feed all data accepted by this writer into `mac`.
+    #[cfg(feature = "mac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+    fn mac_with<M: Mac>(self, mac: M) -> MacWriter<Self, M> {
+        MacWriter::new(self, mac)
+    }
+
+    /// Encrypt all data using `cipher` before writing it to this writer.
+    #[cfg(feature = "cipher")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+    fn encrypt_with<C: StreamCipher>(self, cipher: C) -> EncryptWriter<Self, C> {
+        EncryptWriter::new(self, cipher)
+    }
+}
+
+impl<W: Write> WriteExt for W {}
+
+#[cfg(all(test, feature = "cipher", feature = "digest", feature = "mac"))]
+mod tests {
+    use super::{ReadExt, WriteExt};
+    use cipher::stream::StreamCipher;
+    use digest::Update;
+    use mac::{consts::U8, generic_array::GenericArray, Mac, Output};
+    use std::{
+        io::{self, Read, Write},
+        vec::Vec,
+    };
+
+    /// Toy stream cipher: not secure, but the keystream depends on the key
+    /// and the position in the stream.
+    pub(super) struct ToyCipher {
+        key: u8,
+        pos: u8,
+    }
+
+    impl ToyCipher {
+        pub(super) fn new() -> Self {
+            Self { key: KEY, pos: 0 }
+        }
+    }
+
+    impl StreamCipher for ToyCipher {
+        fn encrypt(&mut self, data: &mut [u8]) {
+            for b in data {
+                *b ^= self.key ^ self.pos.wrapping_mul(13);
+                self.pos = self.pos.wrapping_add(1);
+            }
+        }
+
+        fn decrypt(&mut self, data: &mut [u8]) {
+            self.encrypt(data);
+        }
+    }
+
+    /// Toy MAC: not secure, but every tag byte depends on the key and on all
+    /// input bytes and their order.
+    #[derive(Clone)]
+    pub(super) struct ToyMac {
+        state: [u8; 8],
+        len: usize,
+    }
+
+    impl ToyMac {
+        pub(super) fn new() -> Self {
+            Self {
+                state: [KEY; 8],
+                len: 0,
+            }
+        }
+    }
+
+    impl Mac for ToyMac {
+        type OutputSize = U8;
+
+        fn update(&mut self, data: &[u8]) {
+            for &b in data {
+                let i = self.len % 8;
+                self.state[i] = self.state[i].rotate_left(3) ^ b;
+                for j in 1..8 {
+                    let prev = self.state[(i + j - 1) % 8];
+                    let cur = &mut self.state[(i + j) % 8];
+                    *cur = cur.wrapping_add(prev);
+                }
+                self.len += 1;
+            }
+        }
+
+        fn reset(&mut self) {
+            *self = Self::new();
+        }
+
+        fn finalize(self) -> Output<Self> {
+            Output::new(GenericArray::clone_from_slice(&self.state))
+        }
+    }
+
+    /// Hasher which records all data it was fed.
+    #[derive(Default)]
+    struct Recorder(Vec<u8>);
+
+    impl Update for Recorder {
+        fn update(&mut self, data: impl AsRef<[u8]>) {
+            self.0.extend_from_slice(data.as_ref());
+        }
+    }
+
+    /// Writer which accepts at most `max` bytes per call, or none at all
+    /// while `blocked` is set.
+    #[derive(Default)]
+    struct ShortWriter {
+        data: Vec<u8>,
+        max: usize,
+        blocked: bool,
+    }
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.blocked {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            let n = buf.len().min(self.max);
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Reader which returns at most `max` bytes per call.
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        max: usize,
+    }
+
+    impl Read for ShortReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.max).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    const KEY: u8 = 0x5a;
+    const TAG_SIZE: usize = 8;
+
+    pub(super) fn message() -> Vec<u8> {
+        (0..100u8).map(|i| i.wrapping_mul(31)).collect()
+    }
+
+    /// Encrypt-then-MAC `msg` through a writer which accepts 3 bytes per
+    /// call, returning `ciphertext || tag`.
+    fn seal(msg: &[u8]) -> Vec<u8> {
+        let inner = ShortWriter {
+            max: 3,
+            ..Default::default()
+        };
+        let mut writer = inner.mac_with(ToyMac::new()).encrypt_with(ToyCipher::new());
+        writer.write_all(msg).unwrap();
+        writer.flush().unwrap();
+
+        let (writer, _) = writer.into_inner().ok().unwrap();
+        let (inner, mac) = writer.into_inner();
+        let mut sealed = inner.data;
+        sealed.extend_from_slice(&mac.finalize().into_bytes());
+        sealed
+    }
+
+    /// Verify and decrypt `ciphertext || tag`, reading 7 bytes per call so
+    /// the read at the end of the ciphertext is split at the tag boundary.
+    fn open(sealed: &[u8]) -> io::Result<Vec<u8>> {
+        let ciphertext_len = sealed.len().saturating_sub(TAG_SIZE) as u64;
+        let inner = ShortReader {
+            data: sealed,
+            max: 7,
+        };
+        let mut reader = inner
+            .take(ciphertext_len)
+            .mac_with(ToyMac::new())
+            .decrypt_with(ToyCipher::new());
+
+        let mut msg = Vec::new();
+        reader.read_to_end(&mut msg)?;
+
+        let (reader, _) = reader.into_inner();
+        let (inner, mac) = reader.into_inner();
+        let mut tag = Vec::new();
+        inner.into_inner().read_to_end(&mut tag)?;
+
+        mac.verify(&tag)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "tag mismatch"))?;
+        Ok(msg)
+    }
+
+    #[test]
+    fn round_trip() {
+        let msg = message();
+        let sealed = seal(&msg);
+        assert_eq!(sealed.len(), msg.len() + TAG_SIZE);
+        assert_ne!(&sealed[..msg.len()], &msg[..]);
+        assert_eq!(open(&sealed).unwrap(), msg);
+    }
+
+    #[test]
+    fn reject_tampering() {
+        let sealed = seal(&message());
+
+        for &i in &[0, 50, sealed.len() - TAG_SIZE - 1, sealed.len() - 1] {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 1;
+            assert!(open(&tampered).is_err(), "byte {} not authenticated", i);
+        }
+
+        assert!(open(&sealed[..sealed.len() - 1]).is_err());
+        assert!(open(&[]).is_err());
+    }
+
+    #[test]
+    fn encrypt_writer_keeps_pending_ciphertext() {
+        let msg = message();
+        let inner = ShortWriter {
+            max: 3,
+            blocked: true,
+            ..Default::default()
+        };
+        let mut writer = inner.encrypt_with(ToyCipher::new());
+
+        // Data is accepted even though the wrapped writer doesn't take it
+        assert_eq!(writer.write(&msg[..10]).unwrap(), 10);
+        assert_eq!(writer.pending().len(), 10);
+
+        // Pending ciphertext must be written before more data is accepted
+        assert!(writer.write(&msg[10..]).is_err());
+        assert!(writer.flush().is_err());
+        let mut writer = writer.into_inner().err().unwrap();
+
+        writer.get_mut().blocked = false;
+        writer.write_all(&msg[10..]).unwrap();
+        writer.flush().unwrap();
+        assert!(writer.pending().is_empty());
+
+        let (inner, _) = writer.into_inner().ok().unwrap();
+        let mut decrypted = inner.data;
+        ToyCipher::new().decrypt(&mut decrypted);
+        assert_eq!(decrypted, msg);
+    }
+
+    #[test]
+    fn observers_process_accepted_bytes_only() {
+        let msg = message();
+
+        let inner = ShortWriter {
+            max: 3,
+            ..Default::default()
+        };
+        let mut writer = inner.hash_with(Recorder::default());
+        assert_eq!(writer.write(&msg[..10]).unwrap(), 3);
+        assert_eq!(writer.hasher().0, &msg[..3]);
+
+        let inner = ShortReader { data: &msg, max: 7 };
+        let mut reader = inner.hash_with(Recorder::default());
+        let mut buf = [0u8; 10];
+        assert_eq!(reader.read(&mut buf).unwrap(), 7);
+        assert_eq!(reader.hasher().0, &msg[..7]);
+    }
+}
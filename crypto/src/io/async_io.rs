@@ -0,0 +1,289 @@
+//! Asynchronous I/O support based on the [`futures_io`] traits.
+
+#[cfg(feature = "cipher")]
+use super::{DecryptReader, EncryptWriter};
+#[cfg(feature = "digest")]
+use super::{HashReader, HashWriter};
+#[cfg(feature = "mac")]
+use super::{MacReader, MacWriter};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+
+#[cfg(feature = "cipher")]
+use cipher::stream::StreamCipher;
+#[cfg(feature = "digest")]
+use digest::Update;
+#[cfg(feature = "mac")]
+use mac::Mac;
+
+#[cfg(feature = "cipher")]
+impl<R, C> AsyncRead for DecryptReader<R, C>
+where
+    R: AsyncRead + Unpin,
+    C: StreamCipher + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let res = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(n)) = res {
+            this.cipher.decrypt(&mut buf[..n]);
+        }
+        res
+    }
+}
+
+#[cfg(feature = "cipher")]
+impl<W: AsyncWrite + Unpin, C> EncryptWriter<W, C> {
+    /// Write pending ciphertext to the wrapped stream.
+    fn poll_write_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.pos < self.buffer.len() {
+            match Pin::new(&mut self.inner).poll_write(cx, &self.buffer[self.pos..]) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Poll::Ready(Ok(n)) => self.pos += n,
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "cipher")]
+impl<W, C> AsyncWrite for EncryptWriter<W, C>
+where
+    W: AsyncWrite + Unpin,
+    C: StreamCipher + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        match this.poll_write_pending(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        }
+        this.encrypt_into_buffer(buf);
+
+        // The data is accepted at this point since the keystream was applied
+        // to it. Errors are reported by the next write or flush.
+        let _ = this.poll_write_pending(cx);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.poll_write_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_flush(cx),
+            res => res,
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.poll_write_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_close(cx),
+            res => res,
+        }
+    }
+}
+
+/// Extension trait for wrapping [`AsyncRead`] streams into processing
+/// adapters.
+pub trait AsyncReadExt: AsyncRead + Unpin + Sized {
+    /// Feed all data read from this reader into `hasher`.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    fn hash_with<D: Update>(self, hasher: D) -> HashReader<Self, D> {
+        HashReader::new(self, hasher)
+    }
+
+    /// Feed all data read from this reader into `mac`.
+    #[cfg(feature = "mac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+    fn mac_with<M: Mac>(self, mac: M) -> MacReader<Self, M> {
+        MacReader::new(self, mac)
+    }
+
+    /// Decrypt all data read from this reader using `cipher`.
+    #[cfg(feature = "cipher")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+    fn decrypt_with<C: StreamCipher>(self, cipher: C) -> DecryptReader<Self, C> {
+        DecryptReader::new(self, cipher)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncReadExt for R {}
+
+/// Extension trait for wrapping [`AsyncWrite`] streams into processing
+/// adapters.
+pub trait AsyncWriteExt: AsyncWrite + Unpin + Sized {
+    /// Feed all data accepted by this writer into `hasher`.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    fn hash_with<D: Update>(self, hasher: D) -> HashWriter<Self, D> {
+        HashWriter::new(self, hasher)
+    }
+
+    /// Feed all data accepted by this writer into `mac`.
+    #[cfg(feature = "mac")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+    fn mac_with<M: Mac>(self, mac: M) -> MacWriter<Self, M> {
+        MacWriter::new(self, mac)
+    }
+
+    /// Encrypt all data using `cipher` before writing it to this writer.
+    #[cfg(feature = "cipher")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cipher")))]
+    fn encrypt_with<C: StreamCipher>(self, cipher: C) -> EncryptWriter<Self, C> {
+        EncryptWriter::new(self, cipher)
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWriteExt for W {}
+
+#[cfg(all(test, feature = "cipher", feature = "digest", feature = "mac"))]
+mod tests {
+    use super::{AsyncReadExt as _, AsyncWriteExt as _};
+    use crate::io::tests::{message, ToyCipher, ToyMac};
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use futures::{
+        executor::block_on,
+        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    };
+    use mac::Mac;
+    use std::{io, vec::Vec};
+
+    const TAG_SIZE: usize = 8;
+
+    /// Writer which alternates between returning `Pending` and accepting at
+    /// most 3 bytes.
+    #[derive(Default)]
+    struct ShortWriter {
+        data: Vec<u8>,
+        ready: bool,
+    }
+
+    impl AsyncWrite for ShortWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let n = buf.len().min(3);
+            self.data.extend_from_slice(&buf[..n]);
+            Poll::Ready(Ok(n))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// Reader which alternates between returning `Pending` and returning at
+    /// most 7 bytes.
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        ready: bool,
+    }
+
+    impl AsyncRead for ShortReader<'_> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let n = buf.len().min(7).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Poll::Ready(Ok(n))
+        }
+    }
+
+    async fn seal(msg: &[u8]) -> Vec<u8> {
+        let mut writer = ShortWriter::default()
+            .mac_with(ToyMac::new())
+            .encrypt_with(ToyCipher::new());
+        writer.write_all(msg).await.unwrap();
+        writer.flush().await.unwrap();
+
+        let (writer, _) = writer.into_inner().ok().unwrap();
+        let (inner, mac) = writer.into_inner();
+        let mut sealed = inner.data;
+        sealed.extend_from_slice(&mac.finalize().into_bytes());
+        sealed
+    }
+
+    async fn open(sealed: &[u8]) -> io::Result<Vec<u8>> {
+        let ciphertext_len = sealed.len().saturating_sub(TAG_SIZE) as u64;
+        let inner = ShortReader {
+            data: sealed,
+            ready: false,
+        };
+        let mut reader = inner
+            .take(ciphertext_len)
+            .mac_with(ToyMac::new())
+            .decrypt_with(ToyCipher::new());
+
+        let mut msg = Vec::new();
+        reader.read_to_end(&mut msg).await?;
+
+        let (reader, _) = reader.into_inner();
+        let (inner, mac) = reader.into_inner();
+        let mut tag = Vec::new();
+        inner.into_inner().read_to_end(&mut tag).await?;
+
+        mac.verify(&tag)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "tag mismatch"))?;
+        Ok(msg)
+    }
+
+    #[test]
+    fn round_trip() {
+        let msg = message();
+        let sealed = block_on(seal(&msg));
+        assert_eq!(sealed.len(), msg.len() + TAG_SIZE);
+        assert_eq!(block_on(open(&sealed)).unwrap(), msg);
+    }
+
+    #[test]
+    fn reject_tampering() {
+        let sealed = block_on(seal(&message()));
+
+        for &i in &[0, sealed.len() - TAG_SIZE - 1, sealed.len() - 1] {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 1;
+            assert!(block_on(open(&tampered)).is_err());
+        }
+
+        assert!(block_on(open(&sealed[..sealed.len() - 1])).is_err());
+    }
+}
@@ -15,6 +15,14 @@
 //! with each one gated under a cargo feature, providing a single place to both
 //! import and upgrade these crates while ensuring they remain compatible.
 //!
+//! Besides re-exports, this crate hosts glue which spans several trait crates
+//! and therefore can't live in any one of them, such as the [`io`] adapters
+//! and the [`ProtocolError`] type which their errors convert into. Only the
+//! facade knows about all trait crates, so such types are defined here.
+//! Traits which the trait crates themselves implement or bound on can't be
+//! defined here, since the facade depends on the trait crates and not the
+//! other way around.
+//!
 //! # Traits
 //!
 //! The following traits are available as re-exports of RustCrypto crates through
@@ -31,10 +39,22 @@
 //! | [`signature`](https://docs.rs/signature) | `signature` | Digital signatures (i.e. public key-based message authentication) |
 //! | [`universal_hash`](https://docs.rs/universal-hash) | `universal‑hash` | Universal Hash Functions (used to build MACs) |
 //!
+//! # I/O adapters
+//!
+//! With the `std` feature enabled, the [`io`] module provides reader and writer
+//! adapters which hash, MAC or encrypt data passing through them. Enabling the
+//! `async` feature adds support for [`futures_io`] streams.
+//!
+//! # Errors
+//!
+//! Errors of the trait crates listed above convert into [`ProtocolError`], so
+//! protocols combining several primitives can handle failures uniformly.
+//!
 //! [1]: https://github.com/RustCrypto/traits
 //! [2]: https://github.com/RustCrypto
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
@@ -42,6 +62,17 @@
 #![forbid(unsafe_code)]
 #![warn(rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod io;
+
+mod error;
+
+pub use error::{ErrorKind, ProtocolError};
+
 #[cfg(feature = "aead")]
 pub use aead;
 
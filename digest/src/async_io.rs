@@ -0,0 +1,274 @@
+//! Asynchronous I/O support
+//!
+//! Types in this module are based on the [`futures_io`] traits. Readers and
+//! writers from other async runtimes (e.g. Tokio) can be used via their
+//! respective compatibility layers.
+
+use crate::{DeserializeStateError, Digest, Output, SerializableState, SerializedState, Update};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::{boxed::Box, io};
+
+/// Size of the buffer used by [`UpdateDigest`] and [`HashTask`] for reading
+/// input data.
+const BUFFER_SIZE: usize = 4096;
+
+/// Number of bytes hashed by [`HashTask`] before yielding to the executor.
+const YIELD_INTERVAL: u64 = 16 * BUFFER_SIZE as u64;
+
+/// Wrapper around an [`Update`] implementor which feeds all data written
+/// into it to the hasher.
+///
+/// It can be used with functions like `futures::io::copy` to hash data
+/// streams without buffering whole payloads.
+#[derive(Clone, Debug, Default)]
+pub struct DigestWriter<D> {
+    hasher: D,
+}
+
+impl<D> DigestWriter<D> {
+    /// Create new writer around the given hasher.
+    pub fn new(hasher: D) -> Self {
+        Self { hasher }
+    }
+
+    /// Get reference to the wrapped hasher.
+    pub fn get_ref(&self) -> &D {
+        &self.hasher
+    }
+
+    /// Get mutable reference to the wrapped hasher.
+    pub fn get_mut(&mut self) -> &mut D {
+        &mut self.hasher
+    }
+
+    /// Consume writer and return the wrapped hasher.
+    pub fn into_inner(self) -> D {
+        self.hasher
+    }
+}
+
+impl<D: Update + Unpin> AsyncWrite for DigestWriter<D> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().hasher.update(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Extension trait for feeding data from [`AsyncRead`] streams into hashers.
+pub trait AsyncDigestRead: AsyncRead + Unpin {
+    /// Read all data from the stream until EOF and feed it into `hasher`.
+    ///
+    /// Returned future resolves to the number of processed bytes.
+    fn update_digest<'a, D: Update>(&'a mut self, hasher: &'a mut D) -> UpdateDigest<'a, Self, D> {
+        UpdateDigest {
+            reader: self,
+            hasher,
+            buffer: [0; BUFFER_SIZE],
+            total: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncDigestRead for R {}
+
+/// Future returned by [`AsyncDigestRead::update_digest`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UpdateDigest<'a, R: ?Sized, D> {
+    reader: &'a mut R,
+    hasher: &'a mut D,
+    buffer: [u8; BUFFER_SIZE],
+    total: u64,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized, D: Update> Future for UpdateDigest<'_, R, D> {
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut *this.reader).poll_read(cx, &mut this.buffer) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Ok(this.total)),
+                Poll::Ready(Ok(n)) => {
+                    this.hasher.update(&this.buffer[..n]);
+                    this.total += n as u64;
+                }
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Position and serialized hasher state of a cancelled [`HashTask`].
+#[derive(Clone)]
+pub struct HashCheckpoint<D: SerializableState> {
+    /// Number of input bytes hashed so far.
+    pub position: u64,
+
+    /// Serialized hasher state after hashing `position` bytes.
+    pub state: SerializedState<D>,
+}
+
+/// Future which hashes all data read from an [`AsyncRead`] stream and
+/// resolves to the digest.
+///
+/// The task yields to the executor after every 64 KiB of input, so hashing
+/// large inputs from fast readers doesn't starve other tasks and the task
+/// can be cancelled between chunks. Progress is reported to an optional
+/// callback as the total number of bytes hashed so far.
+///
+/// Dropping the task discards the hash computation. To preserve it, poll the
+/// task by reference and call [`HashTask::cancel`], which returns the reader
+/// together with a [`HashCheckpoint`] that [`HashTask::resume`] accepts once
+/// the input is available again. This also works after the task resolved to
+/// an I/O error, since failed reads don't affect the hasher state. Once the
+/// task resolved to a digest, its hasher and position are reset, so a
+/// checkpoint taken afterwards describes an empty input.
+///
+/// ```
+/// use digest::{
+///     async_io::{HashCheckpoint, HashTask},
+///     Digest, Output, SerializableState,
+/// };
+/// use futures_io::AsyncRead;
+/// use std::io;
+///
+/// async fn hash_file<R, D>(
+///     reader: R,
+///     checkpoint: Option<&HashCheckpoint<D>>,
+/// ) -> io::Result<Output<D>>
+/// where
+///     R: AsyncRead + Unpin,
+///     D: Digest + SerializableState + Unpin,
+/// {
+///     let task = match checkpoint {
+///         Some(checkpoint) => HashTask::resume(reader, checkpoint)
+///             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+///         None => HashTask::new(reader),
+///     };
+///     task.on_progress(|bytes| println!("hashed {} bytes", bytes)).await
+/// }
+/// ```
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct HashTask<R, D> {
+    reader: R,
+    hasher: D,
+    buffer: [u8; BUFFER_SIZE],
+    position: u64,
+    progress: Option<Box<dyn FnMut(u64) + Send>>,
+}
+
+impl<R: AsyncRead + Unpin, D: Digest> HashTask<R, D> {
+    /// Create a task hashing `reader` with a new hasher.
+    pub fn new(reader: R) -> Self {
+        Self::with_hasher(reader, D::new())
+    }
+
+    /// Create a task hashing `reader` with the given hasher.
+    ///
+    /// The hasher may have already processed some data, e.g. a header which
+    /// isn't part of the stream.
+    pub fn with_hasher(reader: R, hasher: D) -> Self {
+        Self {
+            reader,
+            hasher,
+            buffer: [0; BUFFER_SIZE],
+            position: 0,
+            progress: None,
+        }
+    }
+
+    /// Resume a cancelled task.
+    ///
+    /// The reader must be positioned at `checkpoint.position`, i.e. right
+    /// after the last byte hashed before cancellation.
+    pub fn resume(reader: R, checkpoint: &HashCheckpoint<D>) -> Result<Self, DeserializeStateError>
+    where
+        D: SerializableState,
+    {
+        let mut task = Self::with_hasher(reader, D::deserialize(&checkpoint.state)?);
+        task.position = checkpoint.position;
+        Ok(task)
+    }
+
+    /// Report progress to the given callback.
+    ///
+    /// The callback is invoked after every chunk read from the stream with
+    /// the total number of bytes hashed, including those hashed before a
+    /// [`HashTask::resume`].
+    pub fn on_progress(mut self, callback: impl FnMut(u64) + Send + 'static) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Get the number of bytes hashed so far.
+    ///
+    /// This is zero again once the task resolved to a digest.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Cancel the task, returning the reader and a checkpoint from which the
+    /// computation can be resumed.
+    pub fn cancel(self) -> (R, HashCheckpoint<D>)
+    where
+        D: SerializableState,
+    {
+        let checkpoint = HashCheckpoint {
+            position: self.position,
+            state: self.hasher.serialize(),
+        };
+        (self.reader, checkpoint)
+    }
+}
+
+impl<R: AsyncRead + Unpin, D: Digest + Unpin> Future for HashTask<R, D> {
+    type Output = io::Result<Output<D>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<Output<D>>> {
+        let this = self.get_mut();
+        let mut budget = YIELD_INTERVAL;
+        loop {
+            match Pin::new(&mut this.reader).poll_read(cx, &mut this.buffer) {
+                Poll::Ready(Ok(0)) => {
+                    this.position = 0;
+                    return Poll::Ready(Ok(this.hasher.finalize_reset()));
+                }
+                Poll::Ready(Ok(n)) => {
+                    Digest::update(&mut this.hasher, &this.buffer[..n]);
+                    this.position += n as u64;
+                    if let Some(progress) = this.progress.as_mut() {
+                        progress(this.position);
+                    }
+
+                    budget = budget.saturating_sub(n as u64);
+                    if budget == 0 {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                }
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -2,9 +2,17 @@
 
 pub use blobby;
 
-use super::{ExtendableOutput, Reset, Update, VariableOutput, XofReader};
+use super::{
+    ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, KeyedInit, Reset,
+    Update, VariableOutput, XofReader,
+};
 use core::fmt::Debug;
 
+/// Maximum split position used by tests which feed input in two parts.
+///
+/// Covers block boundaries of all commonly used block sizes.
+const MAX_SPLIT: usize = 256;
+
 /// Define test
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
@@ -165,6 +173,142 @@ where
     None
 }
 
+/// Fixed-output digest test for [`FixedOutputReset`] implementations.
+///
+/// Checks that finalization with reset returns correct results and leaves
+/// the hasher in its initial state, and that input split at every position
+/// up to 256 bytes (i.e. around block boundaries) is processed correctly.
+pub fn fixed_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: Update + FixedOutputReset + Default + Debug + Clone,
+{
+    let mut hasher = D::default();
+    hasher.update(input);
+    if hasher.finalize_fixed_reset().as_slice() != output {
+        return Some("whole message with reset");
+    }
+
+    // Test that the hasher is in its initial state after reset
+    hasher.update(input);
+    if hasher.finalize_fixed_reset().as_slice() != output {
+        return Some("whole message after finalize with reset");
+    }
+
+    // Test that input split in two parts is processed correctly
+    for split in 0..=input.len().min(MAX_SPLIT) {
+        let (head, tail) = input.split_at(split);
+        hasher.update(head);
+        hasher.update(tail);
+        if hasher.finalize_fixed_reset().as_slice() != output {
+            return Some("message split in two parts");
+        }
+    }
+
+    None
+}
+
+/// Reset test for keyed hashers, e.g. MACs.
+///
+/// Checks that both [`Reset::reset`] and finalization with reset return the
+/// hasher to its keyed initial state, i.e. that the key is not lost. Outputs
+/// are compared against a freshly keyed instance, so no test vector is needed.
+pub fn keyed_reset_test<D>(key: &[u8], input: &[u8]) -> Option<&'static str>
+where
+    D: KeyedInit + Update + FixedOutputReset + Clone,
+{
+    let mut hasher = match D::new_keyed_varkey(key) {
+        Ok(hasher) => hasher,
+        Err(_) => return Some("invalid key length"),
+    };
+    let expected = hasher.clone().chain(input).finalize_fixed();
+
+    hasher.update(b"data to be discarded by reset");
+    hasher.reset();
+    hasher.update(input);
+    if hasher.clone().finalize_fixed() != expected {
+        return Some("whole message after reset");
+    }
+
+    if hasher.finalize_fixed_reset() != expected {
+        return Some("whole message with reset");
+    }
+
+    hasher.update(input);
+    if hasher.finalize_fixed_reset() != expected {
+        return Some("whole message after finalize with reset");
+    }
+
+    None
+}
+
+/// XOF test for [`ExtendableOutputReset`] implementations.
+///
+/// Checks that finalization with reset returns correct results and leaves
+/// the hasher in its initial state, and that input split at every position
+/// up to 256 bytes is processed correctly.
+pub fn xof_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: Update + ExtendableOutputReset + Default + Debug + Clone,
+{
+    let mut hasher = D::default();
+    let mut buf = [0u8; 1024];
+    let buf = &mut buf[..output.len()];
+
+    hasher.update(input);
+    hasher.finalize_xof_reset().read(buf);
+    if buf != output {
+        return Some("whole message with reset");
+    }
+
+    // Test that the hasher is in its initial state after reset
+    hasher.update(input);
+    hasher.finalize_xof_reset().read(buf);
+    if buf != output {
+        return Some("whole message after finalize with reset");
+    }
+
+    // Test that input split in two parts is processed correctly
+    for split in 0..=input.len().min(MAX_SPLIT) {
+        let (head, tail) = input.split_at(split);
+        hasher.update(head);
+        hasher.update(tail);
+        hasher.finalize_xof_reset().read(buf);
+        if buf != output {
+            return Some("message split in two parts");
+        }
+    }
+
+    None
+}
+
+/// Test `std::io::Write` implementation of a fixed-output digest.
+///
+/// Checks that data written using `write_all` in chunks of various sizes
+/// produces the same result as the expected output.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: FixedOutput + Default + Debug + std::io::Write,
+{
+    for &chunk_size in &[1, 3, 64, 1000] {
+        let mut hasher = D::default();
+        for chunk in input.chunks(chunk_size) {
+            if hasher.write_all(chunk).is_err() {
+                return Some("write error");
+            }
+        }
+        if hasher.flush().is_err() {
+            return Some("flush error");
+        }
+        if hasher.finalize_fixed().as_slice() != output {
+            return Some("message written in chunks");
+        }
+    }
+
+    None
+}
+
 /// Variable-output digest test
 pub fn variable_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
 where
@@ -1,10 +1,15 @@
-use super::{FixedOutput, Reset, Update};
+use super::{FixedOutput, FixedOutputReset, Reset, Update};
 use generic_array::typenum::Unsigned;
 use generic_array::{ArrayLength, GenericArray};
 
+#[cfg(feature = "subtle")]
+use crate::InvalidDigest;
+#[cfg(feature = "subtle")]
+use subtle::ConstantTimeEq;
+
 /// The `Digest` trait specifies an interface common for digest functions.
 ///
-/// It's a convenience wrapper around [`Update`], [`FixedOutput`], [`Reset`],
+/// It's a convenience wrapper around [`Update`], [`FixedOutputReset`], [`Reset`],
 /// [`Clone`], and [`Default`] traits. It also provides additional convenience methods.
 pub trait Digest {
     /// Output size for `Digest`
@@ -47,9 +52,18 @@ pub trait Digest {
     /// println!("{:x}", sha2::Sha256::digest(b"Hello world"));
     /// ```
     fn digest(data: &[u8]) -> Output<Self>;
+
+    /// Finalize hasher and check in constant time that the result is equal
+    /// to the `expected` digest value.
+    ///
+    /// Returns [`InvalidDigest`] if the values differ, including when
+    /// `expected` has a different length than the hasher output.
+    #[cfg(feature = "subtle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+    fn verify(self, expected: &[u8]) -> Result<(), InvalidDigest>;
 }
 
-impl<D: Update + FixedOutput + Reset + Clone + Default> Digest for D {
+impl<D: Update + FixedOutputReset + Clone + Default> Digest for D {
     type OutputSize = <Self as FixedOutput>::OutputSize;
 
     fn new() -> Self {
@@ -90,6 +104,17 @@ impl<D: Update + FixedOutput + Reset + Clone + Default> Digest for D {
         Update::update(&mut hasher, data);
         hasher.finalize_fixed()
     }
+
+    #[cfg(feature = "subtle")]
+    fn verify(self, expected: &[u8]) -> Result<(), InvalidDigest> {
+        let choice = self.finalize_fixed().as_slice().ct_eq(expected);
+
+        if choice.unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(InvalidDigest)
+        }
+    }
 }
 
 /// Output of a [`Digest`] function
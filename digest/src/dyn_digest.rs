@@ -1,7 +1,9 @@
 #![cfg(feature = "alloc")]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
-use super::{FixedOutput, Reset, Update};
+use super::{FixedOutput, FixedOutputReset, Reset, Update};
+#[cfg(feature = "multihash")]
+use crate::MultihashCode;
 use generic_array::typenum::Unsigned;
 
 /// The `DynDigest` trait is a modification of `Digest` trait suitable
@@ -29,7 +31,7 @@ pub trait DynDigest {
     fn box_clone(&self) -> Box<dyn DynDigest>;
 }
 
-impl<D: Update + FixedOutput + Reset + Clone + 'static> DynDigest for D {
+impl<D: Update + FixedOutputReset + Clone + 'static> DynDigest for D {
     fn update(&mut self, data: &[u8]) {
         Update::update(self, data);
     }
@@ -62,3 +64,115 @@ impl Clone for Box<dyn DynDigest> {
         self.box_clone()
     }
 }
+
+/// Constructor of a boxed [`DynDigest`] instance.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type DynDigestCtor = fn() -> Box<dyn DynDigest>;
+
+/// Name-keyed registry of [`DynDigest`] constructors.
+///
+/// Allows applications to select a hash function at runtime, e.g. from
+/// a configuration string, without matching on every supported algorithm:
+///
+/// ```rust,ignore
+/// let mut registry = DynDigestRegistry::new();
+/// registry
+///     .register::<sha2::Sha256>("sha256")
+///     .register::<sha2::Sha512>("sha512");
+///
+/// let mut hasher = registry.get("SHA256").expect("unknown hash function");
+/// hasher.update(b"Hello world");
+/// let hash = hasher.finalize();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default)]
+pub struct DynDigestRegistry {
+    entries: Vec<(&'static str, DynDigestCtor)>,
+    #[cfg(feature = "multihash")]
+    multihash_codes: Vec<(u64, DynDigestCtor)>,
+}
+
+impl DynDigestRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register hash function `D` under the given name.
+    ///
+    /// Names are compared case-insensitively (ASCII only). Registering
+    /// a name which is already present replaces the previous entry.
+    pub fn register<D>(&mut self, name: &'static str) -> &mut Self
+    where
+        D: DynDigest + Default + 'static,
+    {
+        let ctor: DynDigestCtor = new_boxed::<D>;
+        match self.position(name) {
+            Some(i) => self.entries[i] = (name, ctor),
+            None => self.entries.push((name, ctor)),
+        }
+        self
+    }
+
+    /// Register hash function `D` under the given name and its multihash
+    /// code, e.g. for verifying [`Multihash`] values.
+    ///
+    /// Registering a code which is already present replaces the previous
+    /// entry for that code.
+    ///
+    /// [`Multihash`]: crate::multihash::Multihash
+    #[cfg(feature = "multihash")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "multihash")))]
+    pub fn register_multihash<D>(&mut self, name: &'static str) -> &mut Self
+    where
+        D: DynDigest + Default + MultihashCode + 'static,
+    {
+        let ctor: DynDigestCtor = new_boxed::<D>;
+        let code = D::MULTIHASH_CODE;
+        match self.multihash_codes.iter().position(|(c, _)| *c == code) {
+            Some(i) => self.multihash_codes[i] = (code, ctor),
+            None => self.multihash_codes.push((code, ctor)),
+        }
+        self.register::<D>(name)
+    }
+
+    /// Create new boxed hasher instance registered under the given multihash
+    /// code.
+    ///
+    /// Returns `None` if no hash function with this code is registered.
+    #[cfg(feature = "multihash")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "multihash")))]
+    pub fn get_by_multihash_code(&self, code: u64) -> Option<Box<dyn DynDigest>> {
+        self.multihash_codes
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, ctor)| ctor())
+    }
+
+    /// Create new boxed hasher instance registered under the given name.
+    ///
+    /// Returns `None` if no hash function with this name is registered.
+    pub fn get(&self, name: &str) -> Option<Box<dyn DynDigest>> {
+        self.position(name).map(|i| (self.entries[i].1)())
+    }
+
+    /// Is a hash function with the given name registered?
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Iterate over names of registered hash functions.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.iter().map(|(name, _)| *name)
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+    }
+}
+
+fn new_boxed<D: DynDigest + Default + 'static>() -> Box<dyn DynDigest> {
+    Box::new(D::default())
+}
@@ -0,0 +1,68 @@
+use super::{FixedOutput, FixedOutputReset, InvalidOutputSize, Reset, Update};
+use generic_array::{typenum::Unsigned, GenericArray};
+
+/// Object-safe subset of the [`Digest`][crate::Digest] API which does not
+/// require an allocator.
+///
+/// Unlike [`DynDigest`][crate::DynDigest], none of the methods box the hasher
+/// or its output, so this trait can be used with `&mut dyn DynDigestMut`
+/// references to stack-allocated or `static` hashers, e.g. for runtime hash
+/// function selection in `no_std` firmware:
+///
+/// ```rust,ignore
+/// fn hash_with(name: &str, data: &[u8], out: &mut [u8; 64]) -> Option<usize> {
+///     let (mut sha256, mut sha512) = (Sha256::default(), Sha512::default());
+///     let hasher: &mut dyn DynDigestMut = match name {
+///         "sha256" => &mut sha256,
+///         "sha512" => &mut sha512,
+///         _ => return None,
+///     };
+///
+///     let out = &mut out[..hasher.output_size()];
+///     hasher.update(data);
+///     hasher.finalize_into_slice(out).ok()?;
+///     Some(out.len())
+/// }
+/// ```
+pub trait DynDigestMut {
+    /// Digest input data.
+    ///
+    /// This method can be called repeatedly for use with streaming messages.
+    fn update(&mut self, data: &[u8]);
+
+    /// Write result into `out` and reset hasher instance.
+    ///
+    /// Returns an error if the length of `out` is not equal to
+    /// [`output_size`][DynDigestMut::output_size]; the hasher state is left
+    /// unchanged in that case.
+    fn finalize_into_slice(&mut self, out: &mut [u8]) -> Result<(), InvalidOutputSize>;
+
+    /// Reset hasher instance to its initial state.
+    fn reset(&mut self);
+
+    /// Get output size of the hasher
+    fn output_size(&self) -> usize;
+}
+
+impl<D: Update + FixedOutputReset> DynDigestMut for D {
+    fn update(&mut self, data: &[u8]) {
+        Update::update(self, data);
+    }
+
+    fn finalize_into_slice(&mut self, out: &mut [u8]) -> Result<(), InvalidOutputSize> {
+        if out.len() != self.output_size() {
+            return Err(InvalidOutputSize);
+        }
+
+        self.finalize_into_reset(GenericArray::from_mut_slice(out));
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        Reset::reset(self);
+    }
+
+    fn output_size(&self) -> usize {
+        <Self as FixedOutput>::OutputSize::to_usize()
+    }
+}
@@ -12,3 +12,81 @@ impl fmt::Display for InvalidOutputSize {
 
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidOutputSize {}
+
+/// The error type for keyed hasher initialization
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InvalidKeyLength;
+
+impl fmt::Display for InvalidKeyLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid key length")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidKeyLength {}
+
+/// The error type for parameterized hasher initialization
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InvalidParams;
+
+impl fmt::Display for InvalidParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid hasher parameters")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidParams {}
+
+/// The error type for restoring hasher from a serialized state
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeserializeStateError;
+
+impl fmt::Display for DeserializeStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to deserialize hasher state")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeserializeStateError {}
+
+/// The error type for signaling failed digest verification
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InvalidDigest;
+
+impl fmt::Display for InvalidDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("digest mismatch")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDigest {}
+
+/// The error type for decoding or constructing a multihash
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InvalidMultihash;
+
+impl fmt::Display for InvalidMultihash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid multihash")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidMultihash {}
+
+/// The error type for proof-of-work difficulties which can't be satisfied
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InvalidDifficulty;
+
+impl fmt::Display for InvalidDifficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid proof-of-work difficulty")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDifficulty {}
@@ -11,9 +11,6 @@ pub trait FixedOutput {
     /// Write result into provided array and consume the hasher instance.
     fn finalize_into(self, out: &mut GenericArray<u8, Self::OutputSize>);
 
-    /// Write result into provided array and reset the hasher instance.
-    fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, Self::OutputSize>);
-
     /// Retrieve result and consume the hasher instance.
     #[inline]
     fn finalize_fixed(self) -> GenericArray<u8, Self::OutputSize>
@@ -24,6 +21,16 @@ pub trait FixedOutput {
         self.finalize_into(&mut out);
         out
     }
+}
+
+/// Trait for fixed-output digests which can be finalized without being
+/// consumed, resetting the hasher instance instead.
+///
+/// Hashers which can not be cheaply reset (e.g. one-shot keyed
+/// constructions) can implement [`FixedOutput`] only.
+pub trait FixedOutputReset: FixedOutput + Reset {
+    /// Write result into provided array and reset the hasher instance.
+    fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, Self::OutputSize>);
 
     /// Retrieve result and reset the hasher instance.
     #[inline]
@@ -38,11 +45,11 @@ pub trait FixedOutput {
 /// hash output.
 ///
 /// Usage of this trait in user code is discouraged. Instead use the
-/// [`FixedOutput::finalize_fixed`] or [`FixedOutput::finalize_fixed_reset`]
+/// [`FixedOutput::finalize_fixed`] or [`FixedOutputReset::finalize_fixed_reset`]
 /// methods.
 ///
-/// Types which impl this trait along with [`Reset`] will receive a blanket
-/// impl of [`FixedOutput`].
+/// Types which impl this trait will receive a blanket impl of [`FixedOutput`],
+/// and along with [`Reset`] a blanket impl of [`FixedOutputReset`].
 pub trait FixedOutputDirty {
     /// Output size for fixed output digest
     type OutputSize: ArrayLength<u8>;
@@ -55,14 +62,16 @@ pub trait FixedOutputDirty {
     fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, Self::OutputSize>);
 }
 
-impl<D: FixedOutputDirty + Reset> FixedOutput for D {
+impl<D: FixedOutputDirty> FixedOutput for D {
     type OutputSize = D::OutputSize;
 
     #[inline]
     fn finalize_into(mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
         self.finalize_into_dirty(out);
     }
+}
 
+impl<D: FixedOutputDirty + Reset> FixedOutputReset for D {
     #[inline]
     fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
         self.finalize_into_dirty(out);
@@ -0,0 +1,192 @@
+//! Generic HMAC implementation
+
+use crate::{
+    BlockInput, FixedOutput, FixedOutputReset, InvalidKeyLength, Key, KeyedInit, Reset, Update,
+};
+use core::fmt;
+use crypto_mac::{Mac, NewMac, Output};
+use generic_array::typenum::Unsigned;
+use generic_array::{sequence::GenericSequence, GenericArray};
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5C;
+
+/// Generic [HMAC] instance over the hash function `D`, as defined in
+/// [RFC 2104].
+///
+/// Implements [`Mac`] and [`NewMac`] from the `crypto-mac` crate, e.g.
+/// `Hmac::<Sha256>::new_varkey(key)`, as well as the hashing traits of this
+/// crate.
+///
+/// Output size of `D` must not be bigger than its block size, which holds
+/// for all commonly used hash functions.
+///
+/// [HMAC]: https://en.wikipedia.org/wiki/HMAC
+/// [RFC 2104]: https://tools.ietf.org/html/rfc2104
+#[cfg_attr(docsrs, doc(cfg(feature = "mac")))]
+#[derive(Clone)]
+pub struct Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    digest: D,
+    ipad_digest: D,
+    opad_digest: D,
+}
+
+impl<D> KeyedInit for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    type KeySize = D::BlockSize;
+
+    fn new_keyed(key: &Key<Self>) -> Self {
+        Self::with_key(key)
+    }
+
+    /// Keys of any length are accepted: keys longer than the block size
+    /// are hashed first, as specified by RFC 2104.
+    fn new_keyed_varkey(key: &[u8]) -> Result<Self, InvalidKeyLength> {
+        Ok(Self::with_key(key))
+    }
+}
+
+impl<D> Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    fn with_key(key: &[u8]) -> Self {
+        let mut ipad = GenericArray::<u8, D::BlockSize>::generate(|_| IPAD);
+        let mut opad = GenericArray::<u8, D::BlockSize>::generate(|_| OPAD);
+
+        if key.len() <= D::BlockSize::to_usize() {
+            xor(&mut ipad, key);
+            xor(&mut opad, key);
+        } else {
+            let mut hasher = D::default();
+            hasher.update(key);
+            let key = hasher.finalize_fixed();
+            xor(&mut ipad, &key);
+            xor(&mut opad, &key);
+        }
+
+        let mut ipad_digest = D::default();
+        ipad_digest.update(&ipad);
+        let mut opad_digest = D::default();
+        opad_digest.update(&opad);
+
+        Self {
+            digest: ipad_digest.clone(),
+            ipad_digest,
+            opad_digest,
+        }
+    }
+}
+
+impl<D> Update for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    #[inline]
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.digest.update(data);
+    }
+
+    #[inline]
+    fn update_vectored(&mut self, bufs: &[&[u8]]) {
+        self.digest.update_vectored(bufs);
+    }
+}
+
+impl<D> FixedOutput for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    type OutputSize = D::OutputSize;
+
+    fn finalize_into(self, out: &mut GenericArray<u8, Self::OutputSize>) {
+        let mut opad_digest = self.opad_digest;
+        opad_digest.update(self.digest.finalize_fixed());
+        opad_digest.finalize_into(out);
+    }
+}
+
+impl<D> FixedOutputReset for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
+        let digest = core::mem::replace(&mut self.digest, self.ipad_digest.clone());
+        let mut opad_digest = self.opad_digest.clone();
+        opad_digest.update(digest.finalize_fixed());
+        opad_digest.finalize_into(out);
+    }
+}
+
+impl<D> Reset for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    /// Restore the keyed initial state without processing the key again.
+    fn reset(&mut self) {
+        self.digest = self.ipad_digest.clone();
+    }
+}
+
+impl<D> NewMac for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    type KeySize = D::BlockSize;
+
+    fn new(key: &crypto_mac::Key<Self>) -> Self {
+        Self::with_key(key)
+    }
+
+    /// Keys of any length are accepted, see [`KeyedInit::new_keyed_varkey`].
+    fn new_varkey(key: &[u8]) -> Result<Self, crypto_mac::InvalidKeyLength> {
+        Ok(Self::with_key(key))
+    }
+}
+
+impl<D> Mac for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    type OutputSize = D::OutputSize;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Update::update(self, data);
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        Reset::reset(self);
+    }
+
+    #[inline]
+    fn finalize(self) -> Output<Self> {
+        Output::new(self.finalize_fixed())
+    }
+
+    #[inline]
+    fn finalize_reset(&mut self) -> Output<Self> {
+        Output::new(self.finalize_fixed_reset())
+    }
+}
+
+impl<D> fmt::Debug for Hmac<D>
+where
+    D: Update + BlockInput + FixedOutputReset + Default + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Hmac { ... }")
+    }
+}
+
+fn xor(pad: &mut [u8], key: &[u8]) {
+    for (p, k) in pad.iter_mut().zip(key.iter()) {
+        *p ^= *k;
+    }
+}
@@ -0,0 +1,44 @@
+//! Keyed and customized hasher initialization support
+
+use crate::InvalidKeyLength;
+use generic_array::typenum::Unsigned;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Key used by a [`KeyedInit`] hasher.
+pub type Key<D> = GenericArray<u8, <D as KeyedInit>::KeySize>;
+
+/// Trait for hash functions which can be initialized with a secret key,
+/// e.g. BLAKE2 in keyed mode or KMAC.
+///
+/// Keyed hashers still implement the usual [`Update`][crate::Update] and
+/// [`FixedOutput`][crate::FixedOutput] (or [`ExtendableOutput`][crate::ExtendableOutput])
+/// traits, so once constructed they can be used like any other hasher.
+pub trait KeyedInit: Sized {
+    /// Key size in bytes with which hasher is guaranteed to be initialized.
+    type KeySize: ArrayLength<u8>;
+
+    /// Create new hasher instance from key with fixed size.
+    fn new_keyed(key: &Key<Self>) -> Self;
+
+    /// Create new hasher instance from key with variable size.
+    ///
+    /// Default implementation will accept only keys with length equal to
+    /// `KeySize`, but some hashers can accept range of key lengths.
+    fn new_keyed_varkey(key: &[u8]) -> Result<Self, InvalidKeyLength> {
+        if key.len() != Self::KeySize::to_usize() {
+            Err(InvalidKeyLength)
+        } else {
+            Ok(Self::new_keyed(GenericArray::from_slice(key)))
+        }
+    }
+}
+
+/// Trait for hash functions which can be initialized with a customization
+/// string, e.g. cSHAKE or BLAKE2 with personalization.
+///
+/// Hashers with different customization strings are expected to produce
+/// unrelated outputs for the same input data.
+pub trait CustomizedInit: Sized {
+    /// Create new hasher instance with the given customization string.
+    fn new_customized(customization: &[u8]) -> Self;
+}
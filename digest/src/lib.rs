@@ -5,11 +5,16 @@
 //! mid-level traits which expose more fine-grained functionality, and
 //! low-level traits intended to only be used by algorithm implementations:
 //!
-//! - **High-level convenience traits**: [`Digest`], [`DynDigest`]. They are wrappers
-//!   around lower-level traits for most common hash-function use-cases.
-//! - **Mid-level traits**: [`Update`], [`BlockInput`], [`Reset`], [`FixedOutput`],
-//!   [`VariableOutput`], [`ExtendableOutput`]. These traits atomically describe
-//!   available functionality of hash function implementations.
+//! - **High-level convenience traits**: [`Digest`], [`DynDigest`],
+//!   [`DynDigestMut`], [`Mac`]. They are wrappers around lower-level traits
+//!   for most common hash-function use-cases.
+//! - **Mid-level traits**: [`Update`], [`BlockInput`], [`BudgetedUpdate`],
+//!   [`Reset`], [`FixedOutput`], [`FixedOutputReset`], [`VariableOutput`],
+//!   [`ExtendableOutput`],
+//!   [`ExtendableOutputReset`], [`ExtendableOutputCustomized`], [`KeyedInit`],
+//!   [`CustomizedInit`], [`InitWithParams`], [`SerializableState`],
+//!   [`ParallelDigest`]. These traits atomically describe available
+//!   functionality of hash function implementations.
 //! - **Low-level traits**: [`FixedOutputDirty`], [`VariableOutputDirty`],
 //!   [`ExtendableOutputDirty`]. These traits are intended to be implemented by
 //!   low-level algorithm providers only and simplify the amount of work
@@ -18,9 +23,11 @@
 //!
 //! Additionally hash functions implement traits from the standard library:
 //! `Default`, `Clone`, `Write`. The latter is feature-gated behind `std` feature,
-//! which is usually enabled by default by hash implementation crates.
+//! which is usually enabled by default by hash implementation crates. Similarly,
+//! XOF readers implement `Read` (see [`impl_xof_read!`]).
 //!
-//! The [`Digest`] trait is the most commonly used trait.
+//! The [`Digest`] trait is the most commonly used trait. Hash functions with
+//! truncated output can be constructed using the [`Truncated`] wrapper.
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -42,24 +49,76 @@ extern crate std;
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 pub mod dev;
 
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod async_io;
+
+#[cfg(feature = "multihash")]
+#[cfg_attr(docsrs, doc(cfg(feature = "multihash")))]
+pub mod multihash;
+
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub mod oid;
+
+#[cfg(feature = "pow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pow")))]
+pub mod pow;
+
 mod digest;
 mod dyn_digest;
+mod dyn_digest_mut;
 mod errors;
 mod fixed;
+#[cfg(feature = "mac")]
+mod hmac;
+mod keyed;
+mod parallel;
+mod params;
+mod state;
+mod truncated;
 mod variable;
 mod xof;
 
 pub use crate::digest::{Digest, Output};
-pub use crate::errors::InvalidOutputSize;
-pub use crate::fixed::{FixedOutput, FixedOutputDirty};
+pub use crate::dyn_digest_mut::DynDigestMut;
+pub use crate::errors::{
+    DeserializeStateError, InvalidDifficulty, InvalidDigest, InvalidKeyLength, InvalidMultihash,
+    InvalidOutputSize, InvalidParams,
+};
+pub use crate::fixed::{FixedOutput, FixedOutputDirty, FixedOutputReset};
+pub use crate::keyed::{CustomizedInit, Key, KeyedInit};
+pub use crate::parallel::{LaneOutput, ParOutput, ParallelDigest};
+pub use crate::params::{DigestBuilder, InitWithParams};
+pub use crate::state::{SerializableState, SerializedState};
+pub use crate::truncated::Truncated;
 pub use crate::variable::{VariableOutput, VariableOutputDirty};
-pub use crate::xof::{ExtendableOutput, ExtendableOutputDirty, XofReader};
+pub use crate::xof::{
+    ExtendableOutput, ExtendableOutputCustomized, ExtendableOutputDirty, ExtendableOutputReset,
+    XofReader,
+};
 pub use generic_array::{self, typenum::consts};
 
+#[cfg(feature = "bytes")]
+pub use bytes;
+#[cfg(feature = "oid")]
+pub use const_oid;
+#[cfg(feature = "mac")]
+pub use crypto_mac::{self, Mac, MacError, NewMac};
 #[cfg(feature = "alloc")]
-pub use dyn_digest::DynDigest;
+pub use dyn_digest::{DynDigest, DynDigestCtor, DynDigestRegistry};
+#[cfg(feature = "mac")]
+pub use hmac::Hmac;
+#[cfg(feature = "multihash")]
+pub use multihash::MultihashCode;
+#[cfg(feature = "oid")]
+pub use oid::AssociatedOid;
+#[cfg(feature = "subtle")]
+pub use subtle;
+#[cfg(feature = "zeroize")]
+pub use zeroize;
 
-use generic_array::ArrayLength;
+use generic_array::{typenum::Unsigned, ArrayLength};
 
 /// Trait for updating digest state with input data.
 pub trait Update {
@@ -77,6 +136,34 @@ pub trait Update {
         self.update(data);
         self
     }
+
+    /// Digest data from several non-contiguous buffers in order, e.g.
+    /// segments of scatter/gather I/O.
+    ///
+    /// Equivalent to calling [`update`][Update::update] for each buffer.
+    /// Implementations can override this method to avoid copying partial
+    /// blocks at segment boundaries.
+    fn update_vectored(&mut self, bufs: &[&[u8]]) {
+        for buf in bufs {
+            self.update(buf);
+        }
+    }
+
+    /// Digest all remaining data in the given [`Buf`][bytes::Buf].
+    ///
+    /// Non-contiguous buffers (e.g. chains of network buffers) are processed
+    /// chunk by chunk without copying them into a contiguous buffer. Pass
+    /// `&mut buf` to keep using the (fully advanced) buffer afterwards.
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    fn update_buf(&mut self, mut buf: impl bytes::Buf) {
+        while buf.has_remaining() {
+            let chunk = buf.chunk();
+            let len = chunk.len();
+            self.update(chunk);
+            buf.advance(len);
+        }
+    }
 }
 
 /// Trait to indicate that digest function processes data in blocks of size
@@ -88,12 +175,93 @@ pub trait BlockInput {
     type BlockSize: ArrayLength<u8>;
 }
 
+/// Trait for digesting input incrementally with a bounded amount of work
+/// per call.
+///
+/// This allows callers which must not block for long periods, e.g. WASM
+/// code running on the browser main thread, to interleave hashing of large
+/// inputs with yielding to the event loop, without splitting the input into
+/// smaller chunks themselves.
+///
+/// Blanket implemented for all [`Update`] + [`BlockInput`] implementations.
+///
+/// ```rust
+/// use digest::{consts::U64, BlockInput, BudgetedUpdate, Update};
+///
+/// # #[derive(Default)]
+/// # struct Toy(u8);
+/// # impl Update for Toy {
+/// #     fn update(&mut self, data: impl AsRef<[u8]>) {
+/// #         self.0 = data.as_ref().iter().fold(self.0, |acc, b| acc ^ b);
+/// #     }
+/// # }
+/// # impl BlockInput for Toy {
+/// #     type BlockSize = U64;
+/// # }
+/// let data = [0u8; 1000];
+/// let mut hasher = Toy::default();
+/// let mut pos = 0;
+///
+/// while pos < data.len() {
+///     pos += hasher.update_budgeted(&data[pos..], 4);
+///     // yield to the event loop here
+/// }
+/// ```
+pub trait BudgetedUpdate: Update + BlockInput {
+    /// Digest at most `max_blocks` blocks of `data` and return the number of
+    /// bytes consumed.
+    ///
+    /// Consumed bytes are a prefix of `data`: the caller should pass the
+    /// remaining `&data[consumed..]` to the next call. Returns `0` only if
+    /// `data` is empty or `max_blocks` is `0`.
+    fn update_budgeted(&mut self, data: &[u8], max_blocks: usize) -> usize {
+        let budget = max_blocks.saturating_mul(Self::BlockSize::to_usize());
+        let n = core::cmp::min(budget, data.len());
+        self.update(&data[..n]);
+        n
+    }
+}
+
+impl<D: Update + BlockInput> BudgetedUpdate for D {}
+
 /// Trait for resetting hash instances
+///
+/// Keyed hashers (e.g. [`KeyedInit`] implementations such as HMAC) must
+/// reset to their keyed initial state, i.e. the state right after they were
+/// created with the key, not to the unkeyed default state. Implementations
+/// are encouraged to store a copy of the keyed state, so that resetting
+/// doesn't repeat key setup.
 pub trait Reset {
     /// Reset hasher instance to its initial state and return current state.
     fn reset(&mut self);
 }
 
+/// Trait for hashers which can be initialized in const contexts.
+///
+/// This allows pre-initialized hashers to be stored in `static`s, e.g. in
+/// `no_std` firmware, without relying on lazy initialization:
+///
+/// ```rust,ignore
+/// static HASHER: Sha256 = Sha256::INIT;
+/// ```
+///
+/// `INIT` must be equal to the value returned by `Default::default()`.
+pub trait ConstInit: Sized {
+    /// Hasher instance in its initial state.
+    const INIT: Self;
+}
+
+/// Marker trait for hashers whose internal state is wiped when dropped.
+///
+/// Implementers must zeroize all of their state in `Drop`, including any
+/// buffered input which was not yet processed, and must also implement
+/// [`Zeroize`][zeroize::Zeroize] so state can be wiped explicitly. This
+/// allows generic code which hashes secrets (e.g. HMAC keys) to require
+/// wipeable state via a trait bound.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub trait ZeroizingHasher: zeroize::Zeroize {}
+
 #[macro_export]
 /// Implements `std::io::Write` trait for implementer of [`Update`]
 macro_rules! impl_write {
@@ -111,3 +279,17 @@ macro_rules! impl_write {
         }
     };
 }
+
+#[macro_export]
+/// Implements `std::io::Read` trait for implementer of [`XofReader`]
+macro_rules! impl_xof_read {
+    ($reader:ident) => {
+        #[cfg(feature = "std")]
+        impl std::io::Read for $reader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                XofReader::read(self, buf);
+                Ok(buf.len())
+            }
+        }
+    };
+}
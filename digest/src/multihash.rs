@@ -0,0 +1,337 @@
+//! Self-describing digests in the [multihash] format.
+//!
+//! A multihash is the concatenation of:
+//!
+//! - the multicodec code of the hash function, as an unsigned varint
+//! - the length of the digest in bytes, as an unsigned varint
+//! - the digest itself
+//!
+//! This allows content-addressed storage layers to mix hash functions, e.g.
+//! while migrating to a new one, without ambiguity about which function
+//! produced a given digest.
+//!
+//! [multihash]: https://multiformats.io/multihash/
+
+use crate::{Digest, InvalidDigest, InvalidMultihash, Output};
+use core::fmt;
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "alloc")]
+use {
+    crate::DynDigestRegistry,
+    alloc::{boxed::Box, vec::Vec},
+};
+
+/// Maximum supported digest length in bytes.
+pub const MAX_DIGEST_LEN: usize = 64;
+
+/// Maximum length of an unsigned varint in bytes, as specified by
+/// multiformats (i.e. values are limited to 63 bits).
+const MAX_VARINT_LEN: usize = 9;
+
+/// Maximum length of an encoded [`Multihash`] in bytes.
+pub const MAX_ENCODED_LEN: usize = 2 * MAX_VARINT_LEN + MAX_DIGEST_LEN;
+
+/// Associate a multicodec code with a hash function.
+///
+/// Codes are listed in the [multicodec table], e.g. `0x12` for SHA-256.
+///
+/// [multicodec table]: https://github.com/multiformats/multicodec/blob/master/table.csv
+pub trait MultihashCode {
+    /// Multicodec code of the hash function.
+    const MULTIHASH_CODE: u64;
+}
+
+/// Digest tagged with the code of the hash function which produced it.
+///
+/// Digests of up to [`MAX_DIGEST_LEN`] bytes are stored inline, so this type
+/// doesn't require an allocator.
+///
+/// ```rust
+/// use digest::multihash::Multihash;
+///
+/// let multihash = Multihash::new(0x12, &[0xAB; 32]).unwrap();
+///
+/// let mut buf = [0u8; 64];
+/// let encoded = multihash.encode(&mut buf).unwrap();
+/// assert_eq!(&encoded[..2], &[0x12, 0x20]);
+///
+/// let decoded = Multihash::decode(encoded).unwrap();
+/// assert_eq!(decoded, multihash);
+/// assert_eq!(decoded.code(), 0x12);
+/// assert_eq!(decoded.digest(), &[0xAB; 32][..]);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Multihash {
+    code: u64,
+    length: usize,
+    bytes: [u8; MAX_DIGEST_LEN],
+}
+
+impl Multihash {
+    /// Create a [`Multihash`] from a hash function code and digest.
+    ///
+    /// Returns [`InvalidMultihash`] if the code doesn't fit in a multiformats
+    /// varint or the digest is longer than [`MAX_DIGEST_LEN`].
+    pub fn new(code: u64, digest: &[u8]) -> Result<Self, InvalidMultihash> {
+        if code >> 63 != 0 || digest.len() > MAX_DIGEST_LEN {
+            return Err(InvalidMultihash);
+        }
+
+        let mut bytes = [0u8; MAX_DIGEST_LEN];
+        bytes[..digest.len()].copy_from_slice(digest);
+
+        Ok(Self {
+            code,
+            length: digest.len(),
+            bytes,
+        })
+    }
+
+    /// Create a [`Multihash`] from the output of the hash function `D`.
+    ///
+    /// # Panics
+    ///
+    /// If the output size of `D` exceeds [`MAX_DIGEST_LEN`].
+    pub fn from_output<D: Digest + MultihashCode>(output: &Output<D>) -> Self {
+        Self::new(D::MULTIHASH_CODE, output).expect("digest output too long for multihash")
+    }
+
+    /// Compute the [`Multihash`] of `data` using the hash function `D`.
+    pub fn hash<D: Digest + MultihashCode>(data: impl AsRef<[u8]>) -> Self {
+        Self::from_output::<D>(&D::digest(data.as_ref()))
+    }
+
+    /// Multicodec code of the hash function.
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    /// Digest bytes.
+    pub fn digest(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+
+    /// Length of the encoded [`Multihash`] in bytes.
+    pub fn encoded_len(&self) -> usize {
+        varint_len(self.code) + varint_len(self.length as u64) + self.length
+    }
+
+    /// Encode this [`Multihash`] into the provided buffer, returning the
+    /// encoded prefix of the buffer.
+    ///
+    /// Returns [`InvalidMultihash`] if the buffer is too small.
+    pub fn encode<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8], InvalidMultihash> {
+        let len = self.encoded_len();
+        if buf.len() < len {
+            return Err(InvalidMultihash);
+        }
+
+        let mut pos = write_varint(self.code, buf);
+        pos += write_varint(self.length as u64, &mut buf[pos..]);
+        buf[pos..len].copy_from_slice(self.digest());
+        Ok(&buf[..len])
+    }
+
+    /// Encode this [`Multihash`] into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut buf = vec![0u8; self.encoded_len()];
+        self.encode(&mut buf)
+            .expect("buffer sized to encoded length");
+        buf
+    }
+
+    /// Decode a [`Multihash`] which spans the whole input.
+    ///
+    /// Returns [`InvalidMultihash`] if the input is malformed, uses
+    /// non-minimal varints, or has trailing data.
+    pub fn decode(bytes: &[u8]) -> Result<Self, InvalidMultihash> {
+        match Self::read(bytes)? {
+            (multihash, []) => Ok(multihash),
+            _ => Err(InvalidMultihash),
+        }
+    }
+
+    /// Decode a [`Multihash`] from the start of the input, returning it
+    /// along with the remaining bytes.
+    pub fn read(bytes: &[u8]) -> Result<(Self, &[u8]), InvalidMultihash> {
+        let (code, bytes) = read_varint(bytes)?;
+        let (length, bytes) = read_varint(bytes)?;
+
+        if length > bytes.len() as u64 {
+            return Err(InvalidMultihash);
+        }
+
+        let (digest, rest) = bytes.split_at(length as usize);
+        Ok((Self::new(code, digest)?, rest))
+    }
+
+    /// Verify that this [`Multihash`] is the digest of `data` computed using
+    /// the hash function `D`.
+    ///
+    /// The digest must be the full output of `D`; use
+    /// [`Multihash::verify_truncated_with`] to accept truncated digests.
+    /// The digest comparison is constant time.
+    pub fn verify_with<D: Digest + MultihashCode>(
+        &self,
+        data: impl AsRef<[u8]>,
+    ) -> Result<(), InvalidDigest> {
+        let output = self.hash_with::<D>(data)?;
+        self.verify_output(&output, output.len())
+    }
+
+    /// Verify that this [`Multihash`] is the digest of `data` computed using
+    /// the hash function `D`, accepting digests truncated to at least
+    /// `min_len` bytes as permitted by the multihash format.
+    ///
+    /// Empty digests are always rejected. Callers should pick `min_len`
+    /// according to the security level they require, since short digests
+    /// are easy to forge.
+    pub fn verify_truncated_with<D: Digest + MultihashCode>(
+        &self,
+        data: impl AsRef<[u8]>,
+        min_len: usize,
+    ) -> Result<(), InvalidDigest> {
+        self.verify_output(&self.hash_with::<D>(data)?, min_len)
+    }
+
+    /// Verify that this [`Multihash`] is the digest of `data`, using the hash
+    /// function registered for its code in `registry`.
+    ///
+    /// Returns [`InvalidDigest`] if no hash function is registered for the
+    /// code. See [`DynDigestRegistry::register_multihash`] and
+    /// [`Multihash::verify_with`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn verify(&self, registry: &DynDigestRegistry, data: &[u8]) -> Result<(), InvalidDigest> {
+        let output = self.hash_registered(registry, data)?;
+        self.verify_output(&output, output.len())
+    }
+
+    /// Verify that this [`Multihash`] is the digest of `data`, using the hash
+    /// function registered for its code in `registry` and accepting digests
+    /// truncated to at least `min_len` bytes.
+    ///
+    /// See [`Multihash::verify`] and [`Multihash::verify_truncated_with`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn verify_truncated(
+        &self,
+        registry: &DynDigestRegistry,
+        data: &[u8],
+        min_len: usize,
+    ) -> Result<(), InvalidDigest> {
+        self.verify_output(&self.hash_registered(registry, data)?, min_len)
+    }
+
+    /// Hash `data` using `D`, checking that it matches the code.
+    fn hash_with<D: Digest + MultihashCode>(
+        &self,
+        data: impl AsRef<[u8]>,
+    ) -> Result<Output<D>, InvalidDigest> {
+        if self.code != D::MULTIHASH_CODE {
+            return Err(InvalidDigest);
+        }
+
+        Ok(D::digest(data.as_ref()))
+    }
+
+    /// Hash `data` using the hash function registered for the code.
+    #[cfg(feature = "alloc")]
+    fn hash_registered(
+        &self,
+        registry: &DynDigestRegistry,
+        data: &[u8],
+    ) -> Result<Box<[u8]>, InvalidDigest> {
+        let mut hasher = registry
+            .get_by_multihash_code(self.code)
+            .ok_or(InvalidDigest)?;
+
+        hasher.update(data);
+        Ok(hasher.finalize())
+    }
+
+    /// Compare the digest against a prefix of the full hash function output,
+    /// requiring it to be at least `min_len` bytes long.
+    fn verify_output(&self, output: &[u8], min_len: usize) -> Result<(), InvalidDigest> {
+        if self.length == 0 || self.length < min_len || self.length > output.len() {
+            return Err(InvalidDigest);
+        }
+
+        if output[..self.length].ct_eq(self.digest()).into() {
+            Ok(())
+        } else {
+            Err(InvalidDigest)
+        }
+    }
+}
+
+impl AsRef<[u8]> for Multihash {
+    fn as_ref(&self) -> &[u8] {
+        self.digest()
+    }
+}
+
+impl fmt::Debug for Multihash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Multihash")
+            .field("code", &self.code)
+            .field("digest", &self.digest())
+            .finish()
+    }
+}
+
+impl Eq for Multihash {}
+
+impl PartialEq for Multihash {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.digest() == other.digest()
+    }
+}
+
+/// Number of bytes used by the unsigned varint encoding of `value`.
+fn varint_len(mut value: u64) -> usize {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// Write the unsigned varint encoding of `value`, returning its length.
+///
+/// The buffer must be at least [`varint_len`] bytes long.
+fn write_varint(mut value: u64, buf: &mut [u8]) -> usize {
+    let mut pos = 0;
+    while value >= 0x80 {
+        buf[pos] = value as u8 | 0x80;
+        value >>= 7;
+        pos += 1;
+    }
+    buf[pos] = value as u8;
+    pos + 1
+}
+
+/// Read a minimally encoded unsigned varint of at most [`MAX_VARINT_LEN`]
+/// bytes, returning its value and the remaining input.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), InvalidMultihash> {
+    let mut value = 0u64;
+
+    for (i, &byte) in bytes.iter().enumerate().take(MAX_VARINT_LEN) {
+        value |= u64::from(byte & 0x7F) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            // Reject trailing zero groups, i.e. non-minimal encodings
+            if byte == 0 && i > 0 {
+                return Err(InvalidMultihash);
+            }
+
+            return Ok((value, &bytes[i + 1..]));
+        }
+    }
+
+    Err(InvalidMultihash)
+}
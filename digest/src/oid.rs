@@ -0,0 +1,170 @@
+//! Object identifier (OID) support
+//!
+//! Note that enabling the `oid` feature raises MSRV to 1.46, as required by
+//! the [`const_oid`] crate.
+
+use crate::{Digest, Output};
+use const_oid::ObjectIdentifier;
+use generic_array::typenum::Unsigned;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Maximum length of a DER-encoded `DigestInfo` prefix in bytes.
+pub const DIGEST_INFO_PREFIX_MAX_LEN: usize =
+    2 * MAX_DER_LEN_SIZE + 1 + 1 + 2 + const_oid::MAX_LEN + 2 + 1;
+
+/// Maximum number of bytes used by the DER encoding of a `usize` length.
+const MAX_DER_LEN_SIZE: usize = 1 + core::mem::size_of::<usize>();
+
+/// Associate an [`ObjectIdentifier`] (OID) with a hash function.
+///
+/// Hash functions implementing this trait can be used to generically build
+/// `DigestInfo` structures (see [`DigestInfoPrefix`]) and X.509
+/// `AlgorithmIdentifier`s.
+pub trait AssociatedOid {
+    /// Object identifier of the hash function.
+    const OID: ObjectIdentifier;
+
+    /// Human-readable name of the hash function, e.g. `"SHA-256"`.
+    ///
+    /// Intended for diagnostics and logging; the OID should be used for
+    /// identifying the algorithm in encoded data.
+    const NAME: &'static str;
+}
+
+/// DER-encoded prefix of a PKCS#1 v1.5 `DigestInfo` structure.
+///
+/// `DigestInfo` is defined in [RFC 8017 Section 9.2] as:
+///
+/// ```text
+/// DigestInfo ::= SEQUENCE {
+///     digestAlgorithm AlgorithmIdentifier,
+///     digest OCTET STRING
+/// }
+/// ```
+///
+/// The prefix contains everything except the digest bytes, i.e. the DER
+/// encoding of a `DigestInfo` is the prefix immediately followed by the
+/// digest value.
+///
+/// [RFC 8017 Section 9.2]: https://tools.ietf.org/html/rfc8017#section-9.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DigestInfoPrefix {
+    bytes: [u8; DIGEST_INFO_PREFIX_MAX_LEN],
+    length: usize,
+}
+
+impl DigestInfoPrefix {
+    /// Compute `DigestInfo` prefix for the hash function `D`.
+    ///
+    /// The `AlgorithmIdentifier` is encoded with explicit `NULL` parameters,
+    /// as required by RFC 8017 for the SHA-1 and SHA-2 families.
+    pub fn new<D: Digest + AssociatedOid>() -> Self {
+        Self::from_der_oid(D::OID.as_bytes(), D::OutputSize::USIZE)
+    }
+
+    /// Compute `DigestInfo` prefix from the DER-encoded OID of a hash
+    /// function (without tag and length) and its output size in bytes.
+    ///
+    /// Unlike [`DigestInfoPrefix::new`] this can be used in constants, e.g.
+    /// for SHA-256:
+    ///
+    /// ```
+    /// use digest::oid::DigestInfoPrefix;
+    ///
+    /// const SHA256_PREFIX: DigestInfoPrefix = DigestInfoPrefix::from_der_oid(
+    ///     &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01],
+    ///     32,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic if `oid` is longer than [`const_oid::MAX_LEN`] bytes, which
+    /// isn't the case for any valid [`ObjectIdentifier`].
+    pub const fn from_der_oid(oid: &[u8], digest_len: usize) -> Self {
+        // OID TLV followed by DER `NULL`
+        let algorithm_len = 2 + oid.len() + 2;
+        let octet_string_len = der_len_size(digest_len) + 1 + digest_len;
+        let outer_len = 1 + der_len_size(algorithm_len) + algorithm_len + octet_string_len;
+
+        let prefix = Self {
+            bytes: [0; DIGEST_INFO_PREFIX_MAX_LEN],
+            length: 0,
+        };
+        prefix
+            .push(&[0x30])
+            .push_len(outer_len)
+            .push(&[0x30])
+            .push_len(algorithm_len)
+            .push(&[0x06, oid.len() as u8])
+            .push(oid)
+            .push(&[0x05, 0x00, 0x04])
+            .push_len(digest_len)
+    }
+
+    /// Get the prefix as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+
+    const fn push(mut self, data: &[u8]) -> Self {
+        let mut i = 0;
+        while i < data.len() {
+            self.bytes[self.length] = data[i];
+            self.length += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Append DER length `len`, in long form if it doesn't fit in 7 bits.
+    const fn push_len(mut self, len: usize) -> Self {
+        let size = der_len_size(len);
+        if size == 1 {
+            return self.push(&[len as u8]);
+        }
+
+        self = self.push(&[0x80 | (size - 1) as u8]);
+        let mut shift = 8 * (size - 1);
+        while shift > 0 {
+            shift -= 8;
+            self = self.push(&[(len >> shift) as u8]);
+        }
+        self
+    }
+}
+
+impl AsRef<[u8]> for DigestInfoPrefix {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Compute DER encoding of a PKCS#1 v1.5 `DigestInfo` structure for the
+/// given digest value of the hash function `D`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn digest_info<D: Digest + AssociatedOid>(digest: &Output<D>) -> Vec<u8> {
+    let prefix = DigestInfoPrefix::new::<D>();
+    let mut buf = Vec::with_capacity(prefix.as_bytes().len() + digest.len());
+    buf.extend_from_slice(prefix.as_bytes());
+    buf.extend_from_slice(digest);
+    buf
+}
+
+/// Number of bytes used by the DER encoding of the length `len`.
+const fn der_len_size(len: usize) -> usize {
+    if len < 0x80 {
+        return 1;
+    }
+
+    let mut size = 1;
+    let mut rest = len;
+    while rest > 0 {
+        size += 1;
+        rest >>= 8;
+    }
+    size
+}
@@ -0,0 +1,55 @@
+//! Multi-lane digest support
+
+use crate::{FixedOutput, Update};
+use generic_array::typenum::Unsigned;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Output of a single lane of a [`ParallelDigest`] function.
+pub type LaneOutput<D> = GenericArray<u8, <D as FixedOutput>::OutputSize>;
+
+/// Outputs of all lanes of a [`ParallelDigest`] function.
+pub type ParOutput<D> = GenericArray<LaneOutput<D>, <D as ParallelDigest>::ParLanes>;
+
+/// Trait for hash functions which can compute digests of several independent
+/// messages at once, e.g. using SIMD instructions.
+///
+/// Digests computed by this trait must be equal to digests computed by
+/// hashing each message separately. This allows callers like Merkle tree
+/// builders to be written generically over vectorized and scalar hashers.
+pub trait ParallelDigest: Update + FixedOutput + Default {
+    /// Number of messages which can be processed in parallel by
+    /// hasher implementation
+    type ParLanes: ArrayLength<LaneOutput<Self>>;
+
+    /// Compute digests of exactly `ParLanes` messages in parallel.
+    ///
+    /// # Panics
+    /// If length of `msgs` is not equal to `ParLanes`.
+    fn digest_lanes(msgs: &[&[u8]], out: &mut ParOutput<Self>);
+
+    /// Compute digests of an arbitrary number of messages, leveraging
+    /// parallelism when available.
+    ///
+    /// Messages which do not fill all available lanes are hashed one by one.
+    ///
+    /// # Panics
+    /// If length of `msgs` is not equal to length of `out`.
+    fn digest_many(msgs: &[&[u8]], out: &mut [LaneOutput<Self>]) {
+        assert_eq!(msgs.len(), out.len());
+        let lanes = Self::ParLanes::to_usize();
+
+        let mut msg_chunks = msgs.chunks_exact(lanes);
+        let mut out_chunks = out.chunks_exact_mut(lanes);
+        for (msg_chunk, out_chunk) in (&mut msg_chunks).zip(&mut out_chunks) {
+            let mut res = ParOutput::<Self>::default();
+            Self::digest_lanes(msg_chunk, &mut res);
+            out_chunk.clone_from_slice(&res);
+        }
+
+        let msgs = msg_chunks.remainder();
+        let out = out_chunks.into_remainder();
+        for (msg, out) in msgs.iter().zip(out.iter_mut()) {
+            Self::default().chain(msg).finalize_into(out);
+        }
+    }
+}
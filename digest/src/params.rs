@@ -0,0 +1,196 @@
+//! Parameterized hasher initialization support
+
+use crate::InvalidParams;
+use core::fmt;
+use core::marker::PhantomData;
+use generic_array::typenum::{IsLessOrEqual, LeEq, NonZero, Unsigned};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Trait for hash functions which can be initialized with a key, salt,
+/// personalization string and output size, e.g. BLAKE2b and BLAKE2s.
+///
+/// Each of `key`, `salt` and `personal` may be shorter than its maximum size
+/// and is empty when not used. Hashers are usually constructed through
+/// [`DigestBuilder`], which checks parameter lengths at compile time.
+pub trait InitWithParams: Sized {
+    /// Maximum key size in bytes.
+    type MaxKeySize: ArrayLength<u8>;
+    /// Maximum salt size in bytes.
+    type SaltSize: ArrayLength<u8>;
+    /// Maximum personalization string size in bytes.
+    type PersonalSize: ArrayLength<u8>;
+    /// Maximum output size in bytes.
+    type MaxOutputSize: ArrayLength<u8>;
+
+    /// Create new hasher instance with the given parameters.
+    ///
+    /// Returns [`InvalidParams`] if any of the parameters is longer than
+    /// its maximum size, or if `output_size` is zero or bigger than
+    /// `MaxOutputSize`.
+    fn new_with_params(
+        key: &[u8],
+        salt: &[u8],
+        personal: &[u8],
+        output_size: usize,
+    ) -> Result<Self, InvalidParams>;
+
+    /// Create [`DigestBuilder`] for this hasher.
+    fn builder<'a>() -> DigestBuilder<'a, Self> {
+        DigestBuilder::new()
+    }
+}
+
+/// Builder for hashers implementing [`InitWithParams`].
+///
+/// Lengths of the key, salt and personalization string are checked against
+/// the limits of `D` at compile time, only the output size is checked at
+/// runtime. Output size defaults to `D::MaxOutputSize`.
+///
+/// ```rust
+/// use digest::{consts::{U16, U32, U64}, generic_array::GenericArray};
+/// use digest::{DigestBuilder, InitWithParams, InvalidParams};
+///
+/// struct Toy {
+///     key_len: usize,
+///     output_size: usize,
+/// }
+///
+/// impl InitWithParams for Toy {
+///     type MaxKeySize = U64;
+///     type SaltSize = U16;
+///     type PersonalSize = U16;
+///     type MaxOutputSize = U64;
+///
+///     fn new_with_params(
+///         key: &[u8],
+///         _salt: &[u8],
+///         _personal: &[u8],
+///         output_size: usize,
+///     ) -> Result<Self, InvalidParams> {
+///         Ok(Toy { key_len: key.len(), output_size })
+///     }
+/// }
+///
+/// let key = GenericArray::<u8, U32>::default();
+/// let personal = GenericArray::<u8, U16>::clone_from_slice(b"MyApp v1 hashing");
+/// let toy = Toy::builder()
+///     .key(&key)
+///     .personal(&personal)
+///     .output_size(32)
+///     .build()
+///     .unwrap();
+/// assert_eq!((toy.key_len, toy.output_size), (32, 32));
+///
+/// assert!(DigestBuilder::<Toy>::new().output_size(65).build().is_err());
+/// ```
+///
+/// Parameters which exceed the hasher limits are rejected by the compiler:
+///
+/// ```rust,compile_fail,E0277
+/// # use digest::{consts::{U16, U32, U64}, generic_array::GenericArray};
+/// # use digest::{InitWithParams, InvalidParams};
+/// # struct Toy;
+/// # impl InitWithParams for Toy {
+/// #     type MaxKeySize = U64;
+/// #     type SaltSize = U16;
+/// #     type PersonalSize = U16;
+/// #     type MaxOutputSize = U64;
+/// #     fn new_with_params(_: &[u8], _: &[u8], _: &[u8], _: usize) -> Result<Self, InvalidParams> {
+/// #         Ok(Toy)
+/// #     }
+/// # }
+/// let salt = GenericArray::<u8, U32>::default();
+/// Toy::builder().salt(&salt); // error: `SaltSize` of `Toy` is 16 bytes
+/// ```
+pub struct DigestBuilder<'a, D> {
+    key: &'a [u8],
+    salt: &'a [u8],
+    personal: &'a [u8],
+    output_size: usize,
+    _d: PhantomData<D>,
+}
+
+impl<'a, D: InitWithParams> DigestBuilder<'a, D> {
+    /// Create new builder with empty key, salt and personalization string.
+    pub fn new() -> Self {
+        Self {
+            key: &[],
+            salt: &[],
+            personal: &[],
+            output_size: D::MaxOutputSize::to_usize(),
+            _d: PhantomData,
+        }
+    }
+
+    /// Set key.
+    pub fn key<N>(mut self, key: &'a GenericArray<u8, N>) -> Self
+    where
+        N: ArrayLength<u8> + IsLessOrEqual<D::MaxKeySize>,
+        LeEq<N, D::MaxKeySize>: NonZero,
+    {
+        self.key = key;
+        self
+    }
+
+    /// Set salt.
+    pub fn salt<N>(mut self, salt: &'a GenericArray<u8, N>) -> Self
+    where
+        N: ArrayLength<u8> + IsLessOrEqual<D::SaltSize>,
+        LeEq<N, D::SaltSize>: NonZero,
+    {
+        self.salt = salt;
+        self
+    }
+
+    /// Set personalization string.
+    pub fn personal<N>(mut self, personal: &'a GenericArray<u8, N>) -> Self
+    where
+        N: ArrayLength<u8> + IsLessOrEqual<D::PersonalSize>,
+        LeEq<N, D::PersonalSize>: NonZero,
+    {
+        self.personal = personal;
+        self
+    }
+
+    /// Set output size in bytes.
+    pub fn output_size(mut self, output_size: usize) -> Self {
+        self.output_size = output_size;
+        self
+    }
+
+    /// Create hasher instance with the collected parameters.
+    pub fn build(self) -> Result<D, InvalidParams> {
+        if self.output_size == 0 || self.output_size > D::MaxOutputSize::to_usize() {
+            return Err(InvalidParams);
+        }
+        D::new_with_params(self.key, self.salt, self.personal, self.output_size)
+    }
+}
+
+impl<'a, D: InitWithParams> Default for DigestBuilder<'a, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D> Clone for DigestBuilder<'a, D> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            salt: self.salt,
+            personal: self.personal,
+            output_size: self.output_size,
+            _d: PhantomData,
+        }
+    }
+}
+
+impl<'a, D> fmt::Debug for DigestBuilder<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DigestBuilder")
+            .field("salt", &self.salt)
+            .field("personal", &self.personal)
+            .field("output_size", &self.output_size)
+            .finish()
+    }
+}
@@ -0,0 +1,275 @@
+//! Hashcash-style proof-of-work puzzles.
+//!
+//! A puzzle consists of a prefix (e.g. a server-issued challenge) and a
+//! difficulty `N`. A solution is a nonce such that `H(prefix || nonce)` has
+//! at least `N` leading zero bits, where the nonce is encoded as an 8-byte
+//! big-endian integer. Finding a solution takes `2^N` hash evaluations on
+//! average, while verifying it takes a single one.
+//!
+//! [`PowSearch`] works with both fixed output hash functions and XOFs. Its
+//! state can be saved and resumed, split into independent lanes for use on
+//! several threads, and, with the `pow-rayon` feature enabled, searched in
+//! parallel using `PowSearch::solve_parallel`.
+//!
+//! ```
+//! use digest::{consts::U8, pow::PowSearch, FixedOutput, Update};
+//!
+//! # /// Toy hash function which is NOT secure (64-bit FNV-1a)
+//! # #[derive(Clone)]
+//! # struct Fnv(u64);
+//! # impl Default for Fnv {
+//! #     fn default() -> Self {
+//! #         Fnv(0xcbf2_9ce4_8422_2325)
+//! #     }
+//! # }
+//! # impl Update for Fnv {
+//! #     fn update(&mut self, data: impl AsRef<[u8]>) {
+//! #         for &b in data.as_ref() {
+//! #             self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+//! #         }
+//! #     }
+//! # }
+//! # impl FixedOutput for Fnv {
+//! #     type OutputSize = U8;
+//! #     fn finalize_into(self, out: &mut digest::generic_array::GenericArray<u8, U8>) {
+//! #         out.copy_from_slice(&self.0.to_be_bytes());
+//! #     }
+//! # }
+//! let mut search = PowSearch::<Fnv>::new(b"challenge", 12).unwrap();
+//! let nonce = search.solve().unwrap();
+//! assert!(search.verify(nonce));
+//!
+//! // Save the search position and look for another solution later
+//! let position = search.next_nonce().unwrap();
+//! let mut search = PowSearch::<Fnv>::new(b"challenge", 12)
+//!     .unwrap()
+//!     .resume_at(position);
+//! assert!(search.solve().unwrap() > nonce);
+//! ```
+
+use crate::{ExtendableOutput, FixedOutput, InvalidDifficulty, Update, XofReader};
+use generic_array::typenum::Unsigned;
+
+#[cfg(feature = "pow-rayon")]
+use {
+    core::sync::atomic::{AtomicBool, Ordering},
+    rayon::prelude::*,
+};
+
+/// Number of nonces tried by each lane of [`PowSearch::solve_parallel`]
+/// between checks whether another lane found a solution.
+#[cfg(feature = "pow-rayon")]
+const PARALLEL_CHUNK: u64 = 1 << 12;
+
+/// Count the number of leading zero bits in `bytes`.
+pub fn leading_zero_bits(bytes: &[u8]) -> u32 {
+    let mut count = 0;
+    for &byte in bytes {
+        count += byte.leading_zeros();
+        if byte != 0 {
+            break;
+        }
+    }
+    count
+}
+
+/// Resumable search for a proof-of-work nonce.
+///
+/// The prefix is absorbed into the hasher once on construction, so every
+/// attempt only hashes the 8-byte nonce on top of a clone of that state.
+///
+/// The search tries nonces `start, start + step, start + 2·step, ...` until
+/// the nonce space is exhausted. [`PowSearch::next_nonce`] and
+/// [`PowSearch::resume_at`] can be used to persist and restore the search
+/// position, and [`PowSearch::split`] partitions the remaining nonces into
+/// disjoint lanes.
+#[derive(Clone, Debug)]
+pub struct PowSearch<H> {
+    hasher: H,
+    difficulty: u32,
+    check: fn(H, u32) -> bool,
+    next: Option<u64>,
+    step: u64,
+}
+
+impl<H: Update + Clone> PowSearch<H> {
+    /// Create a search for a fixed output hash function.
+    ///
+    /// Returns [`InvalidDifficulty`] if the difficulty exceeds the output
+    /// size of the hash function.
+    pub fn new(prefix: &[u8], difficulty: u32) -> Result<Self, InvalidDifficulty>
+    where
+        H: Default + FixedOutput,
+    {
+        if difficulty as usize > 8 * H::OutputSize::to_usize() {
+            return Err(InvalidDifficulty);
+        }
+
+        Ok(Self::from_hasher(
+            H::default().chain(prefix),
+            difficulty,
+            check_fixed::<H>,
+        ))
+    }
+
+    /// Create a search for an extendable output function (XOF).
+    ///
+    /// Any difficulty is accepted, since the output of a XOF can be read
+    /// up to the required number of bits.
+    pub fn new_xof(prefix: &[u8], difficulty: u32) -> Self
+    where
+        H: Default + ExtendableOutput,
+    {
+        Self::from_hasher(H::default().chain(prefix), difficulty, check_xof::<H>)
+    }
+
+    fn from_hasher(hasher: H, difficulty: u32, check: fn(H, u32) -> bool) -> Self {
+        Self {
+            hasher,
+            difficulty,
+            check,
+            next: Some(0),
+            step: 1,
+        }
+    }
+
+    /// Get the difficulty, i.e. the required number of leading zero bits.
+    pub fn difficulty(&self) -> u32 {
+        self.difficulty
+    }
+
+    /// Get the next nonce which will be tried, or `None` if the search is
+    /// exhausted.
+    pub fn next_nonce(&self) -> Option<u64> {
+        self.next
+    }
+
+    /// Continue the search at the given nonce.
+    pub fn resume_at(mut self, nonce: u64) -> Self {
+        self.next = Some(nonce);
+        self
+    }
+
+    /// Check whether `nonce` solves this puzzle.
+    pub fn verify(&self, nonce: u64) -> bool {
+        (self.check)(
+            self.hasher.clone().chain(nonce.to_be_bytes()),
+            self.difficulty,
+        )
+    }
+
+    /// Try at most `max_attempts` nonces.
+    ///
+    /// Returns the first solution found, after which the search continues
+    /// with the following nonce. Returns `None` if no solution was found,
+    /// in which case this method can be called again to continue the
+    /// search.
+    pub fn step(&mut self, max_attempts: u64) -> Option<u64> {
+        for _ in 0..max_attempts {
+            let nonce = self.next?;
+            self.next = nonce.checked_add(self.step);
+
+            if self.verify(nonce) {
+                return Some(nonce);
+            }
+        }
+
+        None
+    }
+
+    /// Search until a solution is found or the nonce space is exhausted.
+    pub fn solve(&mut self) -> Option<u64> {
+        while self.next.is_some() {
+            if let Some(nonce) = self.step(u64::MAX) {
+                return Some(nonce);
+            }
+        }
+
+        None
+    }
+
+    /// Split the remaining nonces into `lanes` disjoint searches.
+    ///
+    /// Each lane can be run independently, e.g. on its own thread, and
+    /// together the lanes cover exactly the nonces this search would try.
+    ///
+    /// # Panics
+    ///
+    /// If `lanes` is zero.
+    pub fn split(&self, lanes: u64) -> impl Iterator<Item = Self> + '_ {
+        assert!(lanes > 0, "number of lanes must be positive");
+        (0..lanes).map(move |i| self.lane(i, lanes))
+    }
+
+    /// Get lane `i` of the remaining nonces split into `lanes` lanes.
+    fn lane(&self, i: u64, lanes: u64) -> Self {
+        let offset = self.step.checked_mul(i);
+
+        Self {
+            hasher: self.hasher.clone(),
+            difficulty: self.difficulty,
+            check: self.check,
+            next: self
+                .next
+                .and_then(|next| offset.and_then(|offset| next.checked_add(offset))),
+            step: self.step.saturating_mul(lanes),
+        }
+    }
+
+    /// Search in parallel on the rayon thread pool using the given number of
+    /// lanes, returning any solution found.
+    ///
+    /// Unlike [`PowSearch::solve`], the returned solution isn't necessarily
+    /// the smallest one, and the search state isn't advanced.
+    ///
+    /// # Panics
+    ///
+    /// If `lanes` is zero.
+    #[cfg(feature = "pow-rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pow-rayon")))]
+    pub fn solve_parallel(&self, lanes: u64) -> Option<u64>
+    where
+        H: Send + Sync,
+    {
+        assert!(lanes > 0, "number of lanes must be positive");
+        let found = AtomicBool::new(false);
+
+        (0..lanes).into_par_iter().find_map_any(|i| {
+            let mut lane = self.lane(i, lanes);
+
+            while lane.next.is_some() && !found.load(Ordering::Relaxed) {
+                if let Some(nonce) = lane.step(PARALLEL_CHUNK) {
+                    found.store(true, Ordering::Relaxed);
+                    return Some(nonce);
+                }
+            }
+
+            None
+        })
+    }
+}
+
+fn check_fixed<H: FixedOutput>(hasher: H, difficulty: u32) -> bool {
+    leading_zero_bits(&hasher.finalize_fixed()) >= difficulty
+}
+
+fn check_xof<H: ExtendableOutput>(hasher: H, difficulty: u32) -> bool {
+    let mut reader = hasher.finalize_xof();
+    let mut remaining = difficulty;
+    let mut buf = [0u8; 32];
+
+    while remaining > 0 {
+        let len = core::cmp::min(buf.len(), remaining as usize / 8 + 1);
+        reader.read(&mut buf[..len]);
+
+        let zeros = leading_zero_bits(&buf[..len]);
+        if zeros >= remaining {
+            return true;
+        } else if zeros < 8 * len as u32 {
+            return false;
+        }
+        remaining -= zeros;
+    }
+
+    true
+}
@@ -0,0 +1,33 @@
+//! Serializable hasher state support
+
+use crate::DeserializeStateError;
+use generic_array::{ArrayLength, GenericArray};
+
+/// Serialized internal state of a [`SerializableState`] hasher.
+pub type SerializedState<T> = GenericArray<u8, <T as SerializableState>::SerializedStateSize>;
+
+/// Trait for hashers whose internal state can be saved and later restored,
+/// e.g. to checkpoint a long-running hash computation across process restarts.
+///
+/// The serialized state must capture everything needed to resume hashing,
+/// which includes both the compression function state and the contents and
+/// position of any partially filled block buffer. Resuming from a serialized
+/// state and processing the rest of the input must produce the same result
+/// as if the hasher was never interrupted.
+///
+/// Serialized state contains information about the hashed data and
+/// therefore should be treated with the same care as the data itself.
+pub trait SerializableState: Sized {
+    /// Size of the serialized internal state in bytes.
+    type SerializedStateSize: ArrayLength<u8>;
+
+    /// Serialize current hasher state into a byte array.
+    fn serialize(&self) -> SerializedState<Self>;
+
+    /// Restore hasher from a serialized state.
+    ///
+    /// Returns [`DeserializeStateError`] if the provided state is malformed,
+    /// e.g. if the recorded block buffer position exceeds the block size.
+    fn deserialize(serialized_state: &SerializedState<Self>)
+        -> Result<Self, DeserializeStateError>;
+}
@@ -0,0 +1,241 @@
+//! Truncated-output hash function adapter
+
+use crate::{
+    BlockInput, ConstInit, FixedOutput, FixedOutputReset, InvalidKeyLength, Key, KeyedInit, Reset,
+    Update,
+};
+use core::fmt;
+use core::marker::PhantomData;
+use generic_array::typenum::{IsLessOrEqual, LeEq, NonZero};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Wrapper which truncates output of the hash function `D` to `OutSize`
+/// bytes, e.g. `Truncated<Sha512, U32>`.
+///
+/// The wrapper forwards all input to `D` and returns the leftmost `OutSize`
+/// bytes of its output. `OutSize` is checked at compile time to be not bigger
+/// than the output size of `D`.
+///
+/// Note that this is a plain truncation: it does not change initialization
+/// vectors of the hash function, so `Truncated<Sha512, U32>` is NOT
+/// equivalent to SHA-512/256.
+pub struct Truncated<D, OutSize> {
+    inner: D,
+    _out: PhantomData<OutSize>,
+}
+
+impl<D, OutSize> Truncated<D, OutSize>
+where
+    D: FixedOutput,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    /// Create truncating wrapper around the given hasher instance.
+    pub fn from_inner(inner: D) -> Self {
+        Self {
+            inner,
+            _out: PhantomData,
+        }
+    }
+}
+
+impl<D, OutSize> Truncated<D, OutSize> {
+    /// Get reference to the inner hasher.
+    pub fn get_ref(&self) -> &D {
+        &self.inner
+    }
+
+    /// Unwrap the inner hasher.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D, OutSize> Default for Truncated<D, OutSize>
+where
+    D: FixedOutput + Default,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    fn default() -> Self {
+        Self::from_inner(D::default())
+    }
+}
+
+impl<D: ConstInit, OutSize> ConstInit for Truncated<D, OutSize> {
+    const INIT: Self = Self {
+        inner: D::INIT,
+        _out: PhantomData,
+    };
+}
+
+impl<D: Clone, OutSize> Clone for Truncated<D, OutSize> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _out: PhantomData,
+        }
+    }
+}
+
+impl<D: fmt::Debug, OutSize> fmt::Debug for Truncated<D, OutSize> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Truncated").field(&self.inner).finish()
+    }
+}
+
+impl<D: Update, OutSize> Update for Truncated<D, OutSize> {
+    #[inline]
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.inner.update(data);
+    }
+
+    #[inline]
+    fn update_vectored(&mut self, bufs: &[&[u8]]) {
+        self.inner.update_vectored(bufs);
+    }
+}
+
+impl<D: BlockInput, OutSize> BlockInput for Truncated<D, OutSize> {
+    type BlockSize = D::BlockSize;
+}
+
+impl<D: Reset, OutSize> Reset for Truncated<D, OutSize> {
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<D, OutSize> FixedOutput for Truncated<D, OutSize>
+where
+    D: FixedOutput,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    type OutputSize = OutSize;
+
+    #[inline]
+    fn finalize_into(self, out: &mut GenericArray<u8, OutSize>) {
+        let res = self.inner.finalize_fixed();
+        copy_prefix(out, &res);
+    }
+}
+
+impl<D, OutSize> FixedOutputReset for Truncated<D, OutSize>
+where
+    D: FixedOutputReset,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, OutSize>) {
+        let res = self.inner.finalize_fixed_reset();
+        copy_prefix(out, &res);
+    }
+}
+
+impl<D, OutSize> KeyedInit for Truncated<D, OutSize>
+where
+    D: FixedOutput + KeyedInit,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    type KeySize = D::KeySize;
+
+    #[inline]
+    fn new_keyed(key: &Key<Self>) -> Self {
+        Self::from_inner(D::new_keyed(key))
+    }
+
+    #[inline]
+    fn new_keyed_varkey(key: &[u8]) -> Result<Self, InvalidKeyLength> {
+        D::new_keyed_varkey(key).map(Self::from_inner)
+    }
+}
+
+#[cfg(feature = "mac")]
+impl<D, OutSize> crypto_mac::NewMac for Truncated<D, OutSize>
+where
+    D: crypto_mac::NewMac,
+{
+    type KeySize = D::KeySize;
+
+    #[inline]
+    fn new(key: &crypto_mac::Key<Self>) -> Self {
+        Self {
+            inner: D::new(key),
+            _out: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn new_varkey(key: &[u8]) -> Result<Self, crypto_mac::InvalidKeyLength> {
+        D::new_varkey(key).map(|inner| Self {
+            inner,
+            _out: PhantomData,
+        })
+    }
+}
+
+/// MAC tags are truncated the same way as digests, e.g. for HMAC-SHA-256-128.
+#[cfg(feature = "mac")]
+impl<D, OutSize> crypto_mac::Mac for Truncated<D, OutSize>
+where
+    D: crypto_mac::Mac,
+    OutSize: ArrayLength<u8> + IsLessOrEqual<D::OutputSize>,
+    LeEq<OutSize, D::OutputSize>: NonZero,
+{
+    type OutputSize = OutSize;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[inline]
+    fn finalize(self) -> crypto_mac::Output<Self> {
+        let mut out = GenericArray::default();
+        copy_prefix(&mut out, &self.inner.finalize().into_bytes());
+        crypto_mac::Output::new(out)
+    }
+
+    #[inline]
+    fn finalize_reset(&mut self) -> crypto_mac::Output<Self> {
+        let mut out = GenericArray::default();
+        copy_prefix(&mut out, &self.inner.finalize_reset().into_bytes());
+        crypto_mac::Output::new(out)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Update, OutSize> std::io::Write for Truncated<D, OutSize> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Update::update(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Copy leftmost bytes of `res` into `out`.
+///
+/// The bounds check at compile time that `res` is at least as long as `out`,
+/// so slicing `res` can't panic.
+#[inline]
+fn copy_prefix<OutSize, InSize>(out: &mut GenericArray<u8, OutSize>, res: &GenericArray<u8, InSize>)
+where
+    OutSize: ArrayLength<u8> + IsLessOrEqual<InSize>,
+    InSize: ArrayLength<u8>,
+    LeEq<OutSize, InSize>: NonZero,
+{
+    let len = out.len();
+    out.copy_from_slice(&res[..len]);
+}
@@ -3,7 +3,7 @@
 use crate::Reset;
 
 #[cfg(feature = "alloc")]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 /// Trait for describing readers which are used to extract extendable output
 /// from XOF (extendable-output function) result.
@@ -24,6 +24,17 @@ pub trait XofReader {
         self.read(&mut buf);
         buf
     }
+
+    /// Read `n` bytes of output and append them to the provided vector.
+    ///
+    /// Can be called an unlimited number of times in combination with `read`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_into_vec(&mut self, out: &mut Vec<u8>, n: usize) {
+        let pos = out.len();
+        out.resize(pos + n, 0);
+        self.read(&mut out[pos..]);
+    }
 }
 
 /// Trait which describes extendable-output functions (XOF).
@@ -34,9 +45,6 @@ pub trait ExtendableOutput: Sized {
     /// Retrieve XOF reader and consume hasher instance.
     fn finalize_xof(self) -> Self::Reader;
 
-    /// Retrieve XOF reader and reset hasher instance state.
-    fn finalize_xof_reset(&mut self) -> Self::Reader;
-
     /// Retrieve result into a boxed slice of the specified size and consume
     /// the hasher.
     ///
@@ -49,6 +57,16 @@ pub trait ExtendableOutput: Sized {
         self.finalize_xof().read(&mut buf);
         buf
     }
+}
+
+/// Trait for extendable-output functions which can be finalized without
+/// being consumed, resetting the hasher instance instead.
+///
+/// XOFs which can not be cheaply reset can implement [`ExtendableOutput`]
+/// only.
+pub trait ExtendableOutputReset: ExtendableOutput + Reset {
+    /// Retrieve XOF reader and reset hasher instance state.
+    fn finalize_xof_reset(&mut self) -> Self::Reader;
 
     /// Retrieve result into a boxed slice of the specified size and reset
     /// the hasher's state.
@@ -69,10 +87,11 @@ pub trait ExtendableOutput: Sized {
 ///
 /// Usage of this trait in user code is discouraged. Instead use the
 /// [`ExtendableOutput::finalize_xof`] or
-/// [`ExtendableOutput::finalize_xof_reset`] methods.
+/// [`ExtendableOutputReset::finalize_xof_reset`] methods.
 ///
-/// Types which impl this trait along with [`Reset`] will receive a blanket
-/// impl of [`ExtendableOutput`].
+/// Types which impl this trait will receive a blanket impl of
+/// [`ExtendableOutput`], and along with [`Reset`] a blanket impl of
+/// [`ExtendableOutputReset`].
 pub trait ExtendableOutputDirty: Sized {
     /// Reader
     type Reader: XofReader;
@@ -85,14 +104,16 @@ pub trait ExtendableOutputDirty: Sized {
     fn finalize_xof_dirty(&mut self) -> Self::Reader;
 }
 
-impl<X: ExtendableOutputDirty + Reset> ExtendableOutput for X {
+impl<X: ExtendableOutputDirty> ExtendableOutput for X {
     type Reader = X::Reader;
 
     #[inline]
     fn finalize_xof(mut self) -> Self::Reader {
         self.finalize_xof_dirty()
     }
+}
 
+impl<X: ExtendableOutputDirty + Reset> ExtendableOutputReset for X {
     #[inline]
     fn finalize_xof_reset(&mut self) -> Self::Reader {
         let reader = self.finalize_xof_dirty();
@@ -100,3 +121,79 @@ impl<X: ExtendableOutputDirty + Reset> ExtendableOutput for X {
         reader
     }
 }
+
+/// Trait for extendable-output functions which are initialized with a
+/// function name and a customization string, e.g. cSHAKE as defined in
+/// [NIST SP 800-185].
+///
+/// The function name is reserved for functions defined by NIST on top of
+/// cSHAKE (e.g. `b"KMAC"` or `b"TupleHash"`), while the customization string
+/// is chosen by the application for domain separation. XOFs initialized with
+/// different function names or customization strings are expected to produce
+/// unrelated outputs for the same input data.
+///
+/// ```
+/// use digest::{ExtendableOutput, ExtendableOutputCustomized, Update, XofReader};
+///
+/// /// Toy XOF which is NOT secure: absorbs input by XOR into a single byte
+/// struct ToyXof(u8);
+///
+/// struct ToyReader(u8);
+///
+/// impl XofReader for ToyReader {
+///     fn read(&mut self, buffer: &mut [u8]) {
+///         for b in buffer.iter_mut() {
+///             *b = self.0;
+///             self.0 = self.0.wrapping_add(1);
+///         }
+///     }
+/// }
+///
+/// impl Update for ToyXof {
+///     fn update(&mut self, data: impl AsRef<[u8]>) {
+///         for b in data.as_ref() {
+///             self.0 = self.0.rotate_left(1) ^ b;
+///         }
+///     }
+/// }
+///
+/// impl ExtendableOutput for ToyXof {
+///     type Reader = ToyReader;
+///
+///     fn finalize_xof(self) -> ToyReader {
+///         ToyReader(self.0)
+///     }
+/// }
+///
+/// impl ExtendableOutputCustomized for ToyXof {
+///     fn new_customized(function_name: &[u8], customization: &[u8]) -> Self {
+///         // Encode lengths to ensure unambiguous domain separation
+///         let mut xof = ToyXof(0);
+///         xof.update([function_name.len() as u8]);
+///         xof.update(function_name);
+///         xof.update([customization.len() as u8]);
+///         xof.update(customization);
+///         xof
+///     }
+/// }
+///
+/// /// Generic code which only relies on the traits
+/// fn hash<X: ExtendableOutputCustomized + Update>(custom: &[u8], msg: &[u8]) -> [u8; 4] {
+///     let mut out = [0u8; 4];
+///     X::new_customized(b"", custom)
+///         .chain(msg)
+///         .finalize_xof()
+///         .read(&mut out);
+///     out
+/// }
+///
+/// assert_ne!(hash::<ToyXof>(b"app A", b"msg"), hash::<ToyXof>(b"app B", b"msg"));
+/// assert_eq!(hash::<ToyXof>(b"app A", b"msg"), hash::<ToyXof>(b"app A", b"msg"));
+/// ```
+///
+/// [NIST SP 800-185]: https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-185.pdf
+pub trait ExtendableOutputCustomized: ExtendableOutput {
+    /// Create new XOF instance with the given function name and
+    /// customization string, either of which can be empty.
+    fn new_customized(function_name: &[u8], customization: &[u8]) -> Self;
+}
@@ -0,0 +1,155 @@
+/// Tests for the `async_io` adapters
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use core::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use digest::{
+        async_io::{AsyncDigestRead, DigestWriter, HashTask},
+        consts::U8,
+        generic_array::GenericArray,
+        DeserializeStateError, Digest, FixedOutputDirty, Reset, SerializableState, SerializedState,
+        Update,
+    };
+    use futures::{
+        executor::block_on,
+        io::{copy, Cursor},
+        task::noop_waker,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// Number of bytes `HashTask` hashes before yielding to the executor.
+    const YIELD_INTERVAL: u64 = 64 * 1024;
+
+    /// Toy hasher: not secure, but the digest depends on all input bytes and
+    /// their order, and the state is trivially serializable.
+    #[derive(Clone, Default)]
+    struct ToyHash(u64);
+
+    impl Update for ToyHash {
+        fn update(&mut self, data: impl AsRef<[u8]>) {
+            for &b in data.as_ref() {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    impl FixedOutputDirty for ToyHash {
+        type OutputSize = U8;
+
+        fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, U8>) {
+            out.copy_from_slice(&self.0.to_le_bytes());
+        }
+    }
+
+    impl Reset for ToyHash {
+        fn reset(&mut self) {
+            *self = Self::default();
+        }
+    }
+
+    impl SerializableState for ToyHash {
+        type SerializedStateSize = U8;
+
+        fn serialize(&self) -> SerializedState<Self> {
+            GenericArray::clone_from_slice(&self.0.to_le_bytes())
+        }
+
+        fn deserialize(state: &SerializedState<Self>) -> Result<Self, DeserializeStateError> {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(state);
+            Ok(ToyHash(u64::from_le_bytes(bytes)))
+        }
+    }
+
+    /// Input spanning several yield intervals, with a partial last one.
+    fn input() -> Vec<u8> {
+        (0..5 * YIELD_INTERVAL / 2)
+            .map(|i| (i % 251) as u8)
+            .collect()
+    }
+
+    fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        let waker = noop_waker();
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn digest_writer() {
+        let input = input();
+        let mut writer = DigestWriter::new(ToyHash::default());
+        let copied = block_on(copy(Cursor::new(&input[..]), &mut writer)).unwrap();
+        assert_eq!(copied, input.len() as u64);
+        assert_eq!(writer.into_inner().finalize(), ToyHash::digest(&input));
+    }
+
+    #[test]
+    fn update_digest() {
+        let input = input();
+        let mut hasher = ToyHash::default();
+        let read = block_on(Cursor::new(&input[..]).update_digest(&mut hasher)).unwrap();
+        assert_eq!(read, input.len() as u64);
+        assert_eq!(hasher.finalize(), ToyHash::digest(&input));
+    }
+
+    #[test]
+    fn yields_every_interval() {
+        let input = input();
+        let mut task = HashTask::<_, ToyHash>::new(Cursor::new(&input[..]));
+
+        assert!(poll_once(&mut task).is_pending());
+        assert_eq!(task.position(), YIELD_INTERVAL);
+        assert!(poll_once(&mut task).is_pending());
+        assert_eq!(task.position(), 2 * YIELD_INTERVAL);
+
+        match poll_once(&mut task) {
+            Poll::Ready(Ok(digest)) => assert_eq!(digest, ToyHash::digest(&input)),
+            _ => panic!("task didn't finish"),
+        }
+    }
+
+    #[test]
+    fn cancel_and_resume() {
+        let input = input();
+        let progress = Arc::new(Mutex::new(Vec::new()));
+
+        let recorder = progress.clone();
+        let mut task = HashTask::<_, ToyHash>::new(Cursor::new(&input[..]))
+            .on_progress(move |bytes| recorder.lock().unwrap().push(bytes));
+        assert!(poll_once(&mut task).is_pending());
+
+        let (reader, checkpoint) = task.cancel();
+        assert_eq!(checkpoint.position, YIELD_INTERVAL);
+        assert_eq!(reader.position(), YIELD_INTERVAL);
+
+        let recorder = progress.clone();
+        let task = HashTask::<_, ToyHash>::resume(reader, &checkpoint)
+            .unwrap()
+            .on_progress(move |bytes| recorder.lock().unwrap().push(bytes));
+        let digest = block_on(task).unwrap();
+
+        let one_shot = block_on(HashTask::<_, ToyHash>::new(Cursor::new(&input[..]))).unwrap();
+        assert_eq!(digest, one_shot);
+        assert_eq!(digest, ToyHash::digest(&input));
+
+        // progress continues across the resume and covers the whole input
+        let progress = progress.lock().unwrap();
+        assert!(progress.windows(2).all(|w| w[0] < w[1]));
+        assert!(progress.contains(&YIELD_INTERVAL));
+        assert_eq!(progress.last(), Some(&(input.len() as u64)));
+    }
+
+    #[test]
+    fn reset_after_completion() {
+        let input = input();
+        let mut task = HashTask::<_, ToyHash>::new(Cursor::new(&input[..]));
+        assert_eq!(block_on(&mut task).unwrap(), ToyHash::digest(&input));
+        assert_eq!(task.position(), 0);
+
+        let (_, checkpoint) = task.cancel();
+        assert_eq!(checkpoint.position, 0);
+        assert_eq!(checkpoint.state, ToyHash::default().serialize());
+    }
+}
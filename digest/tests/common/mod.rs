@@ -0,0 +1,48 @@
+//! Hash functions for tests.
+//!
+//! The `sha2` dev-dependency implements the `digest` traits released on
+//! crates.io, so its hashers are wrapped to implement the traits of this
+//! crate.
+#![allow(dead_code)]
+
+use digest::{
+    consts::{U128, U32, U64},
+    generic_array::GenericArray,
+    BlockInput, FixedOutputDirty, Reset, Update,
+};
+use sha2::digest as sha2_digest;
+
+macro_rules! wrap {
+    ($name:ident, $block_size:ty, $output_size:ty) => {
+        /// `sha2` hasher implementing this crate's traits
+        #[derive(Clone, Default)]
+        pub struct $name(sha2::$name);
+
+        impl Update for $name {
+            fn update(&mut self, data: impl AsRef<[u8]>) {
+                sha2_digest::Update::update(&mut self.0, data);
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block_size;
+        }
+
+        impl FixedOutputDirty for $name {
+            type OutputSize = $output_size;
+
+            fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, $output_size>) {
+                sha2_digest::FixedOutputDirty::finalize_into_dirty(&mut self.0, out);
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                sha2_digest::Reset::reset(&mut self.0);
+            }
+        }
+    };
+}
+
+wrap!(Sha256, U64, U32);
+wrap!(Sha512, U128, U64);
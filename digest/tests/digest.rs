@@ -0,0 +1,45 @@
+#[cfg(feature = "subtle")]
+mod common;
+
+/// Tests for `Digest::verify`
+#[cfg(all(test, feature = "subtle"))]
+mod tests {
+    use crate::common::Sha256;
+    use digest::{Digest, InvalidDigest};
+
+    const MSG: &[u8] = b"verify";
+
+    #[test]
+    fn verify_match() {
+        let expected = Sha256::digest(MSG);
+        assert_eq!(Sha256::new().chain(MSG).verify(&expected), Ok(()));
+    }
+
+    #[test]
+    fn verify_mismatch() {
+        let mut expected = Sha256::digest(MSG);
+        expected[31] ^= 1;
+        assert_eq!(
+            Sha256::new().chain(MSG).verify(&expected),
+            Err(InvalidDigest)
+        );
+        assert_eq!(
+            Sha256::new().chain(b"other").verify(&Sha256::digest(MSG)),
+            Err(InvalidDigest)
+        );
+    }
+
+    #[test]
+    fn verify_wrong_length() {
+        let expected = Sha256::digest(MSG);
+        assert_eq!(
+            Sha256::new().chain(MSG).verify(&expected[..16]),
+            Err(InvalidDigest)
+        );
+        assert_eq!(Sha256::new().chain(MSG).verify(&[]), Err(InvalidDigest));
+
+        let mut longer = expected.to_vec();
+        longer.push(0);
+        assert_eq!(Sha256::new().chain(MSG).verify(&longer), Err(InvalidDigest));
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "alloc")]
+mod common;
+
+/// Tests for `DynDigestRegistry`
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::common::{Sha256, Sha512};
+    use digest::{Digest, DynDigestRegistry};
+
+    const MSG: &[u8] = b"registry";
+
+    fn registry() -> DynDigestRegistry {
+        let mut registry = DynDigestRegistry::new();
+        registry
+            .register::<Sha256>("sha256")
+            .register::<Sha512>("sha512");
+        registry
+    }
+
+    #[test]
+    fn register_and_get() {
+        let registry = registry();
+        assert!(registry.contains("sha256"));
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["sha256", "sha512"]);
+
+        let mut hasher = registry.get("sha256").unwrap();
+        assert_eq!(hasher.output_size(), 32);
+        hasher.update(MSG);
+        assert_eq!(&hasher.finalize()[..], &Sha256::digest(MSG)[..]);
+
+        let mut hasher = registry.get("sha512").unwrap();
+        assert_eq!(hasher.output_size(), 64);
+        hasher.update(MSG);
+        assert_eq!(&hasher.finalize()[..], &Sha512::digest(MSG)[..]);
+    }
+
+    #[test]
+    fn names_are_case_insensitive() {
+        let registry = registry();
+        assert!(registry.contains("SHA256"));
+        assert_eq!(registry.get("Sha512").unwrap().output_size(), 64);
+    }
+
+    #[test]
+    fn unknown_name() {
+        let registry = registry();
+        assert!(!registry.contains("sha3-256"));
+        assert!(registry.get("sha3-256").is_none());
+        assert!(DynDigestRegistry::new().get("sha256").is_none());
+    }
+
+    #[test]
+    fn replace_entry() {
+        let mut registry = registry();
+        registry.register::<Sha512>("SHA256");
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["SHA256", "sha512"]);
+        assert_eq!(registry.get("sha256").unwrap().output_size(), 64);
+    }
+}
@@ -0,0 +1,84 @@
+/// Tests for `DynDigestMut`
+#[cfg(test)]
+mod tests {
+    use digest::{
+        consts::U8, generic_array::GenericArray, Digest, DynDigestMut, FixedOutputDirty, Reset,
+        Update,
+    };
+
+    /// Toy hasher: not secure, but the digest depends on all input bytes and
+    /// their order.
+    #[derive(Clone, Default)]
+    struct ToyHash(u64);
+
+    impl Update for ToyHash {
+        fn update(&mut self, data: impl AsRef<[u8]>) {
+            for &b in data.as_ref() {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    impl FixedOutputDirty for ToyHash {
+        type OutputSize = U8;
+
+        fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, U8>) {
+            out.copy_from_slice(&self.0.to_le_bytes());
+        }
+    }
+
+    impl Reset for ToyHash {
+        fn reset(&mut self) {
+            *self = Self::default();
+        }
+    }
+
+    const MSG: &[u8] = b"dyn digest";
+
+    #[test]
+    fn finalize_into_slice() {
+        let mut toy = ToyHash::default();
+        let hasher: &mut dyn DynDigestMut = &mut toy;
+        assert_eq!(hasher.output_size(), 8);
+
+        let mut out = [0u8; 8];
+        hasher.update(&MSG[..3]);
+        hasher.update(&MSG[3..]);
+        hasher.finalize_into_slice(&mut out).unwrap();
+        assert_eq!(out[..], ToyHash::digest(MSG)[..]);
+
+        // the hasher is reset after finalization
+        hasher.update(MSG);
+        hasher.finalize_into_slice(&mut out).unwrap();
+        assert_eq!(out[..], ToyHash::digest(MSG)[..]);
+    }
+
+    #[test]
+    fn reset() {
+        let mut toy = ToyHash::default();
+        let hasher: &mut dyn DynDigestMut = &mut toy;
+
+        let mut out = [0u8; 8];
+        hasher.update(b"discarded");
+        hasher.reset();
+        hasher.update(MSG);
+        hasher.finalize_into_slice(&mut out).unwrap();
+        assert_eq!(out[..], ToyHash::digest(MSG)[..]);
+    }
+
+    #[test]
+    fn output_length_mismatch() {
+        let mut toy = ToyHash::default();
+        let hasher: &mut dyn DynDigestMut = &mut toy;
+        hasher.update(MSG);
+
+        assert!(hasher.finalize_into_slice(&mut [0u8; 7]).is_err());
+        assert!(hasher.finalize_into_slice(&mut [0u8; 9]).is_err());
+        assert!(hasher.finalize_into_slice(&mut []).is_err());
+
+        // the hasher state is left unchanged on error
+        let mut out = [0u8; 8];
+        hasher.finalize_into_slice(&mut out).unwrap();
+        assert_eq!(out[..], ToyHash::digest(MSG)[..]);
+    }
+}
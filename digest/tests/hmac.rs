@@ -0,0 +1,105 @@
+#[cfg(feature = "mac")]
+mod common;
+
+/// Tests for `Hmac`
+#[cfg(all(test, feature = "mac"))]
+mod tests {
+    use crate::common::{Sha256, Sha512};
+    use digest::{consts::U16, BlockInput, FixedOutputReset, Hmac, Mac, NewMac, Truncated, Update};
+
+    /// Check `Hmac<D>` against a test vector, verifying the tag both in one
+    /// pass and with the message fed byte-by-byte.
+    fn check<D>(key: &[u8], data: &[u8], tag: &[u8])
+    where
+        D: Update + BlockInput + FixedOutputReset + Default + Clone,
+    {
+        let mut mac = Hmac::<D>::new_varkey(key).unwrap();
+        Mac::update(&mut mac, data);
+        assert_eq!(&mac.finalize().into_bytes()[..], tag);
+
+        let mut mac = Hmac::<D>::new_varkey(key).unwrap();
+        data.iter().for_each(|b| Mac::update(&mut mac, &[*b]));
+        assert!(mac.verify(tag).is_ok());
+    }
+
+    /// RFC 4231 section 4.2, test case 1
+    #[test]
+    fn rfc4231_test_case_1() {
+        let key = [0x0b; 20];
+        let data = b"Hi There";
+        check::<Sha256>(
+            &key,
+            data,
+            &[
+                0xb0, 0x34, 0x4c, 0x61, 0xd8, 0xdb, 0x38, 0x53, 0x5c, 0xa8, 0xaf, 0xce, 0xaf, 0x0b,
+                0xf1, 0x2b, 0x88, 0x1d, 0xc2, 0x00, 0xc9, 0x83, 0x3d, 0xa7, 0x26, 0xe9, 0x37, 0x6c,
+                0x2e, 0x32, 0xcf, 0xf7,
+            ],
+        );
+        check::<Sha512>(
+            &key,
+            data,
+            &[
+                0x87, 0xaa, 0x7c, 0xde, 0xa5, 0xef, 0x61, 0x9d, 0x4f, 0xf0, 0xb4, 0x24, 0x1a, 0x1d,
+                0x6c, 0xb0, 0x23, 0x79, 0xf4, 0xe2, 0xce, 0x4e, 0xc2, 0x78, 0x7a, 0xd0, 0xb3, 0x05,
+                0x45, 0xe1, 0x7c, 0xde, 0xda, 0xa8, 0x33, 0xb7, 0xd6, 0xb8, 0xa7, 0x02, 0x03, 0x8b,
+                0x27, 0x4e, 0xae, 0xa3, 0xf4, 0xe4, 0xbe, 0x9d, 0x91, 0x4e, 0xeb, 0x61, 0xf1, 0x70,
+                0x2e, 0x69, 0x6c, 0x20, 0x3a, 0x12, 0x68, 0x54,
+            ],
+        );
+    }
+
+    /// RFC 4231 section 4.6, test case 5: output truncated to 128 bits
+    #[test]
+    fn rfc4231_test_case_5() {
+        let mut mac = Truncated::<Hmac<Sha256>, U16>::new_varkey(&[0x0c; 20]).unwrap();
+        Mac::update(&mut mac, b"Test With Truncation");
+        assert_eq!(
+            &mac.finalize().into_bytes()[..],
+            &[
+                0xa3, 0xb6, 0x16, 0x74, 0x73, 0x10, 0x0e, 0xe0, 0x6e, 0x0c, 0x79, 0x6c, 0x29, 0x55,
+                0x55, 0x2b,
+            ]
+        );
+    }
+
+    /// RFC 4231 section 4.7, test case 6: key longer than the block size
+    #[test]
+    fn rfc4231_test_case_6() {
+        let key = [0xaa; 131];
+        let data = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        check::<Sha256>(
+            &key,
+            data,
+            &[
+                0x60, 0xe4, 0x31, 0x59, 0x1e, 0xe0, 0xb6, 0x7f, 0x0d, 0x8a, 0x26, 0xaa, 0xcb, 0xf5,
+                0xb7, 0x7f, 0x8e, 0x0b, 0xc6, 0x21, 0x37, 0x28, 0xc5, 0x14, 0x05, 0x46, 0x04, 0x0f,
+                0x0e, 0xe3, 0x7f, 0x54,
+            ],
+        );
+        check::<Sha512>(
+            &key,
+            data,
+            &[
+                0x80, 0xb2, 0x42, 0x63, 0xc7, 0xc1, 0xa3, 0xeb, 0xb7, 0x14, 0x93, 0xc1, 0xdd, 0x7b,
+                0xe8, 0xb4, 0x9b, 0x46, 0xd1, 0xf4, 0x1b, 0x4a, 0xee, 0xc1, 0x12, 0x1b, 0x01, 0x37,
+                0x83, 0xf8, 0xf3, 0x52, 0x6b, 0x56, 0xd0, 0x37, 0xe0, 0x5f, 0x25, 0x98, 0xbd, 0x0f,
+                0xd2, 0x21, 0x5d, 0x6a, 0x1e, 0x52, 0x95, 0xe6, 0x4f, 0x73, 0xf6, 0x3f, 0x0a, 0xec,
+                0x8b, 0x91, 0x5a, 0x98, 0x5d, 0x78, 0x65, 0x98,
+            ],
+        );
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn keyed_reset() {
+        use digest::dev::keyed_reset_test;
+
+        // keys shorter and longer than the SHA-256 block size
+        for key in &[&[0x0b; 20][..], &[0xaa; 131][..]] {
+            if let Some(desc) = keyed_reset_test::<Hmac<Sha256>>(key, b"Hi There") {
+                panic!("keyed reset test failed: {}", desc);
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(feature = "multihash")]
+mod common;
+
+/// Tests for `Multihash`
+#[cfg(all(test, feature = "multihash"))]
+mod tests {
+    use crate::common::Sha256;
+    use digest::{multihash::Multihash, Digest, MultihashCode};
+
+    impl MultihashCode for Sha256 {
+        const MULTIHASH_CODE: u64 = 0x12;
+    }
+
+    const MSG: &[u8] = b"multihash";
+
+    #[test]
+    fn verify_full_digest() {
+        let multihash = Multihash::hash::<Sha256>(MSG);
+        assert!(multihash.verify_with::<Sha256>(MSG).is_ok());
+        assert!(multihash.verify_with::<Sha256>(b"other").is_err());
+    }
+
+    #[test]
+    fn verify_rejects_other_code() {
+        let multihash = Multihash::new(0x13, &Sha256::digest(MSG)).unwrap();
+        assert!(multihash.verify_with::<Sha256>(MSG).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_truncated_digest() {
+        let multihash = Multihash::new(0x12, &Sha256::digest(MSG)[..16]).unwrap();
+        assert!(multihash.verify_with::<Sha256>(MSG).is_err());
+    }
+
+    #[test]
+    fn verify_truncated_enforces_min_len() {
+        let output = Sha256::digest(MSG);
+        let multihash = Multihash::new(0x12, &output[..16]).unwrap();
+        assert!(multihash.verify_truncated_with::<Sha256>(MSG, 16).is_ok());
+        assert!(multihash.verify_truncated_with::<Sha256>(MSG, 17).is_err());
+        assert!(multihash
+            .verify_truncated_with::<Sha256>(b"other", 16)
+            .is_err());
+
+        let empty = Multihash::new(0x12, &[]).unwrap();
+        assert!(empty.verify_truncated_with::<Sha256>(MSG, 0).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_digest_longer_than_output() {
+        let mut digest = [0u8; 33];
+        digest[..32].copy_from_slice(&Sha256::digest(MSG));
+        let multihash = Multihash::new(0x12, &digest).unwrap();
+        assert!(multihash.verify_with::<Sha256>(MSG).is_err());
+        assert!(multihash.verify_truncated_with::<Sha256>(MSG, 1).is_err());
+    }
+
+    #[test]
+    fn decode_round_trip() {
+        let multihash = Multihash::new(0x1234, &[0xAB; 3]).unwrap();
+        let mut buf = [0u8; 16];
+        let encoded = multihash.encode(&mut buf).unwrap();
+        assert_eq!(encoded, &[0xB4, 0x24, 0x03, 0xAB, 0xAB, 0xAB]);
+        assert_eq!(Multihash::decode(encoded).unwrap(), multihash);
+    }
+
+    #[test]
+    fn decode_rejects_non_minimal_varint() {
+        // 0x12 encoded as two bytes
+        assert!(Multihash::decode(&[0x92, 0x00, 0x01, 0xAB]).is_err());
+        // length 1 encoded as two bytes
+        assert!(Multihash::decode(&[0x12, 0x81, 0x00, 0xAB]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_over_long_varint() {
+        // 10 byte varint, longer than multiformats permits
+        let mut bytes = [0x80u8; 12];
+        bytes[9] = 0x01;
+        bytes[10] = 0x00;
+        assert!(Multihash::decode(&bytes[..11]).is_err());
+
+        // unterminated varint
+        assert!(Multihash::decode(&[0x80, 0x80]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_length_past_end() {
+        assert!(Multihash::decode(&[0x12, 0x03, 0xAB, 0xAB]).is_err());
+        // digests longer than `MAX_DIGEST_LEN` are rejected too
+        assert!(Multihash::decode(&[0x12, 0x41]).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_trailing_bytes() {
+        let bytes = [0x12, 0x01, 0xAB, 0xCD];
+        assert!(Multihash::decode(&bytes).is_err());
+
+        let (multihash, rest) = Multihash::read(&bytes).unwrap();
+        assert_eq!(multihash.digest(), &[0xAB]);
+        assert_eq!(rest, &[0xCD]);
+    }
+}
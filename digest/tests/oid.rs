@@ -0,0 +1,63 @@
+#[cfg(feature = "oid")]
+mod common;
+
+/// Tests for `DigestInfoPrefix`
+#[cfg(all(test, feature = "oid"))]
+mod tests {
+    use crate::common::Sha256;
+    use digest::{
+        const_oid::ObjectIdentifier,
+        oid::{AssociatedOid, DigestInfoPrefix},
+    };
+
+    impl AssociatedOid for Sha256 {
+        const OID: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 1]);
+        const NAME: &'static str = "SHA-256";
+    }
+
+    /// DER encoding of the SHA-256 OID without tag and length
+    const SHA256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+
+    /// `DigestInfo` prefix for SHA-256 from RFC 8017 Section 9.2, Note 1
+    const SHA256_PREFIX: &[u8] = &[
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+        0x05, 0x00, 0x04, 0x20,
+    ];
+
+    #[test]
+    fn sha256_prefix() {
+        assert_eq!(DigestInfoPrefix::new::<Sha256>().as_bytes(), SHA256_PREFIX);
+    }
+
+    #[test]
+    fn const_prefix() {
+        const PREFIX: DigestInfoPrefix = DigestInfoPrefix::from_der_oid(SHA256_OID, 32);
+        assert_eq!(PREFIX, DigestInfoPrefix::new::<Sha256>());
+    }
+
+    #[test]
+    fn long_form_lengths() {
+        let prefix = DigestInfoPrefix::from_der_oid(SHA256_OID, 0x80);
+        assert_eq!(&prefix.as_bytes()[..3], &[0x30, 0x81, 0x92]);
+        assert_eq!(&prefix.as_bytes()[18..], &[0x04, 0x81, 0x80]);
+
+        let prefix = DigestInfoPrefix::from_der_oid(SHA256_OID, 0x100);
+        assert_eq!(&prefix.as_bytes()[..4], &[0x30, 0x82, 0x01, 0x13]);
+        assert_eq!(&prefix.as_bytes()[19..], &[0x04, 0x82, 0x01, 0x00]);
+
+        let prefix = DigestInfoPrefix::from_der_oid(SHA256_OID, 0x10000);
+        assert_eq!(&prefix.as_bytes()[..5], &[0x30, 0x83, 0x01, 0x00, 0x14]);
+        assert_eq!(&prefix.as_bytes()[20..], &[0x04, 0x83, 0x01, 0x00, 0x00]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn digest_info() {
+        use digest::{oid::digest_info, Digest};
+
+        let digest = Sha256::digest(b"abc");
+        let encoded = digest_info::<Sha256>(&digest);
+        assert_eq!(&encoded[..SHA256_PREFIX.len()], SHA256_PREFIX);
+        assert_eq!(&encoded[SHA256_PREFIX.len()..], &digest[..]);
+    }
+}
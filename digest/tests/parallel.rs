@@ -0,0 +1,54 @@
+mod common;
+
+/// Tests for `ParallelDigest`
+#[cfg(test)]
+mod tests {
+    use crate::common::Sha256;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use digest::{consts::U4, Digest, LaneOutput, ParOutput, ParallelDigest};
+
+    /// Number of `digest_lanes` calls, only made by `matches_sequential_digests`
+    static LANE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    impl ParallelDigest for Sha256 {
+        type ParLanes = U4;
+
+        fn digest_lanes(msgs: &[&[u8]], out: &mut ParOutput<Self>) {
+            assert_eq!(msgs.len(), 4);
+            LANE_CALLS.fetch_add(1, Ordering::SeqCst);
+            for (msg, out) in msgs.iter().zip(out.iter_mut()) {
+                *out = Sha256::digest(msg);
+            }
+        }
+    }
+
+    fn messages(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| vec![i as u8; 10 * i]).collect()
+    }
+
+    #[test]
+    fn matches_sequential_digests() {
+        for &n in &[0, 1, 3, 4, 5, 8, 11] {
+            LANE_CALLS.store(0, Ordering::SeqCst);
+
+            let msgs = messages(n);
+            let msgs: Vec<&[u8]> = msgs.iter().map(|msg| &msg[..]).collect();
+            let mut out = vec![LaneOutput::<Sha256>::default(); n];
+            Sha256::digest_many(&msgs, &mut out);
+
+            for (msg, out) in msgs.iter().zip(out.iter()) {
+                assert_eq!(out, &Sha256::digest(msg), "{} messages", n);
+            }
+            assert_eq!(LANE_CALLS.load(Ordering::SeqCst), n / 4, "{} messages", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn length_mismatch() {
+        let msgs = messages(5);
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| &msg[..]).collect();
+        let mut out = vec![LaneOutput::<Sha256>::default(); 4];
+        Sha256::digest_many(&msgs, &mut out);
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(feature = "pow")]
+mod common;
+
+/// Tests for `PowSearch`
+#[cfg(all(test, feature = "pow"))]
+mod tests {
+    use crate::common::Sha256;
+    use digest::{
+        pow::{leading_zero_bits, PowSearch},
+        ExtendableOutput, InvalidDifficulty, Update, XofReader,
+    };
+
+    /// XOF whose output starts with as many zero bits as the value of the last
+    /// 8 bytes absorbed, i.e. the nonce, followed by one bits.
+    #[derive(Clone, Default)]
+    struct ZeroPrefixXof([u8; 8]);
+
+    impl Update for ZeroPrefixXof {
+        fn update(&mut self, data: impl AsRef<[u8]>) {
+            for &b in data.as_ref() {
+                self.0.rotate_left(1);
+                self.0[7] = b;
+            }
+        }
+    }
+
+    impl ExtendableOutput for ZeroPrefixXof {
+        type Reader = ZeroPrefixReader;
+
+        fn finalize_xof(self) -> ZeroPrefixReader {
+            ZeroPrefixReader {
+                zeros: u64::from_be_bytes(self.0),
+                pos: 0,
+            }
+        }
+    }
+
+    struct ZeroPrefixReader {
+        zeros: u64,
+        pos: u64,
+    }
+
+    impl XofReader for ZeroPrefixReader {
+        fn read(&mut self, buffer: &mut [u8]) {
+            for byte in buffer {
+                let ones = (8 * (self.pos + 1)).saturating_sub(self.zeros);
+                *byte = if ones >= 8 { 0xff } else { (1u8 << ones) - 1 };
+                self.pos += 1;
+            }
+        }
+    }
+
+    /// Collect up to `max` nonces tried by `search`, which must accept every
+    /// nonce.
+    fn tried(mut search: PowSearch<Sha256>, max: usize) -> Vec<u64> {
+        let mut nonces = Vec::new();
+        while nonces.len() < max {
+            match search.step(1) {
+                Some(nonce) => nonces.push(nonce),
+                None => break,
+            }
+        }
+        nonces
+    }
+
+    /// Collect the nonces tried by all `lanes`, in ascending order.
+    fn tried_by_lanes(lanes: impl Iterator<Item = PowSearch<Sha256>>, max: usize) -> Vec<u64> {
+        let mut nonces: Vec<u64> = lanes.flat_map(|lane| tried(lane, max)).collect();
+        nonces.sort_unstable();
+        nonces
+    }
+
+    #[test]
+    fn count_leading_zero_bits() {
+        assert_eq!(leading_zero_bits(&[]), 0);
+        assert_eq!(leading_zero_bits(&[0x80]), 0);
+        assert_eq!(leading_zero_bits(&[0x01, 0x00]), 7);
+        assert_eq!(leading_zero_bits(&[0x00, 0x00]), 16);
+        assert_eq!(leading_zero_bits(&[0x00, 0x10, 0x00]), 11);
+    }
+
+    #[test]
+    fn xof_zeros_across_reads() {
+        // `check_xof` reads the output in chunks of at most 32 bytes
+        for &difficulty in &[
+            1, 7, 8, 9, 255, 256, 257, 263, 264, 265, 300, 512, 513, 1000,
+        ] {
+            let search = PowSearch::<ZeroPrefixXof>::new_xof(b"prefix", difficulty);
+            let zeros = u64::from(difficulty);
+            assert!(!search.verify(zeros - 1), "difficulty {}", difficulty);
+            assert!(search.verify(zeros), "difficulty {}", difficulty);
+            assert!(search.verify(zeros + 1), "difficulty {}", difficulty);
+        }
+
+        assert!(PowSearch::<ZeroPrefixXof>::new_xof(b"", 0).verify(0));
+    }
+
+    #[test]
+    fn xof_solve() {
+        let mut search = PowSearch::<ZeroPrefixXof>::new_xof(b"", 300);
+        assert_eq!(search.solve(), Some(300));
+        assert_eq!(search.next_nonce(), Some(301));
+    }
+
+    #[test]
+    fn difficulty_bounds() {
+        assert!(PowSearch::<Sha256>::new(b"", 0).is_ok());
+        assert!(PowSearch::<Sha256>::new(b"", 256).is_ok());
+        assert_eq!(
+            PowSearch::<Sha256>::new(b"", 257).err(),
+            Some(InvalidDifficulty)
+        );
+        assert_eq!(
+            PowSearch::<Sha256>::new(b"", u32::MAX).err(),
+            Some(InvalidDifficulty)
+        );
+
+        // difficulty 0 is solved by the first nonce tried
+        let mut search = PowSearch::<Sha256>::new(b"", 0).unwrap().resume_at(42);
+        assert_eq!(search.solve(), Some(42));
+    }
+
+    #[test]
+    fn split_covers_each_nonce_once() {
+        let search = PowSearch::<Sha256>::new(b"", 0).unwrap().resume_at(10);
+
+        let lanes = search.split(3);
+        assert_eq!(tried_by_lanes(lanes, 10), (10..40).collect::<Vec<_>>());
+
+        // lanes of lanes
+        let lanes = search
+            .split(2)
+            .flat_map(|lane| lane.split(3).collect::<Vec<_>>());
+        assert_eq!(tried_by_lanes(lanes, 10), (10..70).collect::<Vec<_>>());
+
+        // a single lane is the search itself
+        let lanes = search.split(1);
+        assert_eq!(tried_by_lanes(lanes, 10), tried(search, 10));
+    }
+
+    #[test]
+    fn split_at_end_of_nonce_space() {
+        let search = PowSearch::<Sha256>::new(b"", 0)
+            .unwrap()
+            .resume_at(u64::MAX - 6);
+
+        let lanes = search.split(4);
+        assert_eq!(
+            tried_by_lanes(lanes, 100),
+            (u64::MAX - 6..=u64::MAX).collect::<Vec<_>>()
+        );
+
+        // lanes starting past the end of the nonce space are exhausted
+        let search = search.resume_at(u64::MAX - 1);
+        let starts: Vec<_> = search.split(5).map(|lane| lane.next_nonce()).collect();
+        assert_eq!(
+            starts,
+            [Some(u64::MAX - 1), Some(u64::MAX), None, None, None]
+        );
+        assert_eq!(
+            tried_by_lanes(search.split(5), 100),
+            [u64::MAX - 1, u64::MAX]
+        );
+
+        let mut exhausted = search;
+        while exhausted.step(1).is_some() {}
+        assert_eq!(exhausted.next_nonce(), None);
+        assert!(tried_by_lanes(exhausted.split(3), 100).is_empty());
+    }
+
+    #[cfg(feature = "pow-rayon")]
+    #[test]
+    fn solve_parallel() {
+        let search = PowSearch::<ZeroPrefixXof>::new_xof(b"", 300);
+        let nonce = search.solve_parallel(4).unwrap();
+        assert!(nonce >= 300);
+        assert_eq!(search.next_nonce(), Some(0));
+    }
+}
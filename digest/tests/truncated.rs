@@ -0,0 +1,53 @@
+mod common;
+
+/// Tests for `Truncated`
+#[cfg(test)]
+mod tests {
+    use crate::common::{Sha256, Sha512};
+    use digest::{
+        consts::{U16, U32, U64},
+        Digest, Truncated,
+    };
+
+    const MSG: &[u8] = b"truncated";
+
+    #[test]
+    fn leftmost_bytes() {
+        let full = Sha256::digest(MSG);
+        assert_eq!(Truncated::<Sha256, U16>::digest(MSG)[..], full[..16]);
+
+        let full = Sha512::digest(MSG);
+        assert_eq!(Truncated::<Sha512, U32>::digest(MSG)[..], full[..32]);
+    }
+
+    #[test]
+    fn full_size_is_identity() {
+        assert_eq!(Truncated::<Sha256, U32>::digest(MSG), Sha256::digest(MSG));
+        assert_eq!(Truncated::<Sha512, U64>::digest(MSG), Sha512::digest(MSG));
+    }
+
+    #[test]
+    fn reset() {
+        let expected = Truncated::<Sha256, U16>::digest(MSG);
+
+        let mut hasher = Truncated::<Sha256, U16>::new();
+        hasher.update(b"discarded");
+        hasher.reset();
+        hasher.update(MSG);
+        assert_eq!(hasher.finalize(), expected);
+    }
+
+    #[test]
+    fn finalize_reset() {
+        let expected = Truncated::<Sha256, U16>::digest(MSG);
+
+        let mut hasher = Truncated::<Sha256, U16>::new();
+        hasher.update(MSG);
+        assert_eq!(hasher.finalize_reset(), expected);
+
+        // the hasher is reusable after `finalize_reset`
+        hasher.update(MSG);
+        assert_eq!(hasher.finalize_reset(), expected);
+        assert_eq!(hasher.finalize(), Truncated::<Sha256, U16>::digest(b""));
+    }
+}
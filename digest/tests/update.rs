@@ -0,0 +1,68 @@
+mod common;
+
+/// Tests for provided methods of `Update`
+#[cfg(test)]
+mod tests {
+    use crate::common::Sha256;
+    use digest::{consts::U16, Digest, Truncated, Update};
+
+    const MSG: &[u8] = b"scatter/gather input spanning several segments";
+
+    #[test]
+    fn update_vectored() {
+        let expected = Sha256::digest(MSG);
+
+        let bufs: &[&[u8]] = &[
+            &MSG[..7],
+            &[],
+            &MSG[7..8],
+            &MSG[8..30],
+            &[],
+            &MSG[30..],
+            &[],
+        ];
+        let mut hasher = Sha256::new();
+        hasher.update_vectored(bufs);
+        assert_eq!(hasher.finalize(), expected);
+
+        // `Truncated` forwards vectored input to the inner hasher
+        let mut hasher = Truncated::<Sha256, U16>::new();
+        hasher.update_vectored(bufs);
+        assert_eq!(hasher.finalize()[..], expected[..16]);
+    }
+
+    #[test]
+    fn update_vectored_empty() {
+        let expected = Sha256::digest(b"");
+
+        let mut hasher = Sha256::new();
+        hasher.update_vectored(&[]);
+        assert_eq!(hasher.clone().finalize(), expected);
+
+        hasher.update_vectored(&[&[], &[]]);
+        assert_eq!(hasher.finalize(), expected);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn update_buf() {
+        use bytes::{Buf, Bytes};
+
+        let expected = Sha256::digest(MSG);
+
+        let buf = Bytes::from_static(&MSG[..7])
+            .chain(Bytes::new())
+            .chain(Bytes::from_static(&MSG[7..30]))
+            .chain(&MSG[30..]);
+        let mut hasher = Sha256::new();
+        hasher.update_buf(buf);
+        assert_eq!(hasher.finalize(), expected);
+
+        // a borrowed buffer is fully advanced
+        let mut buf = Bytes::from_static(MSG);
+        let mut hasher = Sha256::new();
+        hasher.update_buf(&mut buf);
+        assert!(!buf.has_remaining());
+        assert_eq!(hasher.finalize(), expected);
+    }
+}
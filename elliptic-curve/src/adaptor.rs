@@ -0,0 +1,85 @@
+//! Adaptor signature traits.
+//!
+//! Adaptor signatures (a.k.a. pre-signatures) are signatures which are
+//! "encrypted" to an adaptor point `T = t·G`: a pre-signature can be
+//! verified against `T`, but only becomes a valid signature once adapted
+//! using the secret `t`. Anyone holding both the pre-signature and the
+//! adapted signature can extract `t`. This is the building block of
+//! scriptless scripts, atomic swaps and payment channel protocols.
+//!
+//! The traits in this module are generic over the curve `C`. The hash function
+//! used to compute Schnorr-style challenges is left to implementations, e.g.
+//! as a type parameter of the key type, so that its output size needn't match
+//! the scalar size and can be reduced from a wider value.
+//!
+//! The `dev` module includes a reference implementation for `MockCurve`.
+
+use crate::{
+    scalar::NonZeroScalar, Curve, Error, FieldBytes, ProjectiveArithmetic, ProjectivePoint, Scalar,
+};
+use ff::PrimeField;
+use rand_core::{CryptoRng, RngCore};
+
+/// Pre-signature which can be adapted into a full signature.
+pub trait PreSignature<C>: Sized
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Signature type produced by adapting this pre-signature.
+    type Signature;
+
+    /// Adapt this pre-signature into a full signature using the discrete
+    /// logarithm `secret` of the adaptor point.
+    fn adapt(&self, secret: &NonZeroScalar<C>) -> Self::Signature;
+
+    /// Extract the discrete logarithm of `adaptor` from this pre-signature
+    /// and the full signature adapted from it.
+    ///
+    /// Returns `Error` if `signature` wasn't adapted from this pre-signature
+    /// using the secret corresponding to `adaptor`.
+    fn extract(
+        &self,
+        signature: &Self::Signature,
+        adaptor: &ProjectivePoint<C>,
+    ) -> Result<NonZeroScalar<C>, Error>;
+}
+
+/// Create pre-signatures using `Self` (e.g. a secret key).
+pub trait AdaptorSigner<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Pre-signature type.
+    type PreSignature: PreSignature<C>;
+
+    /// Create a pre-signature of `msg` for the given adaptor point.
+    fn pre_sign(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+        adaptor: &ProjectivePoint<C>,
+    ) -> Result<Self::PreSignature, Error>;
+}
+
+/// Verify pre-signatures using `Self` (e.g. a public key).
+pub trait AdaptorVerifier<C, P>
+where
+    C: Curve + ProjectiveArithmetic,
+    P: PreSignature<C>,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Verify that `pre_signature` is a valid pre-signature of `msg` for
+    /// the given adaptor point, i.e. that adapting it with the discrete
+    /// logarithm of `adaptor` yields a valid signature.
+    fn verify_pre_signature(
+        &self,
+        msg: &[u8],
+        adaptor: &ProjectivePoint<C>,
+        pre_signature: &P,
+    ) -> Result<(), Error>;
+}
@@ -1,11 +1,17 @@
 //! Development-related functionality: helpers and types for writing tests
 //! against concrete implementations of the traits in this crate.
 
+pub mod adaptor;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod timing;
+
 use crate::{
     consts::U32,
     digest::Digest,
     ff::{Field, PrimeField},
-    group,
+    group::{self, Curve as _},
+    ops::{SqrtMethod, SquareRoot},
     rand_core::RngCore,
     scalar::ScalarBits,
     sec1::{FromEncodedPoint, ToEncodedPoint},
@@ -25,7 +31,9 @@ use core::{
 /// curve type.
 ///
 /// Note: this type is roughly modeled off of NIST P-256, but does not provide
-/// an actual cure arithmetic implementation.
+/// an actual curve arithmetic implementation. Points are represented by their
+/// discrete logarithm with respect to the generator, so the group law is the
+/// (insecure, variable-time) arithmetic of the P-256 scalar field.
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct MockCurve;
 
@@ -35,6 +43,10 @@ impl Curve for MockCurve {
 
 impl weierstrass::Curve for MockCurve {}
 
+impl weierstrass::point::Compression for MockCurve {
+    const COMPRESS_POINTS: bool = false;
+}
+
 impl ProjectiveArithmetic for MockCurve {
     type ProjectivePoint = ProjectivePoint;
 }
@@ -71,13 +83,63 @@ const MODULUS: U256 = [
     0xffff_ffff_0000_0000,
 ];
 
+// Note: P-256 modulus - 2, the exponent for inversion
+const MODULUS_MINUS_2: U256 = [
+    0xf3b9_cac2_fc63_254f,
+    0xbce6_faad_a717_9e84,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_0000_0000,
+];
+
+#[cfg(test)]
+std::thread_local! {
+    /// Number of [`Scalar`] to and from [`FieldBytes`] conversions performed
+    /// by the current thread, so tests can check that decoding does the same
+    /// work for valid and invalid inputs.
+    pub(crate) static REPR_CONVERSIONS: core::cell::Cell<usize> = core::cell::Cell::new(0);
+}
+
+/// Deterministic xorshift generator, so tests don't need an entropy source.
+#[cfg(test)]
+pub(crate) struct XorShift(pub(crate) u64);
+
+#[cfg(test)]
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Not actually cryptographically secure: only for APIs under test which
+/// require a [`CryptoRng`][rand_core::CryptoRng].
+#[cfg(test)]
+impl rand_core::CryptoRng for XorShift {}
+
 /// Example scalar type
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Scalar([u64; LIMBS]);
 
 impl Field for Scalar {
-    fn random(_rng: impl RngCore) -> Self {
-        unimplemented!();
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+        rng.fill_bytes(&mut bytes);
+        Self::reduce(&bytes)
     }
 
     fn zero() -> Self {
@@ -85,7 +147,7 @@ impl Field for Scalar {
     }
 
     fn one() -> Self {
-        unimplemented!();
+        Self([1, 0, 0, 0])
     }
 
     fn is_zero(&self) -> bool {
@@ -94,20 +156,21 @@ impl Field for Scalar {
 
     #[must_use]
     fn square(&self) -> Self {
-        unimplemented!();
+        *self * self
     }
 
     #[must_use]
     fn double(&self) -> Self {
-        unimplemented!();
+        *self + self
     }
 
     fn invert(&self) -> CtOption<Self> {
-        unimplemented!();
+        let inverse = self.pow_vartime(MODULUS_MINUS_2);
+        CtOption::new(inverse, !self.ct_eq(&Self::zero()))
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        unimplemented!();
+        self.square_root()
     }
 }
 
@@ -125,6 +188,9 @@ impl PrimeField for Scalar {
     const S: u32 = 4;
 
     fn from_repr(bytes: FieldBytes) -> Option<Self> {
+        #[cfg(test)]
+        REPR_CONVERSIONS.with(|n| n.set(n.get() + 1));
+
         let mut w = [0u64; LIMBS];
 
         // Interpret the bytes as a big-endian integer w.
@@ -148,6 +214,9 @@ impl PrimeField for Scalar {
     }
 
     fn to_repr(&self) -> FieldBytes {
+        #[cfg(test)]
+        REPR_CONVERSIONS.with(|n| n.set(n.get() + 1));
+
         let mut ret = FieldBytes::default();
         ret[0..8].copy_from_slice(&self.0[3].to_be_bytes());
         ret[8..16].copy_from_slice(&self.0[2].to_be_bytes());
@@ -157,26 +226,70 @@ impl PrimeField for Scalar {
     }
 
     fn to_le_bits(&self) -> ScalarBits<MockCurve> {
-        unimplemented!();
+        limbs_to_bits(&self.0)
     }
 
     fn is_odd(&self) -> bool {
-        unimplemented!();
+        self.0[0] & 1 == 1
     }
 
     fn char_le_bits() -> ScalarBits<MockCurve> {
-        unimplemented!();
+        limbs_to_bits(&MODULUS)
     }
 
     fn multiplicative_generator() -> Self {
-        unimplemented!();
+        Self::from(7)
     }
 
     fn root_of_unity() -> Self {
-        unimplemented!();
+        // 7^t where n - 1 = 2^4 * t
+        Self([
+            0x0592_d7fb_b41e_6602,
+            0x1546_cad0_0437_8daf,
+            0xba80_7ace_842a_3dfc,
+            0xffc9_7f06_2a77_0992,
+        ])
     }
 }
 
+impl SquareRoot for Scalar {
+    const P_MINUS_1_DIV_2: &'static [u64] = &[
+        0x79dc_e561_7e31_92a8,
+        0xde73_7d56_d38b_cf42,
+        0x7fff_ffff_ffff_ffff,
+        0x7fff_ffff_8000_0000,
+    ];
+
+    const SQRT_METHOD: SqrtMethod = SqrtMethod::TonelliShanks {
+        t_minus_1_div_2: &[
+            0x279d_ce56_17e3_192a,
+            0xfde7_37d5_6d38_bcf4,
+            0x07ff_ffff_ffff_ffff,
+            0x07ff_ffff_f800_0000,
+        ],
+    };
+}
+
+/// Convert little-endian 64-bit limbs into a bit array.
+fn limbs_to_bits(limbs: &U256) -> ScalarBits<MockCurve> {
+    #[cfg(target_pointer_width = "32")]
+    let limbs = [
+        limbs[0] as u32,
+        (limbs[0] >> 32) as u32,
+        limbs[1] as u32,
+        (limbs[1] >> 32) as u32,
+        limbs[2] as u32,
+        (limbs[2] >> 32) as u32,
+        limbs[3] as u32,
+        (limbs[3] >> 32) as u32,
+    ];
+
+    #[cfg(target_pointer_width = "64")]
+    let limbs = *limbs;
+
+    limbs.into()
+}
+
 impl ConditionallySelectable for Scalar {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Scalar([
@@ -200,84 +313,87 @@ impl ConstantTimeEq for Scalar {
 impl Add<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn add(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn add(self, other: Scalar) -> Scalar {
+        self.add(&other)
     }
 }
 
 impl Add<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn add(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn add(self, other: &Scalar) -> Scalar {
+        Self::add_inner(&self, other)
     }
 }
 
 impl AddAssign<Scalar> for Scalar {
-    fn add_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: Scalar) {
+        *self = *self + rhs;
     }
 }
 
 impl AddAssign<&Scalar> for Scalar {
-    fn add_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: &Scalar) {
+        *self = *self + rhs;
     }
 }
 
 impl Sub<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn sub(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn sub(self, other: Scalar) -> Scalar {
+        self.sub(&other)
     }
 }
 
 impl Sub<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn sub(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn sub(self, other: &Scalar) -> Scalar {
+        Self::sub_inner(
+            self.0[0], self.0[1], self.0[2], self.0[3], 0, other.0[0], other.0[1], other.0[2],
+            other.0[3], 0,
+        )
     }
 }
 
 impl SubAssign<Scalar> for Scalar {
-    fn sub_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: Scalar) {
+        *self = *self - rhs;
     }
 }
 
 impl SubAssign<&Scalar> for Scalar {
-    fn sub_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: &Scalar) {
+        *self = *self - rhs;
     }
 }
 
 impl Mul<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: Scalar) -> Scalar {
+        self.mul(&other)
     }
 }
 
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: &Scalar) -> Scalar {
+        Self::mul_inner(&self, other)
     }
 }
 
 impl MulAssign<Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
@@ -285,13 +401,13 @@ impl Neg for Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        unimplemented!();
+        Self::sub_inner(0, 0, 0, 0, 0, self.0[0], self.0[1], self.0[2], self.0[3], 0)
     }
 }
 
 impl From<u64> for Scalar {
-    fn from(_: u64) -> Scalar {
-        unimplemented!();
+    fn from(n: u64) -> Scalar {
+        Self([n, 0, 0, 0])
     }
 }
 
@@ -317,8 +433,19 @@ impl FromDigest<MockCurve> for Scalar {
     where
         D: Digest<OutputSize = U32>,
     {
-        let bytes = digest.finalize();
+        Self::reduce(&digest.finalize())
+    }
+}
 
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.as_mut().zeroize()
+    }
+}
+
+impl Scalar {
+    /// Reduce a big-endian 256-bit integer modulo the scalar field modulus.
+    fn reduce(bytes: &FieldBytes) -> Self {
         Self::sub_inner(
             u64::from_be_bytes(bytes[24..32].try_into().unwrap()),
             u64::from_be_bytes(bytes[16..24].try_into().unwrap()),
@@ -332,15 +459,33 @@ impl FromDigest<MockCurve> for Scalar {
             0,
         )
     }
-}
 
-impl Zeroize for Scalar {
-    fn zeroize(&mut self) {
-        self.0.as_mut().zeroize()
+    const fn add_inner(&self, rhs: &Self) -> Self {
+        let (w0, carry) = adc64(self.0[0], rhs.0[0], 0);
+        let (w1, carry) = adc64(self.0[1], rhs.0[1], carry);
+        let (w2, carry) = adc64(self.0[2], rhs.0[2], carry);
+        let (w3, w4) = adc64(self.0[3], rhs.0[3], carry);
+
+        Self::sub_inner(
+            w0, w1, w2, w3, w4, MODULUS[0], MODULUS[1], MODULUS[2], MODULUS[3], 0,
+        )
+    }
+
+    /// Variable-time double-and-add multiplication.
+    fn mul_inner(&self, rhs: &Self) -> Self {
+        let mut result = Self::zero();
+
+        for i in (0..256).rev() {
+            result = result.add_inner(&result);
+
+            if (rhs.0[i / 64] >> (i % 64)) & 1 == 1 {
+                result = result.add_inner(self);
+            }
+        }
+
+        result
     }
-}
 
-impl Scalar {
     #[allow(clippy::too_many_arguments)]
     const fn sub_inner(
         l0: u64,
@@ -377,8 +522,10 @@ pub struct AffinePoint {
 }
 
 impl ConditionallySelectable for AffinePoint {
-    fn conditional_select(_a: &Self, _b: &Self, _choice: Choice) -> Self {
-        unimplemented!();
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            inner: EncodedPoint::conditional_select(&a.inner, &b.inner, choice),
+        }
     }
 }
 
@@ -391,8 +538,8 @@ impl Default for AffinePoint {
 }
 
 impl FromEncodedPoint<MockCurve> for AffinePoint {
-    fn from_encoded_point(point: &EncodedPoint) -> Option<Self> {
-        Some(Self { inner: *point })
+    fn from_encoded_point(point: &EncodedPoint) -> CtOption<Self> {
+        CtOption::new(Self { inner: *point }, Choice::from(1))
     }
 }
 
@@ -400,8 +547,10 @@ impl ToEncodedPoint<MockCurve> for AffinePoint {
     fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
         if compress == self.inner.is_compressed() {
             self.inner
+        } else if compress {
+            self.inner.compress()
         } else {
-            unimplemented!();
+            ProjectivePoint::from(*self).to_affine().inner
         }
     }
 }
@@ -409,67 +558,62 @@ impl ToEncodedPoint<MockCurve> for AffinePoint {
 impl Mul<NonZeroScalar> for AffinePoint {
     type Output = AffinePoint;
 
-    fn mul(self, _scalar: NonZeroScalar) -> Self {
-        unimplemented!();
+    fn mul(self, scalar: NonZeroScalar) -> Self {
+        (ProjectivePoint::from(self) * scalar.as_ref()).to_affine()
     }
 }
 
 /// Example projective point type
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct ProjectivePoint {
-    /// Is this point supposed to be the additive identity?
-    /// (a.k.a. point at infinity)
-    identity: bool,
-}
-
-impl Default for ProjectivePoint {
-    fn default() -> Self {
-        Self { identity: true }
-    }
-}
+///
+/// Points are represented by their discrete logarithm with respect to the
+/// generator. The affine encoding of a point uses this logarithm as both its
+/// `x` and `y` coordinate.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ProjectivePoint(Scalar);
 
 impl From<AffinePoint> for ProjectivePoint {
     fn from(point: AffinePoint) -> ProjectivePoint {
-        Self {
-            identity: point.inner.is_identity(),
+        match point.inner.x() {
+            Some(x) => Self(Scalar::reduce(x)),
+            None => Self::default(),
         }
     }
 }
 
 impl FromEncodedPoint<MockCurve> for ProjectivePoint {
-    fn from_encoded_point(_point: &EncodedPoint) -> Option<Self> {
-        unimplemented!();
+    fn from_encoded_point(point: &EncodedPoint) -> CtOption<Self> {
+        AffinePoint::from_encoded_point(point).map(Self::from)
     }
 }
 
 impl ToEncodedPoint<MockCurve> for ProjectivePoint {
-    fn to_encoded_point(&self, _compress: bool) -> EncodedPoint {
-        unimplemented!();
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.to_affine().to_encoded_point(compress)
     }
 }
 
 impl group::Group for ProjectivePoint {
     type Scalar = Scalar;
 
-    fn random(_rng: impl RngCore) -> Self {
-        unimplemented!();
+    fn random(rng: impl RngCore) -> Self {
+        Self(Scalar::random(rng))
     }
 
     fn identity() -> Self {
-        unimplemented!();
+        Self::default()
     }
 
     fn generator() -> Self {
-        unimplemented!();
+        Self(Scalar::one())
     }
 
     fn is_identity(&self) -> Choice {
-        Choice::from(self.identity as u8)
+        self.0.ct_eq(&Scalar::zero())
     }
 
     #[must_use]
     fn double(&self) -> Self {
-        unimplemented!();
+        Self(self.0.double())
     }
 }
 
@@ -477,159 +621,166 @@ impl group::Curve for ProjectivePoint {
     type AffineRepr = AffinePoint;
 
     fn to_affine(&self) -> AffinePoint {
-        unimplemented!();
+        let inner = if self.0.is_zero() {
+            EncodedPoint::identity()
+        } else {
+            let coordinate = self.0.to_repr();
+            EncodedPoint::from_affine_coordinates(&coordinate, &coordinate, false)
+        };
+
+        AffinePoint { inner }
     }
 }
 
 impl Add<ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: ProjectivePoint) -> ProjectivePoint {
+        Self(self.0 + other.0)
     }
 }
 
 impl Add<&ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        Self(self.0 + other.0)
     }
 }
 
 impl AddAssign<ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl AddAssign<&ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl Sub<ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: ProjectivePoint) -> ProjectivePoint {
+        Self(self.0 - other.0)
     }
 }
 
 impl Sub<&ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        Self(self.0 - other.0)
     }
 }
 
 impl SubAssign<ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl SubAssign<&ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl Add<AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self + ProjectivePoint::from(other)
     }
 }
 
 impl Add<&AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: &AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        self + ProjectivePoint::from(*other)
     }
 }
 
 impl AddAssign<AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: AffinePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl AddAssign<&AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &AffinePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl Sum for ProjectivePoint {
-    fn sum<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
     }
 }
 
 impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
-    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
     }
 }
 
 impl Sub<AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        self - ProjectivePoint::from(other)
     }
 }
 
 impl Sub<&AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: &AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        self - ProjectivePoint::from(*other)
     }
 }
 
 impl SubAssign<AffinePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: AffinePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: AffinePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl SubAssign<&AffinePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: &AffinePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl Mul<Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn mul(self, _other: Scalar) -> ProjectivePoint {
-        unimplemented!();
+    fn mul(self, other: Scalar) -> ProjectivePoint {
+        Self(self.0 * other)
     }
 }
 
 impl Mul<&Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn mul(self, _other: &Scalar) -> ProjectivePoint {
-        unimplemented!();
+    fn mul(self, other: &Scalar) -> ProjectivePoint {
+        Self(self.0 * other)
     }
 }
 
 impl MulAssign<Scalar> for ProjectivePoint {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for ProjectivePoint {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
@@ -637,6 +788,6 @@ impl Neg for ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn neg(self) -> ProjectivePoint {
-        unimplemented!();
+        Self(-self.0)
     }
 }
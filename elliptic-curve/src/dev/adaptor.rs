@@ -0,0 +1,298 @@
+//! Reference implementation of the [`crate::adaptor`] traits:
+//! Schnorr adaptor signatures over [`MockCurve`].
+//!
+//! A signature `(R, s)` of `msg` under the public key `P = x·G` satisfies
+//! `s·G = R + e·P` with the challenge `e = H(R || P || msg)`. A pre-signature
+//! for the adaptor point `T` is `(R, s')` with `R = k·G + T` and
+//! `s' = k + e·x`, so that `s = s' + t` completes it.
+//!
+//! The challenge hash `D` may have any output size: its output is interpreted
+//! as a big-endian integer and reduced modulo the scalar field order.
+
+use super::{FieldBytes, MockCurve, NonZeroScalar, ProjectivePoint, Scalar};
+use crate::{
+    adaptor::{self, AdaptorSigner, AdaptorVerifier},
+    digest::Digest,
+    ff::Field,
+    group::Group,
+    rand_core::{CryptoRng, RngCore},
+    sec1::ToEncodedPoint,
+    Error,
+};
+use core::marker::PhantomData;
+
+/// Schnorr signature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Signature {
+    r: ProjectivePoint,
+    s: Scalar,
+}
+
+/// Schnorr pre-signature, i.e. an adaptor signature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PreSignature {
+    r: ProjectivePoint,
+    s: Scalar,
+}
+
+/// Secret key which creates pre-signatures, using the digest `D` for
+/// challenges.
+#[derive(Clone)]
+pub struct SigningKey<D> {
+    secret: NonZeroScalar,
+    verifying_key: VerifyingKey<D>,
+}
+
+/// Public key which verifies signatures and pre-signatures, using the digest
+/// `D` for challenges.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VerifyingKey<D> {
+    point: ProjectivePoint,
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest> SigningKey<D> {
+    /// Create signing key from the given secret scalar.
+    pub fn new(secret: NonZeroScalar) -> Self {
+        let verifying_key = VerifyingKey {
+            point: ProjectivePoint::generator() * secret.as_ref(),
+            digest: PhantomData,
+        };
+
+        Self {
+            secret,
+            verifying_key,
+        }
+    }
+
+    /// Get the corresponding verifying key.
+    pub fn verifying_key(&self) -> &VerifyingKey<D> {
+        &self.verifying_key
+    }
+}
+
+impl<D: Digest> VerifyingKey<D> {
+    /// Verify a complete Schnorr signature of `msg`.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        let e = self.challenge(&signature.r, msg);
+        let expected = signature.r + self.point * e;
+
+        if ProjectivePoint::generator() * signature.s == expected {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Compute the challenge `e = H(R || P || msg)`.
+    fn challenge(&self, r: &ProjectivePoint, msg: &[u8]) -> Scalar {
+        let digest = D::new()
+            .chain(r.to_encoded_point(true).as_bytes())
+            .chain(self.point.to_encoded_point(true).as_bytes())
+            .chain(msg)
+            .finalize();
+
+        reduce_wide(&digest)
+    }
+}
+
+impl<D: Digest> AdaptorSigner<MockCurve> for SigningKey<D> {
+    type PreSignature = PreSignature;
+
+    fn pre_sign(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+        adaptor: &ProjectivePoint,
+    ) -> Result<PreSignature, Error> {
+        let k = NonZeroScalar::random(rng);
+        let r = ProjectivePoint::generator() * k.as_ref() + adaptor;
+        let e = self.verifying_key.challenge(&r, msg);
+
+        Ok(PreSignature {
+            r,
+            s: *k + e * self.secret.as_ref(),
+        })
+    }
+}
+
+impl<D: Digest> AdaptorVerifier<MockCurve, PreSignature> for VerifyingKey<D> {
+    fn verify_pre_signature(
+        &self,
+        msg: &[u8],
+        adaptor: &ProjectivePoint,
+        pre_signature: &PreSignature,
+    ) -> Result<(), Error> {
+        let e = self.challenge(&pre_signature.r, msg);
+        let expected = pre_signature.r - adaptor + self.point * e;
+
+        if ProjectivePoint::generator() * pre_signature.s == expected {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl adaptor::PreSignature<MockCurve> for PreSignature {
+    type Signature = Signature;
+
+    fn adapt(&self, secret: &NonZeroScalar) -> Signature {
+        Signature {
+            r: self.r,
+            s: self.s + secret.as_ref(),
+        }
+    }
+
+    fn extract(
+        &self,
+        signature: &Signature,
+        adaptor: &ProjectivePoint,
+    ) -> Result<NonZeroScalar, Error> {
+        if signature.r != self.r {
+            return Err(Error);
+        }
+
+        let secret =
+            Option::<NonZeroScalar>::from(NonZeroScalar::new(signature.s - self.s)).ok_or(Error)?;
+
+        if ProjectivePoint::generator() * secret.as_ref() == *adaptor {
+            Ok(secret)
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+/// Reduce a big-endian integer of any length modulo the scalar field order.
+fn reduce_wide(bytes: &[u8]) -> Scalar {
+    // 2^256 mod n
+    let radix = Scalar::reduce(&FieldBytes::from([0xff; 32])) + Scalar::one();
+
+    bytes.rchunks(32).rev().fold(Scalar::zero(), |acc, chunk| {
+        let mut limb = FieldBytes::default();
+        limb[32 - chunk.len()..].copy_from_slice(chunk);
+        acc * radix + Scalar::reduce(&limb)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reduce_wide, Scalar, SigningKey};
+    use crate::{
+        adaptor::{AdaptorSigner, AdaptorVerifier, PreSignature},
+        dev::{NonZeroScalar, ProjectivePoint, XorShift},
+        digest::Digest,
+        ff::Field,
+        group::Group,
+        FromDigest,
+    };
+    use sha2::{Sha256, Sha512};
+
+    const MSG: &[u8] = b"atomic swap";
+
+    fn scalar(n: u64) -> NonZeroScalar {
+        NonZeroScalar::new(Scalar::from(n)).unwrap()
+    }
+
+    /// Pre-sign `MSG`, then adapt and extract with the adaptor secret `t`.
+    fn adapt_and_extract<D: Digest>() {
+        let signing_key = SigningKey::<D>::new(scalar(42));
+        let verifying_key = signing_key.verifying_key();
+        let t = scalar(7);
+        let adaptor = ProjectivePoint::generator() * t.as_ref();
+
+        let pre_signature = signing_key.pre_sign(XorShift(1), MSG, &adaptor).unwrap();
+        assert!(verifying_key
+            .verify_pre_signature(MSG, &adaptor, &pre_signature)
+            .is_ok());
+
+        let signature = pre_signature.adapt(&t);
+        assert!(verifying_key.verify(MSG, &signature).is_ok());
+        assert_eq!(
+            pre_signature
+                .extract(&signature, &adaptor)
+                .unwrap()
+                .as_ref(),
+            t.as_ref()
+        );
+    }
+
+    #[test]
+    fn adapt_and_extract_sha256() {
+        adapt_and_extract::<Sha256>();
+    }
+
+    #[test]
+    fn adapt_and_extract_sha512() {
+        adapt_and_extract::<Sha512>();
+    }
+
+    #[test]
+    fn verify_pre_signature_rejects_mismatch() {
+        let signing_key = SigningKey::<Sha256>::new(scalar(42));
+        let verifying_key = signing_key.verifying_key();
+        let adaptor = ProjectivePoint::generator() * scalar(7).as_ref();
+        let pre_signature = signing_key.pre_sign(XorShift(1), MSG, &adaptor).unwrap();
+
+        let other_adaptor = ProjectivePoint::generator() * scalar(8).as_ref();
+        assert!(verifying_key
+            .verify_pre_signature(b"other", &adaptor, &pre_signature)
+            .is_err());
+        assert!(verifying_key
+            .verify_pre_signature(MSG, &other_adaptor, &pre_signature)
+            .is_err());
+
+        let other_key = SigningKey::<Sha256>::new(scalar(43));
+        assert!(other_key
+            .verifying_key()
+            .verify_pre_signature(MSG, &adaptor, &pre_signature)
+            .is_err());
+    }
+
+    #[test]
+    fn pre_signature_is_not_a_signature() {
+        let signing_key = SigningKey::<Sha256>::new(scalar(42));
+        let adaptor = ProjectivePoint::generator() * scalar(7).as_ref();
+        let pre_signature = signing_key.pre_sign(XorShift(1), MSG, &adaptor).unwrap();
+
+        // adapting with the wrong secret doesn't produce a valid signature
+        let signature = pre_signature.adapt(&scalar(8));
+        assert!(signing_key.verifying_key().verify(MSG, &signature).is_err());
+    }
+
+    #[test]
+    fn extract_rejects_unrelated_signature() {
+        let signing_key = SigningKey::<Sha256>::new(scalar(42));
+        let t = scalar(7);
+        let adaptor = ProjectivePoint::generator() * t.as_ref();
+        let pre_signature = signing_key.pre_sign(XorShift(1), MSG, &adaptor).unwrap();
+        let signature = pre_signature.adapt(&t);
+
+        let other_adaptor = ProjectivePoint::generator() * scalar(8).as_ref();
+        assert!(pre_signature.extract(&signature, &other_adaptor).is_err());
+
+        let other = signing_key.pre_sign(XorShift(2), MSG, &adaptor).unwrap();
+        assert!(other.extract(&signature, &adaptor).is_err());
+        assert!(pre_signature
+            .extract(&pre_signature.adapt(&scalar(8)), &adaptor)
+            .is_err());
+    }
+
+    #[test]
+    fn reduce_wide_matches_narrow_reduction() {
+        let digest = Sha256::new().chain(MSG);
+        assert_eq!(
+            reduce_wide(&digest.clone().finalize()),
+            Scalar::from_digest(digest)
+        );
+
+        // 2^256 + 1
+        let mut wide = [0u8; 33];
+        wide[0] = 1;
+        wide[32] = 1;
+        let radix = reduce_wide(&wide[..]) - Scalar::one();
+        assert_eq!(reduce_wide(&[0xff; 32]) + Scalar::one(), radix);
+        assert_eq!(reduce_wide(&[]), Scalar::zero());
+    }
+}
@@ -0,0 +1,253 @@
+//! Fixed-vs-random timing tests in the style of [dudect].
+//!
+//! These tests measure the execution time of an operation for two classes
+//! of inputs: a single fixed input, and freshly generated random inputs.
+//! Measurements of both classes are interleaved in random order and compared
+//! using Welch's t-test. A large absolute t-statistic indicates that the
+//! execution time depends on the input, i.e. the operation is likely not
+//! constant time.
+//!
+//! This is a smoke test, not a proof of constant-time behavior: results are
+//! affected by the platform, compiler and system noise, and the test should
+//! be run with optimizations enabled and with a large number of samples.
+//!
+//! [dudect]: https://eprint.iacr.org/2016/1123.pdf
+
+use crate::{
+    ff::{Field, PrimeField},
+    group::Group,
+    rand_core::RngCore,
+    scalar::{NonZeroScalar, Scalar},
+    subtle::Choice,
+    Curve, FieldBytes, ProjectiveArithmetic,
+};
+use std::{time::Instant, vec::Vec};
+
+/// Threshold for the absolute t-statistic above which an operation is
+/// considered to leak timing information, as used by dudect.
+pub const T_THRESHOLD: f64 = 4.5;
+
+/// Percentile of measurements kept after discarding outliers, e.g. caused
+/// by interrupts or context switches.
+const CROP_PERCENTILE: f64 = 0.9;
+
+/// Result of a fixed-vs-random timing test.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TimingReport {
+    /// Number of measurements used to compute the t-statistic.
+    pub samples: usize,
+
+    /// Welch's t-statistic comparing the fixed and random input classes.
+    pub t_statistic: f64,
+}
+
+impl TimingReport {
+    /// Did the test detect a timing difference between the input classes,
+    /// i.e. is the absolute t-statistic above [`T_THRESHOLD`]?
+    pub fn is_leaky(&self) -> bool {
+        self.t_statistic.abs() > T_THRESHOLD
+    }
+}
+
+/// Run a fixed-vs-random timing test over an arbitrary operation.
+///
+/// The operation returns a [`Choice`] derived from its result, which is used
+/// to prevent the compiler from optimizing the operation away.
+pub fn fixed_vs_random<I, R, Op>(
+    mut rng: R,
+    samples: usize,
+    fixed: I,
+    mut random: impl FnMut(&mut R) -> I,
+    mut op: Op,
+) -> TimingReport
+where
+    I: Clone,
+    R: RngCore,
+    Op: FnMut(&I) -> Choice,
+{
+    // Prepare inputs ahead of time so input generation isn't measured
+    let inputs: Vec<(bool, I)> = (0..samples)
+        .map(|_| {
+            if rng.next_u32() & 1 == 0 {
+                (false, fixed.clone())
+            } else {
+                (true, random(&mut rng))
+            }
+        })
+        .collect();
+
+    let mut sink = 0u8;
+    let mut measurements = Vec::with_capacity(samples);
+
+    for (class, input) in &inputs {
+        let start = Instant::now();
+        sink ^= op(input).unwrap_u8();
+        measurements.push((*class, start.elapsed().as_nanos() as f64));
+    }
+
+    // `Choice::from` acts as an optimization barrier for `sink`
+    let _ = Choice::from(sink & 1);
+
+    let mut sorted: Vec<f64> = measurements.iter().map(|(_, t)| *t).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted
+        .get((sorted.len() as f64 * CROP_PERCENTILE) as usize)
+        .copied()
+        .unwrap_or(f64::INFINITY);
+
+    let mut stats = [Welford::default(), Welford::default()];
+    for (class, time) in measurements {
+        if time <= cutoff {
+            stats[class as usize].push(time);
+        }
+    }
+
+    TimingReport {
+        samples: stats[0].n + stats[1].n,
+        t_statistic: Welford::t_statistic(&stats[0], &stats[1]),
+    }
+}
+
+/// Fixed-vs-random timing test for scalar multiplication of the group
+/// generator, e.g. `scalar_mul::<ProjectivePoint<C>>(rng, 100_000)` for a
+/// curve `C` with a [`ProjectiveArithmetic`][crate::ProjectiveArithmetic]
+/// implementation.
+///
+/// The fixed class uses the zero scalar.
+pub fn scalar_mul<G: Group>(rng: impl RngCore, samples: usize) -> TimingReport {
+    let generator = G::generator();
+    fixed_vs_random(
+        rng,
+        samples,
+        G::Scalar::zero(),
+        |rng| G::Scalar::random(rng),
+        |scalar| (generator * *scalar).is_identity(),
+    )
+}
+
+/// Fixed-vs-random timing test for field element inversion.
+///
+/// The fixed class uses the element one.
+pub fn field_inversion<F: Field>(rng: impl RngCore, samples: usize) -> TimingReport {
+    fixed_vs_random(
+        rng,
+        samples,
+        F::one(),
+        |rng| F::random(rng),
+        |element| element.invert().is_some(),
+    )
+}
+
+/// Fixed-vs-random timing test for decoding a [`NonZeroScalar`].
+///
+/// The fixed class uses the all-zero encoding, which is rejected, while the
+/// random class uses random bytes, which are almost always a valid encoding
+/// for curves with a scalar modulus close to a power of two. A leak indicates
+/// that decoding returns early on invalid input.
+pub fn nonzero_scalar_from_repr<C>(rng: impl RngCore, samples: usize) -> TimingReport
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fixed_vs_random(
+        rng,
+        samples,
+        FieldBytes::<C>::default(),
+        |rng| {
+            let mut repr = FieldBytes::<C>::default();
+            rng.fill_bytes(&mut repr);
+            repr
+        },
+        |repr| NonZeroScalar::<C>::from_repr(repr.clone()).is_some(),
+    )
+}
+
+/// Online mean and variance computation (Welford's algorithm).
+#[derive(Default)]
+struct Welford {
+    n: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    fn push(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.n < 2 {
+            0.0
+        } else {
+            self.m2 / (self.n - 1) as f64
+        }
+    }
+
+    /// Welch's t-statistic for two samples.
+    fn t_statistic(a: &Self, b: &Self) -> f64 {
+        if a.n == 0 || b.n == 0 {
+            return 0.0;
+        }
+
+        let se = (a.variance() / a.n as f64 + b.variance() / b.n as f64).sqrt();
+        if se == 0.0 {
+            0.0
+        } else {
+            (a.mean - b.mean) / se
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Welford;
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar, XorShift};
+
+    #[test]
+    fn welch_t_statistic() {
+        let mut a = Welford::default();
+        let mut b = Welford::default();
+        for x in &[1.0, 2.0, 3.0, 4.0] {
+            a.push(*x);
+            b.push(*x + 10.0);
+        }
+
+        assert_eq!(a.mean, 2.5);
+        assert!((a.variance() - 5.0 / 3.0).abs() < 1e-12);
+        assert!((Welford::t_statistic(&a, &b) + 10.954451150103322).abs() < 1e-9);
+        assert_eq!(Welford::t_statistic(&a, &a), 0.0);
+    }
+
+    /// Run each harness with a few samples, to check it works end to end.
+    #[test]
+    fn harnesses_run() {
+        let samples = 200;
+        let reports = [
+            super::nonzero_scalar_from_repr::<MockCurve>(XorShift(1), samples),
+            super::scalar_mul::<ProjectivePoint>(XorShift(2), samples),
+            super::field_inversion::<Scalar>(XorShift(3), samples),
+        ];
+
+        for report in &reports {
+            assert!(
+                report.samples > 0 && report.samples <= samples,
+                "{:?}",
+                report
+            );
+            assert!(report.t_statistic.is_finite(), "{:?}", report);
+        }
+    }
+
+    // Timing measurements are noisy in debug builds and on shared CI runners:
+    // run with `cargo test --release --all-features -- --ignored`
+    #[test]
+    #[ignore]
+    fn nonzero_scalar_from_repr_does_not_return_early() {
+        let report = super::nonzero_scalar_from_repr::<MockCurve>(XorShift(0x2545_f491), 1_000_000);
+        assert!(!report.is_leaky(), "{:?}", report);
+    }
+}
@@ -29,12 +29,16 @@
 use crate::{
     consts::U1,
     public_key::PublicKey,
-    scalar::NonZeroScalar,
+    scalar::{NonZeroScalar, SecretScalar},
     sec1::{EncodedPoint, ToEncodedPoint, UncompressedPointSize, UntaggedPointSize},
     weierstrass::Curve,
     AffinePoint, FieldBytes, ProjectiveArithmetic, ProjectivePoint, Scalar,
 };
-use core::{borrow::Borrow, fmt::Debug, ops::Add};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    ops::Add,
+};
 use ff::PrimeField;
 use generic_array::ArrayLength;
 use group::Curve as _;
@@ -57,7 +61,7 @@ use zeroize::Zeroize;
 ///
 /// ```ignore
 /// let shared_secret = elliptic_curve::ecdh::diffie_hellman(
-///     secret_key.secret_scalar(),
+///     secret_key.secret_scalar().expose_secret(),
 ///     public_key.as_affine()
 /// );
 /// ```
@@ -109,7 +113,7 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
 {
-    scalar: NonZeroScalar<C>,
+    scalar: SecretScalar<C>,
 }
 
 impl<C> EphemeralSecret<C>
@@ -125,7 +129,7 @@ where
     /// Generate a cryptographically random [`EphemeralSecret`].
     pub fn random(rng: impl CryptoRng + RngCore) -> Self {
         Self {
-            scalar: NonZeroScalar::random(rng),
+            scalar: SecretScalar::random(rng),
         }
     }
 
@@ -133,13 +137,13 @@ where
     ///
     /// The `compress` flag enables point compression.
     pub fn public_key(&self) -> PublicKey<C> {
-        PublicKey::from_secret_scalar(&self.scalar)
+        PublicKey::from_secret_scalar(self.scalar.expose_secret())
     }
 
     /// Compute a Diffie-Hellman shared secret from an ephemeral secret and the
     /// public key of the other participant in the exchange.
     pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
-        diffie_hellman(&self.scalar, public_key.as_affine())
+        diffie_hellman(self.scalar.expose_secret(), public_key.as_affine())
     }
 }
 
@@ -158,6 +162,17 @@ where
     }
 }
 
+impl<C> Debug for EphemeralSecret<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EphemeralSecret<{:?}>{{ ... }}", C::default())
+    }
+}
+
 impl<C> Zeroize for EphemeralSecret<C>
 where
     C: Curve + ProjectiveArithmetic,
@@ -236,6 +251,17 @@ where
     }
 }
 
+impl<C> Debug for SharedSecret<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SharedSecret<{:?}>{{ ... }}", C::default())
+    }
+}
+
 impl<C> Zeroize for SharedSecret<C>
 where
     C: Curve + ProjectiveArithmetic,
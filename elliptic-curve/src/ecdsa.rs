@@ -0,0 +1,458 @@
+//! Elliptic Curve Digital Signature Algorithm (ECDSA) signature type.
+//!
+//! This module provides a curve-generic [`Signature`] container which curve
+//! implementation crates can use directly, rather than each defining their
+//! own signature type.
+//!
+//! Signatures can be converted to and from two encodings:
+//!
+//! - Fixed-size: the big endian serialization of `r` followed by the big
+//!   endian serialization of `s`, each encoded as [`FieldBytes`]. This is the
+//!   encoding used by the [`signature::Signature`] impl and [`AsRef<[u8]>`].
+//! - ASN.1 DER: a `SEQUENCE` of two `INTEGER`s as described in RFC 3279
+//!   Section 2.2.3, see [`Signature::from_der`] and [`Signature::to_der`].
+//!
+//! Low-S normalization (as required by e.g. Bitcoin) is supported via
+//! [`Signature::normalize_s`] and the [`CanonicalSignature`] impl. It assumes
+//! that the curve's scalars serialize as big endian integers, which is the
+//! case for all curves implemented in terms of this crate.
+
+use crate::{
+    scalar::{NonZeroScalar, Scalar},
+    Curve, Error, FieldBytes, ProjectiveArithmetic,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Debug},
+    ops::Add,
+};
+use ff::PrimeField;
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use signature::CanonicalSignature;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// ASN.1 DER tag for `SEQUENCE`.
+const SEQUENCE_TAG: u8 = 0x30;
+
+/// ASN.1 DER tag for `INTEGER`.
+const INTEGER_TAG: u8 = 0x02;
+
+/// Size of a fixed-size ECDSA signature for the given elliptic curve, i.e.
+/// twice the size of a field element.
+pub type SignatureSize<C> = <<C as Curve>::FieldSize as Add>::Output;
+
+/// Fixed-size byte array containing an ECDSA signature.
+pub type SignatureBytes<C> = GenericArray<u8, SignatureSize<C>>;
+
+/// ECDSA signature, consisting of two non-zero scalars `r` and `s`.
+///
+/// Internally stored in the fixed-size encoding. Both scalars are checked to
+/// be non-zero and in range when the signature is constructed, so decoding
+/// them again via [`Signature::r`] and [`Signature::s`] never fails.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    bytes: SignatureBytes<C>,
+}
+
+impl<C> Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a [`Signature`] from the `r` and `s` scalars.
+    pub fn from_scalars(r: NonZeroScalar<C>, s: NonZeroScalar<C>) -> Self {
+        let size = C::FieldSize::to_usize();
+        let mut bytes = SignatureBytes::<C>::default();
+        bytes[..size].copy_from_slice(&r.to_repr());
+        bytes[size..].copy_from_slice(&s.to_repr());
+        Self { bytes }
+    }
+
+    /// Decode a [`Signature`] from the big endian serializations of `r` and
+    /// `s`.
+    ///
+    /// Returns an error if either of them is zero or out of range.
+    pub fn from_scalar_bytes(r: &FieldBytes<C>, s: &FieldBytes<C>) -> Result<Self, Error> {
+        let r = NonZeroScalar::from_repr(r.clone());
+        let s = NonZeroScalar::from_repr(s.clone());
+
+        if (r.is_some() & s.is_some()).into() {
+            Ok(Self::from_scalars(r.unwrap(), s.unwrap()))
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Decode a [`Signature`] from its ASN.1 DER encoding.
+    ///
+    /// Only strict DER is accepted, i.e. integers must be minimally encoded
+    /// and positive, and no trailing data is allowed.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let (tag, body, rest) = der_read_tlv(bytes)?;
+
+        if tag != SEQUENCE_TAG || !rest.is_empty() {
+            return Err(Error);
+        }
+
+        let (r, body) = der_read_integer::<C>(body)?;
+        let (s, body) = der_read_integer::<C>(body)?;
+
+        if !body.is_empty() {
+            return Err(Error);
+        }
+
+        Self::from_scalar_bytes(&r, &s)
+    }
+
+    /// Encode this signature as ASN.1 DER into the provided buffer,
+    /// returning the encoded slice.
+    ///
+    /// Returns an error if the buffer is too small.
+    pub fn encode_der<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let (r, s) = self.split_bytes();
+        let (r, s) = (der_trim_integer(r), der_trim_integer(s));
+
+        // Leading zero byte is needed if the high bit of the integer is set
+        let r_len = r.len() + (r[0] >> 7) as usize;
+        let s_len = s.len() + (s[0] >> 7) as usize;
+        let body_len = 2 + r_len + 2 + s_len;
+        let header_len = if body_len < 0x80 { 2 } else { 3 };
+        let total_len = header_len + body_len;
+
+        if body_len > 0xFF || buf.len() < total_len {
+            return Err(Error);
+        }
+
+        buf[0] = SEQUENCE_TAG;
+        if header_len == 2 {
+            buf[1] = body_len as u8;
+        } else {
+            buf[1] = 0x81;
+            buf[2] = body_len as u8;
+        }
+
+        let pos = der_write_integer(buf, header_len, r, r_len);
+        der_write_integer(buf, pos, s, s_len);
+        Ok(&buf[..total_len])
+    }
+
+    /// Encode this signature as ASN.1 DER.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_der(&self) -> Vec<u8> {
+        // SEQUENCE header (3) + 2 * (INTEGER header (2) + leading zero (1))
+        let mut buf = alloc::vec![0u8; 9 + SignatureSize::<C>::to_usize()];
+        let len = self
+            .encode_der(&mut buf)
+            .expect("DER buffer too small")
+            .len();
+        buf.truncate(len);
+        buf
+    }
+
+    /// Get the `r` component of this signature.
+    pub fn r(&self) -> NonZeroScalar<C> {
+        let (r, _) = self.split_bytes();
+        Option::from(NonZeroScalar::from_repr(GenericArray::clone_from_slice(r)))
+            .expect("r was validated on construction")
+    }
+
+    /// Get the `s` component of this signature.
+    pub fn s(&self) -> NonZeroScalar<C> {
+        let (_, s) = self.split_bytes();
+        Option::from(NonZeroScalar::from_repr(GenericArray::clone_from_slice(s)))
+            .expect("s was validated on construction")
+    }
+
+    /// Split this signature into its `r` and `s` components.
+    pub fn split_scalars(&self) -> (NonZeroScalar<C>, NonZeroScalar<C>) {
+        (self.r(), self.s())
+    }
+
+    /// Is the `s` component of this signature in the lower half of the
+    /// scalar field, i.e. `s <= (n - 1) / 2`?
+    pub fn is_low_s(&self) -> bool {
+        let s = self.s();
+        let neg_s = -*s.as_ref();
+        s.to_repr() <= neg_s.to_repr()
+    }
+
+    /// Normalize `s` to the lower half of the scalar field.
+    ///
+    /// Returns the normalized signature if `s` was high, i.e. `(r, n - s)`,
+    /// or `None` if the signature is already low-S.
+    pub fn normalize_s(&self) -> Option<Self> {
+        if self.is_low_s() {
+            return None;
+        }
+
+        let neg_s = Option::from(NonZeroScalar::new(-*self.s().as_ref()))
+            .expect("negation of non-zero scalar");
+        Some(Self::from_scalars(self.r(), neg_s))
+    }
+
+    /// Split the fixed-size encoding into the serializations of `r` and `s`.
+    fn split_bytes(&self) -> (&[u8], &[u8]) {
+        self.bytes.split_at(C::FieldSize::to_usize())
+    }
+}
+
+impl<C> AsRef<[u8]> for Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<C> Debug for Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ecdsa::Signature<{:?}>(", C::default())?;
+
+        for byte in self.bytes.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<C> From<Signature<C>> for SignatureBytes<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> SignatureBytes<C> {
+        signature.bytes
+    }
+}
+
+impl<C> TryFrom<&[u8]> for Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = signature::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, signature::Error> {
+        if bytes.len() != SignatureSize::<C>::to_usize() {
+            return Err(signature::Error::new());
+        }
+
+        let (r, s) = bytes.split_at(C::FieldSize::to_usize());
+        Self::from_scalar_bytes(GenericArray::from_slice(r), GenericArray::from_slice(s))
+            .map_err(|_| signature::Error::new())
+    }
+}
+
+impl<C> signature::Signature for Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<Self, signature::Error> {
+        Self::try_from(bytes)
+    }
+}
+
+/// Low-S signatures are considered canonical.
+impl<C> CanonicalSignature for Signature<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn is_canonical(&self) -> bool {
+        self.is_low_s()
+    }
+
+    fn normalize(&self) -> Self {
+        self.normalize_s().unwrap_or_else(|| self.clone())
+    }
+}
+
+/// Read a DER tag-length-value with a one-byte tag, returning the tag, the
+/// value and the remaining input.
+fn der_read_tlv(bytes: &[u8]) -> Result<(u8, &[u8], &[u8]), Error> {
+    let (&tag, bytes) = bytes.split_first().ok_or(Error)?;
+    let (&len, bytes) = bytes.split_first().ok_or(Error)?;
+
+    let (len, bytes) = match len {
+        0..=0x7F => (len as usize, bytes),
+        0x81 => {
+            let (&len, bytes) = bytes.split_first().ok_or(Error)?;
+
+            // Long form must only be used for lengths which need it
+            if len < 0x80 {
+                return Err(Error);
+            }
+
+            (len as usize, bytes)
+        }
+        _ => return Err(Error),
+    };
+
+    if bytes.len() < len {
+        return Err(Error);
+    }
+
+    let (value, rest) = bytes.split_at(len);
+    Ok((tag, value, rest))
+}
+
+/// Read a positive, minimally encoded DER `INTEGER` which fits in a field
+/// element, returning its big endian serialization and the remaining input.
+fn der_read_integer<C: Curve>(bytes: &[u8]) -> Result<(FieldBytes<C>, &[u8]), Error> {
+    let (tag, value, rest) = der_read_tlv(bytes)?;
+
+    if tag != INTEGER_TAG || value.is_empty() || value[0] & 0x80 != 0 {
+        return Err(Error);
+    }
+
+    let value = if value[0] == 0 && value.len() > 1 {
+        // Leading zero is only allowed if the high bit of the next byte is set
+        if value[1] & 0x80 == 0 {
+            return Err(Error);
+        }
+
+        &value[1..]
+    } else {
+        value
+    };
+
+    let size = C::FieldSize::to_usize();
+
+    if value.len() > size {
+        return Err(Error);
+    }
+
+    let mut result = FieldBytes::<C>::default();
+    result[size - value.len()..].copy_from_slice(value);
+    Ok((result, rest))
+}
+
+/// Strip leading zero bytes from a big endian integer, keeping at least one.
+fn der_trim_integer(bytes: &[u8]) -> &[u8] {
+    let zeroes = bytes.iter().take_while(|&&b| b == 0).count();
+    &bytes[zeroes.min(bytes.len() - 1)..]
+}
+
+/// Write a DER `INTEGER` with a value of length `len` (including a leading
+/// zero byte, if needed) at `pos`, returning the position after it.
+fn der_write_integer(buf: &mut [u8], pos: usize, value: &[u8], len: usize) -> usize {
+    buf[pos] = INTEGER_TAG;
+    buf[pos + 1] = len as u8;
+
+    let start = pos + 2 + len - value.len();
+    buf[pos + 2..start].iter_mut().for_each(|b| *b = 0);
+    buf[start..start + value.len()].copy_from_slice(value);
+    pos + 2 + len
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::Signature;
+    use crate::dev::MockCurve;
+    use hex_literal::hex;
+    use signature::CanonicalSignature;
+
+    type Sig = Signature<MockCurve>;
+
+    const HIGH_S: [u8; 64] = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001
+         ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550"
+    );
+
+    const LOW_S: [u8; 64] = hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001
+         0000000000000000000000000000000000000000000000000000000000000001"
+    );
+
+    #[test]
+    fn fixed_size_round_trip() {
+        let sig = <Sig as signature::Signature>::from_bytes(&HIGH_S).unwrap();
+        assert_eq!(sig.as_ref(), &HIGH_S[..]);
+    }
+
+    #[test]
+    fn reject_zero_and_out_of_range() {
+        let mut bytes = LOW_S;
+        bytes[31] = 0;
+        assert!(<Sig as signature::Signature>::from_bytes(&bytes).is_err());
+
+        let mut bytes = LOW_S;
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+        assert!(<Sig as signature::Signature>::from_bytes(&bytes).is_err());
+
+        assert!(<Sig as signature::Signature>::from_bytes(&LOW_S[..63]).is_err());
+    }
+
+    #[test]
+    fn der_round_trip() {
+        let sig = <Sig as signature::Signature>::from_bytes(&HIGH_S).unwrap();
+        let mut buf = [0u8; 80];
+        let der = sig.encode_der(&mut buf).unwrap();
+
+        assert_eq!(der[..7], hex!("30 26 02 01 01 02 21")[..]);
+        assert_eq!(der[7], 0);
+        assert_eq!(der.len(), 2 + 3 + 35);
+        assert_eq!(Sig::from_der(der).unwrap(), sig);
+        assert_eq!(sig.to_der(), der);
+    }
+
+    #[test]
+    fn der_reject_non_minimal() {
+        // r encoded with a superfluous leading zero
+        let der = hex!("30 07 02 02 00 01 02 01 01");
+        assert!(Sig::from_der(&der).is_err());
+
+        // negative r
+        let der = hex!("30 06 02 01 81 02 01 01");
+        assert!(Sig::from_der(&der).is_err());
+
+        // trailing data
+        let der = hex!("30 06 02 01 01 02 01 01 00");
+        assert!(Sig::from_der(&der).is_err());
+
+        let der = hex!("30 06 02 01 01 02 01 01");
+        assert!(Sig::from_der(&der).is_ok());
+    }
+
+    #[test]
+    fn low_s_normalization() {
+        let high = <Sig as signature::Signature>::from_bytes(&HIGH_S).unwrap();
+        let low = <Sig as signature::Signature>::from_bytes(&LOW_S).unwrap();
+
+        assert!(!high.is_canonical());
+        assert!(low.is_canonical());
+        assert_eq!(high.normalize(), low);
+        assert!(low.normalize_s().is_none());
+    }
+}
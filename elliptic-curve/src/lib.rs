@@ -2,6 +2,17 @@
 //! and traits for representing various elliptic curve forms, scalars, points,
 //! and public/secret keys composed thereof.
 //!
+//! ## Usage without `arithmetic`
+//!
+//! Curve crates which don't provide arithmetic (e.g. ones delegating all
+//! operations to a hardware security module) can disable the `arithmetic`
+//! feature and implement only the byte-level traits: `secret_key::SecretValue`
+//! to validate secret keys, and [`sec1::ValidatePublicKey`] to validate
+//! public keys. `SecretKey` (which requires the `zeroize` feature) and
+//! [`sec1::EncodedPoint`], including their PKCS#8 support, are available in
+//! this configuration, with [`sec1::EncodedPoint`] standing in for
+//! `PublicKey`.
+//!
 //! ## Minimum Supported Rust Version
 //!
 //! Rust **1.46** or higher.
@@ -22,7 +33,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", test))]
 extern crate std;
 
 pub mod error;
@@ -31,6 +42,9 @@ pub mod sec1;
 pub mod util;
 pub mod weierstrass;
 
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub mod adaptor;
 #[cfg(feature = "arithmetic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
 pub mod point;
@@ -39,6 +53,9 @@ pub mod point;
 pub mod public_key;
 #[cfg(feature = "arithmetic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub mod rerandomize;
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
 pub mod scalar;
 
 #[cfg(any(feature = "dev"))]
@@ -49,6 +66,10 @@ pub mod dev;
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
 pub mod ecdh;
 
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub mod ecdsa;
+
 #[cfg(feature = "zeroize")]
 #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
 pub mod secret_key;
@@ -62,7 +83,7 @@ pub use subtle;
 #[cfg(feature = "arithmetic")]
 pub use self::{
     point::{AffinePoint, ProjectiveArithmetic, ProjectivePoint},
-    public_key::PublicKey,
+    public_key::{CachedPublicKey, PublicKey},
     scalar::Scalar,
 };
 #[cfg(feature = "arithmetic")]
@@ -78,6 +99,11 @@ pub use digest::{self, Digest};
 #[cfg(feature = "pkcs8")]
 pub use pkcs8;
 
+#[cfg(feature = "ecdsa")]
+pub use signature;
+
+#[cfg(all(feature = "arithmetic", feature = "zeroize"))]
+pub use scalar::SecretScalar;
 #[cfg(feature = "zeroize")]
 pub use secret_key::SecretKey;
 #[cfg(feature = "zeroize")]
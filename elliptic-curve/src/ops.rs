@@ -4,6 +4,9 @@ pub use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 use subtle::CtOption;
 
+#[cfg(feature = "arithmetic")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 /// Perform an inversion on a field element (i.e. base field element or scalar)
 pub trait Invert {
     /// Field element type
@@ -21,3 +24,193 @@ impl<F: ff::Field> Invert for F {
         ff::Field::invert(self)
     }
 }
+
+/// Algorithm used by [`SquareRoot::square_root`] to compute square roots of
+/// prime field elements.
+///
+/// Exponents are little-endian integers represented as 64-bit limbs.
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+#[derive(Copy, Clone, Debug)]
+pub enum SqrtMethod {
+    /// Fields where `p ≡ 3 mod 4`, for which `sqrt(x) = x^((p + 1) / 4)`.
+    ThreeModFour {
+        /// `(p + 1) / 4`
+        p_plus_1_div_4: &'static [u64],
+    },
+
+    /// Constant-time Tonelli-Shanks algorithm for arbitrary odd primes.
+    ///
+    /// Uses [`ff::PrimeField::S`] and [`ff::PrimeField::root_of_unity`],
+    /// where `p - 1 = 2^S * t` with odd `t`.
+    TonelliShanks {
+        /// `(t - 1) / 2`
+        t_minus_1_div_2: &'static [u64],
+    },
+}
+
+/// Constant-time square root and quadratic residuosity test for prime field
+/// elements (i.e. base field elements or scalars).
+///
+/// Point decompression and hash-to-curve operate on secret-dependent values,
+/// so unlike [`ff::Field::sqrt`] these methods are guaranteed to run in
+/// constant time. Implementers only need to provide the exponents below.
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub trait SquareRoot: ff::PrimeField + ConstantTimeEq {
+    /// `(p - 1) / 2` as little-endian 64-bit limbs.
+    const P_MINUS_1_DIV_2: &'static [u64];
+
+    /// Algorithm used for computing square roots in this field.
+    const SQRT_METHOD: SqrtMethod;
+
+    /// Compute the Legendre symbol of this element via Euler's criterion.
+    ///
+    /// Returns zero if the element is zero, one if it's a non-zero square,
+    /// and `-1` otherwise.
+    fn legendre(&self) -> Self {
+        self.pow_vartime(Self::P_MINUS_1_DIV_2)
+    }
+
+    /// Is this element a square (including zero)?
+    fn is_square(&self) -> Choice {
+        let legendre = self.legendre();
+        legendre.ct_eq(&Self::zero()) | legendre.ct_eq(&Self::one())
+    }
+
+    /// Compute the square root of this element, if it's a square.
+    fn square_root(&self) -> CtOption<Self> {
+        match Self::SQRT_METHOD {
+            SqrtMethod::ThreeModFour { p_plus_1_div_4 } => sqrt_3_mod_4(self, p_plus_1_div_4),
+            SqrtMethod::TonelliShanks { t_minus_1_div_2 } => {
+                sqrt_tonelli_shanks(self, t_minus_1_div_2)
+            }
+        }
+    }
+}
+
+/// Compute square root of `x` in a field where `p ≡ 3 mod 4`.
+///
+/// `p_plus_1_div_4` is `(p + 1) / 4` as little-endian 64-bit limbs.
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub fn sqrt_3_mod_4<F>(x: &F, p_plus_1_div_4: &[u64]) -> CtOption<F>
+where
+    F: ff::PrimeField + ConstantTimeEq,
+{
+    let root = x.pow_vartime(p_plus_1_div_4);
+    CtOption::new(root, root.square().ct_eq(x))
+}
+
+/// Compute square root of `x` using constant-time Tonelli-Shanks algorithm.
+///
+/// `t_minus_1_div_2` is `(t - 1) / 2` as little-endian 64-bit limbs, where
+/// `p - 1 = 2^S * t` with odd `t`.
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub fn sqrt_tonelli_shanks<F>(x: &F, t_minus_1_div_2: &[u64]) -> CtOption<F>
+where
+    F: ff::PrimeField + ConstantTimeEq,
+{
+    let w = x.pow_vartime(t_minus_1_div_2);
+
+    let mut v = F::S;
+    let mut root = *x * w;
+    let mut b = root * w;
+    let mut z = F::root_of_unity();
+
+    for max_v in (1..=F::S).rev() {
+        let mut k = 1;
+        let mut tmp = b.square();
+        let mut j_less_than_v = Choice::from(1);
+
+        for j in 2..max_v {
+            let tmp_is_one = tmp.ct_eq(&F::one());
+            let squared = F::conditional_select(&tmp, &z, tmp_is_one).square();
+            tmp = F::conditional_select(&squared, &tmp, tmp_is_one);
+            let new_z = F::conditional_select(&z, &squared, tmp_is_one);
+            j_less_than_v &= !j.ct_eq(&v);
+            k = u32::conditional_select(&j, &k, tmp_is_one);
+            z = F::conditional_select(&z, &new_z, j_less_than_v);
+        }
+
+        let result = root * z;
+        root = F::conditional_select(&result, &root, b.ct_eq(&F::one()));
+        z = z.square();
+        b *= z;
+        v = k;
+    }
+
+    CtOption::new(root, root.square().ct_eq(x))
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::SquareRoot;
+    use crate::{
+        dev::{Scalar, XorShift},
+        ff::{Field, PrimeField},
+    };
+
+    /// A non-square: the multiplicative generator.
+    fn non_residue() -> Scalar {
+        Scalar::multiplicative_generator()
+    }
+
+    #[test]
+    fn root_of_unity_order() {
+        let root = Scalar::root_of_unity();
+        assert_eq!(root.pow_vartime(&[1 << Scalar::S]), Scalar::one());
+        assert_ne!(root.pow_vartime(&[1 << (Scalar::S - 1)]), Scalar::one());
+    }
+
+    #[test]
+    fn sqrt_of_square() {
+        let mut rng = XorShift(0x2545_f491);
+        for _ in 0..32 {
+            let x = Scalar::random(&mut rng);
+            let root = x.square().square_root().unwrap();
+            assert!(root == x || root == -x);
+            assert_eq!(x.square().legendre(), Scalar::one());
+            assert!(bool::from(x.square().is_square()));
+        }
+    }
+
+    #[test]
+    fn sqrt_of_non_residue() {
+        let mut rng = XorShift(0x2545_f491);
+        for _ in 0..32 {
+            let x = Scalar::random(&mut rng).square() * non_residue();
+            assert!(bool::from(x.square_root().is_none()));
+            assert_eq!(x.legendre(), -Scalar::one());
+            assert!(!bool::from(x.is_square()));
+        }
+    }
+
+    #[test]
+    fn sqrt_of_zero() {
+        assert_eq!(Scalar::zero().square_root().unwrap(), Scalar::zero());
+        assert_eq!(Scalar::zero().legendre(), Scalar::zero());
+        assert!(bool::from(Scalar::zero().is_square()));
+    }
+
+    #[test]
+    fn legendre_symbol_range() {
+        let mut rng = XorShift(0x2545_f491);
+        let symbols = [Scalar::zero(), Scalar::one(), -Scalar::one()];
+        for _ in 0..32 {
+            let x = Scalar::random(&mut rng);
+            assert!(symbols.contains(&x.legendre()));
+        }
+    }
+
+    #[test]
+    fn invert() {
+        let mut rng = XorShift(0x2545_f491);
+        for _ in 0..8 {
+            let x = Scalar::random(&mut rng);
+            assert_eq!(x * x.invert().unwrap(), Scalar::one());
+        }
+        assert!(bool::from(Scalar::zero().invert().is_none()));
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::{
     consts::U1,
+    rerandomize::{Rerandomize, Tweak},
     scalar::NonZeroScalar,
     sec1::{
         EncodedPoint, FromEncodedPoint, ToEncodedPoint, UncompressedPointSize, UntaggedPointSize,
@@ -17,12 +18,10 @@ use core::{
 use ff::PrimeField;
 use generic_array::ArrayLength;
 use group::{Curve as _, Group};
+use subtle::{Choice, ConditionallySelectable, CtOption};
 
 #[cfg(feature = "pkcs8")]
-use {
-    crate::{AlgorithmParameters, ALGORITHM_OID},
-    pkcs8::FromPublicKey,
-};
+use {crate::AlgorithmParameters, pkcs8::FromPublicKey};
 
 #[cfg(feature = "pem")]
 use {
@@ -161,7 +160,13 @@ where
     C: Curve + ProjectiveArithmetic,
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
-    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    AffinePoint<C>: Copy
+        + Clone
+        + ConditionallySelectable
+        + Debug
+        + Default
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
     ProjectivePoint<C>: From<AffinePoint<C>>,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
@@ -178,7 +183,13 @@ where
     C: Curve + ProjectiveArithmetic,
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
-    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    AffinePoint<C>: Copy
+        + Clone
+        + ConditionallySelectable
+        + Debug
+        + Default
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
     ProjectivePoint<C>: From<AffinePoint<C>>,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
@@ -225,15 +236,27 @@ where
     C: Curve + ProjectiveArithmetic,
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
-    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    AffinePoint<C>: Copy
+        + Clone
+        + ConditionallySelectable
+        + Debug
+        + Default
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
     ProjectivePoint<C>: From<AffinePoint<C>>,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
-    /// Initialize [`PublicKey`] from an [`EncodedPoint`]
-    fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> Option<Self> {
-        AffinePoint::<C>::from_encoded_point(encoded_point)
-            .and_then(|point| PublicKey::from_affine(point).ok())
+    /// Initialize [`PublicKey`] from an [`EncodedPoint`].
+    ///
+    /// Returns a [`CtOption`] which is none if the point fails to decode or
+    /// is the identity. Both checks are always performed.
+    fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> CtOption<Self> {
+        let point = AffinePoint::<C>::from_encoded_point(encoded_point);
+        let is_some = point.is_some();
+        let point = point.unwrap_or(AffinePoint::<C>::default());
+        let is_identity = ProjectivePoint::<C>::from(point).is_identity();
+        CtOption::new(Self { point }, is_some & !is_identity)
     }
 }
 
@@ -263,6 +286,42 @@ where
 {
 }
 
+impl<C> ConditionallySelectable for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + ConditionallySelectable + Debug,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            point: AffinePoint::<C>::conditional_select(&a.point, &b.point, choice),
+        }
+    }
+}
+
+impl<C, T> Rerandomize<C, T> for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    T: Tweak<C>,
+{
+    fn rerandomize(&self, tweak: &T) -> CtOption<Self> {
+        let point = tweak.tweak_point(&self.to_projective());
+        let is_identity = point.is_identity();
+
+        CtOption::new(
+            Self {
+                point: point.to_affine(),
+            },
+            !is_identity,
+        )
+    }
+}
+
 impl<C> Eq for PublicKey<C>
 where
     C: Curve + ProjectiveArithmetic,
@@ -297,6 +356,7 @@ where
 impl<C> FromPublicKey for PublicKey<C>
 where
     Self: TryFrom<EncodedPoint<C>, Error = Error>,
+    EncodedPoint<C>: FromPublicKey,
     C: Curve + AlgorithmParameters + ProjectiveArithmetic,
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
@@ -306,16 +366,8 @@ where
     UncompressedPointSize<C>: ArrayLength<u8>,
 {
     fn from_spki(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> pkcs8::Result<Self> {
-        if spki.algorithm.oid != ALGORITHM_OID || spki.algorithm.parameters_oid() != Some(C::OID) {
-            return Err(pkcs8::Error::Decode);
-        }
-
-        // Look for a leading `0x00` byte in the bitstring
-        if spki.subject_public_key.get(0).cloned() != Some(0x00) {
-            return Err(pkcs8::Error::Decode);
-        }
-
-        Self::from_sec1_bytes(&spki.subject_public_key[1..]).map_err(|_| pkcs8::Error::Decode)
+        EncodedPoint::<C>::from_spki(spki)
+            .and_then(|point| Self::try_from(point).map_err(|_| pkcs8::Error::Decode))
     }
 }
 
@@ -347,6 +399,7 @@ where
 impl<C> FromStr for PublicKey<C>
 where
     Self: TryFrom<EncodedPoint<C>, Error = Error>,
+    EncodedPoint<C>: FromPublicKey,
     C: Curve + AlgorithmParameters + ProjectiveArithmetic,
     FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
@@ -379,16 +432,281 @@ where
     }
 }
 
+/// [`PublicKey`] with precomputed representations, for hot verification
+/// paths which use the same key many times.
+///
+/// Computes the projective form of the point and both SEC1 encodings once
+/// on construction, so that encoding the key or converting it to a
+/// [`ProjectivePoint`] doesn't redo any work. It implements the same
+/// traits as [`PublicKey`] and can be converted back into one at any time.
+///
+/// Fixed-base precomputation tables are not included, since the `group`
+/// crate has no generic API for them yet.
+#[derive(Clone, Debug)]
+pub struct CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    public_key: PublicKey<C>,
+    projective: ProjectivePoint<C>,
+    compressed: EncodedPoint<C>,
+    uncompressed: EncodedPoint<C>,
+}
+
+impl<C> CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Precompute cached representations of the given [`PublicKey`].
+    pub fn new(public_key: PublicKey<C>) -> Self {
+        Self {
+            public_key,
+            projective: public_key.to_projective(),
+            compressed: public_key.to_encoded_point(true),
+            uncompressed: public_key.to_encoded_point(false),
+        }
+    }
+
+    /// Borrow the inner [`PublicKey`].
+    pub fn public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+
+    /// Borrow the inner [`AffinePoint`].
+    pub fn as_affine(&self) -> &AffinePoint<C> {
+        self.public_key.as_affine()
+    }
+
+    /// Borrow the precomputed [`ProjectivePoint`].
+    pub fn as_projective(&self) -> &ProjectivePoint<C> {
+        &self.projective
+    }
+
+    /// Borrow the precomputed SEC1 [`EncodedPoint`], either compressed or
+    /// uncompressed.
+    pub fn as_encoded_point(&self, compress: bool) -> &EncodedPoint<C> {
+        if compress {
+            &self.compressed
+        } else {
+            &self.uncompressed
+        }
+    }
+}
+
+impl<C> AsRef<AffinePoint<C>> for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn as_ref(&self) -> &AffinePoint<C> {
+        self.as_affine()
+    }
+}
+
+impl<C> From<PublicKey<C>> for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from(public_key: PublicKey<C>) -> Self {
+        Self::new(public_key)
+    }
+}
+
+impl<C> From<CachedPublicKey<C>> for PublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from(cached: CachedPublicKey<C>) -> PublicKey<C> {
+        cached.public_key
+    }
+}
+
+impl<C> From<&CachedPublicKey<C>> for EncodedPoint<C>
+where
+    C: Curve + ProjectiveArithmetic + point::Compression,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from(cached: &CachedPublicKey<C>) -> EncodedPoint<C> {
+        cached.as_encoded_point(C::COMPRESS_POINTS).clone()
+    }
+}
+
+impl<C> FromEncodedPoint<C> for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy
+        + Clone
+        + ConditionallySelectable
+        + Debug
+        + Default
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Initialize [`CachedPublicKey`] from an [`EncodedPoint`]
+    fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> CtOption<Self> {
+        let public_key = PublicKey::<C>::from_encoded_point(encoded_point);
+        let is_some = public_key.is_some();
+        let public_key = public_key.unwrap_or(PublicKey {
+            point: AffinePoint::<C>::default(),
+        });
+        CtOption::new(Self::new(public_key), is_some)
+    }
+}
+
+impl<C> ToEncodedPoint<C> for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Return the precomputed SEC1 [`EncodedPoint`]
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint<C> {
+        self.as_encoded_point(compress).clone()
+    }
+}
+
+impl<C> Eq for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+}
+
+impl<C> PartialEq for CachedPublicKey<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy + Clone + Debug + Default + FromEncodedPoint<C> + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.uncompressed == other.uncompressed
+    }
+}
+
 #[cfg(all(feature = "dev", test))]
 mod tests {
-    use crate::{dev::MockCurve, sec1::FromEncodedPoint};
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, Scalar},
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    };
 
+    type CachedPublicKey = super::CachedPublicKey<MockCurve>;
     type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
     type PublicKey = super::PublicKey<MockCurve>;
 
+    fn public_key(secret: u64) -> PublicKey {
+        let scalar = NonZeroScalar::new(Scalar::from(secret)).unwrap();
+        PublicKey::from_secret_scalar(&scalar)
+    }
+
     #[test]
     fn from_encoded_point_rejects_identity() {
         let identity = EncodedPoint::identity();
-        assert_eq!(PublicKey::from_encoded_point(&identity), None);
+        assert!(bool::from(
+            PublicKey::from_encoded_point(&identity).is_none()
+        ));
+    }
+
+    #[test]
+    fn cached_public_key_encodings() {
+        let public_key = public_key(42);
+        let cached = CachedPublicKey::from(public_key);
+
+        for &compress in &[false, true] {
+            assert_eq!(
+                cached.as_encoded_point(compress),
+                &public_key.to_encoded_point(compress)
+            );
+            assert_eq!(
+                cached.to_encoded_point(compress),
+                public_key.to_encoded_point(compress)
+            );
+        }
+        assert_eq!(EncodedPoint::from(&cached), EncodedPoint::from(&public_key));
+        assert_eq!(cached.as_projective(), &public_key.to_projective());
+        assert_eq!(cached.public_key(), &public_key);
+        assert_eq!(PublicKey::from(cached), public_key);
+    }
+
+    #[test]
+    fn cached_public_key_from_encoded_point() {
+        let public_key = public_key(42);
+        let cached = CachedPublicKey::new(public_key);
+
+        for &compress in &[false, true] {
+            let encoded = public_key.to_encoded_point(compress);
+            let decoded = CachedPublicKey::from_encoded_point(&encoded).unwrap();
+            assert_eq!(decoded, cached);
+            assert_eq!(PublicKey::from(decoded), public_key);
+        }
+
+        let identity = EncodedPoint::identity();
+        assert!(bool::from(
+            CachedPublicKey::from_encoded_point(&identity).is_none()
+        ));
+    }
+
+    #[test]
+    fn cached_public_key_eq() {
+        let cached = CachedPublicKey::new(public_key(42));
+        assert_eq!(cached, cached.clone());
+        assert_ne!(cached, CachedPublicKey::new(public_key(43)));
+
+        // keys decoded from different encodings of the same point are equal
+        let compressed = public_key(42).to_encoded_point(true);
+        let decoded = CachedPublicKey::from_encoded_point(&compressed).unwrap();
+        assert_eq!(decoded, cached);
     }
 }
@@ -0,0 +1,181 @@
+//! Rerandomization of keys by a scalar tweak, as used for key blinding.
+//!
+//! Privacy protocols such as BIP-32 public derivation or signature key
+//! blinding derive unlinkable keys from a long-term keypair: the secret key
+//! holder and parties knowing only the public key apply the same tweak to
+//! their respective keys, and end up with a matching keypair.
+//!
+//! How the tweak is applied is determined by its type:
+//!
+//! - [`Additive`]: a secret key `x` is rerandomized to `x + t`, and the
+//!   corresponding public key `x·G` to `x·G + t·G`, as in BIP-32.
+//! - [`Multiplicative`]: a secret key `x` is rerandomized to `x·t`, and the
+//!   corresponding public key `x·G` to `t·(x·G)`, as in most signature key
+//!   blinding schemes.
+//!
+//! Other operations can be supported by implementing [`Tweak`].
+
+use crate::{Curve, FieldBytes, ProjectiveArithmetic, ProjectivePoint, Scalar};
+use ff::PrimeField;
+use group::Group;
+use subtle::CtOption;
+
+/// Operation rerandomizing the scalar and point of a keypair.
+///
+/// Implementations must be homomorphic with respect to scalar
+/// multiplication of the generator, i.e. for any scalar `x`:
+///
+/// ```text
+/// G * tweak.tweak_scalar(x) == tweak.tweak_point(G * x)
+/// ```
+pub trait Tweak<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Apply this tweak to a secret scalar.
+    fn tweak_scalar(&self, scalar: &Scalar<C>) -> Scalar<C>;
+
+    /// Apply this tweak to a public point.
+    fn tweak_point(&self, point: &ProjectivePoint<C>) -> ProjectivePoint<C>;
+}
+
+/// Tweak which is added to the secret scalar.
+#[derive(Clone, Debug)]
+pub struct Additive<C>(pub Scalar<C>)
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>;
+
+impl<C> Tweak<C> for Additive<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn tweak_scalar(&self, scalar: &Scalar<C>) -> Scalar<C> {
+        *scalar + self.0
+    }
+
+    fn tweak_point(&self, point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        *point + C::ProjectivePoint::generator() * self.0
+    }
+}
+
+/// Tweak which the secret scalar is multiplied by.
+#[derive(Clone, Debug)]
+pub struct Multiplicative<C>(pub Scalar<C>)
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>;
+
+impl<C> Tweak<C> for Multiplicative<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn tweak_scalar(&self, scalar: &Scalar<C>) -> Scalar<C> {
+        *scalar * self.0
+    }
+
+    fn tweak_point(&self, point: &ProjectivePoint<C>) -> ProjectivePoint<C> {
+        *point * self.0
+    }
+}
+
+/// Key which can be rerandomized by a tweak of type `T`, e.g. [`Additive`]
+/// or [`Multiplicative`].
+///
+/// Rerandomizing a secret key must yield the secret key of the rerandomized
+/// public key, see [`RerandomizableKeypair`].
+pub trait Rerandomize<C, T = Additive<C>>: Sized
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Rerandomize this key by the given tweak.
+    ///
+    /// Returns none if the result isn't a valid key, e.g. if an additive
+    /// tweak is the negation of the secret scalar, or a multiplicative tweak
+    /// is zero. The check runs in constant time.
+    fn rerandomize(&self, tweak: &T) -> CtOption<Self>;
+}
+
+/// Secret key whose rerandomization is compatible with the rerandomization
+/// of its public key.
+///
+/// For any tweak `t`, implementations must satisfy:
+///
+/// ```text
+/// sk.rerandomize(t).public_key() == sk.public_key().rerandomize(t)
+/// ```
+///
+/// This allows protocols to be written generically over the curve, and
+/// parties holding only the public key to derive the public half of any
+/// rerandomized keypair.
+pub trait RerandomizableKeypair<C, T = Additive<C>>: Rerandomize<C, T>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Public key type.
+    type PublicKey: Rerandomize<C, T>;
+
+    /// Get the public key which corresponds to this secret key.
+    fn public_key(&self) -> Self::PublicKey;
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{Additive, Multiplicative, RerandomizableKeypair, Rerandomize, Tweak};
+    use crate::{
+        dev::{MockCurve, PublicKey, Scalar, SecretKey},
+        ff::{Field, PrimeField},
+    };
+    use hex_literal::hex;
+
+    const SECRET_KEY: [u8; 32] =
+        hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+    /// Check both halves of the keypair agree after rerandomization by `tweak`.
+    fn check<T: Tweak<MockCurve>>(tweak: T) -> bool {
+        let public_key = <SecretKey as RerandomizableKeypair<MockCurve, T>>::public_key;
+
+        let secret_key = SecretKey::from_bytes(&SECRET_KEY).unwrap();
+        let secret = Option::<SecretKey>::from(secret_key.rerandomize(&tweak));
+        let public = Option::<PublicKey>::from(public_key(&secret_key).rerandomize(&tweak));
+
+        match (secret, public) {
+            (Some(secret), Some(public)) => {
+                assert_eq!(public_key(&secret), public);
+                true
+            }
+            (None, None) => false,
+            _ => panic!("keypair halves disagree on validity"),
+        }
+    }
+
+    #[test]
+    fn additive() {
+        assert!(check(Additive(Scalar::from(1))));
+        assert!(check(Additive(Scalar::from(0x1234_5678))));
+        assert!(check(Additive(-Scalar::one())));
+
+        let negated = -Scalar::from_repr(SECRET_KEY.into()).unwrap();
+        assert!(!check(Additive(negated)));
+    }
+
+    #[test]
+    fn multiplicative() {
+        assert!(check(Multiplicative(Scalar::one())));
+        assert!(check(Multiplicative(Scalar::from(0x1234_5678))));
+        assert!(check(Multiplicative(-Scalar::from(2))));
+        assert!(!check(Multiplicative(Scalar::zero())));
+    }
+}
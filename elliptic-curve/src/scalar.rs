@@ -6,14 +6,23 @@ use crate::{
     Curve, Error, FieldBytes, ProjectiveArithmetic,
 };
 use bitvec::{array::BitArray, order::Lsb0};
-use core::{convert::TryFrom, ops::Deref};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, LowerHex, UpperHex},
+    ops::Deref,
+};
 use ff::{Field, PrimeField};
 use generic_array::{typenum::Unsigned, GenericArray};
 use group::Group;
-use subtle::{Choice, ConditionallySelectable, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
+#[cfg(feature = "serde")]
+use {
+    core::marker::PhantomData,
+    serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+};
 
 /// Scalar field element for a particular elliptic curve.
 pub type Scalar<C> = <<C as ProjectiveArithmetic>::ProjectivePoint as Group>::Scalar;
@@ -29,6 +38,13 @@ pub type ScalarBits<C> = BitArray<Lsb0, <Scalar<C> as PrimeField>::ReprBits>;
 ///
 /// In the context of ECC, it's useful for ensuring that scalar multiplication
 /// cannot result in the point at infinity.
+///
+/// This type doesn't impl [`Display`], hex formatting or `serde` traits, since
+/// it's used for both secret and non-secret values. Non-secret scalars (e.g.
+/// signature components) can be wrapped in [`PublicScalar`] to get these
+/// impls, and secret scalars should be wrapped in `SecretScalar` (with the
+/// `zeroize` feature), which redacts its [`Debug`][fmt::Debug] output and is
+/// zeroized on drop.
 #[derive(Clone)]
 pub struct NonZeroScalar<C>
 where
@@ -49,24 +65,47 @@ where
     pub fn random(mut rng: impl CryptoRng + RngCore) -> Self {
         // Use rejection sampling to eliminate zero values
         loop {
-            if let Some(result) = Self::new(Field::random(&mut rng)) {
-                break result;
+            let result = Self::new(Field::random(&mut rng));
+
+            if result.is_some().into() {
+                break result.unwrap();
             }
         }
     }
 
-    /// Decode a [`NonZeroScalar] from a serialized field element
-    pub fn from_repr(repr: FieldBytes<C>) -> Option<Self> {
-        Scalar::<C>::from_repr(repr).and_then(Self::new)
+    /// Decode a [`NonZeroScalar`] from a serialized field element.
+    ///
+    /// Returns a [`CtOption`] which is none if the serialized value is zero
+    /// or not a canonical encoding of a field element. Both checks are always
+    /// performed, so the time taken does not depend on which of them fails
+    /// beyond the time taken by the curve's [`PrimeField::from_repr`].
+    pub fn from_repr(repr: FieldBytes<C>) -> CtOption<Self> {
+        let scalar = Scalar::<C>::from_repr(repr);
+        let is_canonical = Choice::from(scalar.is_some() as u8);
+        let scalar = scalar.unwrap_or_default();
+        let is_nonzero = Self::new(scalar).is_some();
+        CtOption::new(Self { scalar }, is_canonical & is_nonzero)
     }
 
     /// Create a [`NonZeroScalar`] from a scalar.
-    // TODO(tarcieri): make this constant time?
-    pub fn new(scalar: Scalar<C>) -> Option<Self> {
-        if scalar.is_zero() {
-            None
-        } else {
-            Some(Self { scalar })
+    ///
+    /// Returns a [`CtOption`] which is none if the scalar is zero. The check
+    /// is performed in constant time.
+    pub fn new(scalar: Scalar<C>) -> CtOption<Self> {
+        let is_zero = scalar
+            .to_repr()
+            .as_slice()
+            .ct_eq(Scalar::<C>::zero().to_repr().as_slice());
+
+        CtOption::new(Self { scalar }, !is_zero)
+    }
+
+    /// Placeholder value for [`CtOption`]s which are none.
+    ///
+    /// This is zero and must never be exposed as a [`NonZeroScalar`].
+    fn placeholder() -> Self {
+        Self {
+            scalar: Scalar::<C>::default(),
         }
     }
 }
@@ -151,7 +190,10 @@ where
 
     fn try_from(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() == C::FieldSize::to_usize() {
-            NonZeroScalar::from_repr(GenericArray::clone_from_slice(bytes)).ok_or(Error)
+            Option::from(NonZeroScalar::from_repr(GenericArray::clone_from_slice(
+                bytes,
+            )))
+            .ok_or(Error)
         } else {
             Err(Error)
         }
@@ -169,3 +211,634 @@ where
         self.scalar.zeroize();
     }
 }
+
+/// Non-zero scalar which is explicitly non-secret, e.g. a signature
+/// component or a public tweak.
+///
+/// Unlike [`NonZeroScalar`] it impls [`Display`] and hex formatting (as a big
+/// endian hex string), as well as `serde` traits (as big endian bytes) when
+/// the `serde` feature is enabled. Never use it for secret values.
+#[derive(Clone, Copy)]
+pub struct PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    scalar: NonZeroScalar<C>,
+}
+
+impl<C> PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    /// Mark the given scalar as non-secret.
+    pub fn new(scalar: NonZeroScalar<C>) -> Self {
+        Self { scalar }
+    }
+
+    /// Decode a [`PublicScalar`] from a serialized field element.
+    ///
+    /// Returns a [`CtOption`] which is none if the serialized value is zero
+    /// or not a canonical encoding of a field element.
+    pub fn from_repr(repr: FieldBytes<C>) -> CtOption<Self> {
+        let scalar = NonZeroScalar::from_repr(repr);
+        let is_some = scalar.is_some();
+        CtOption::new(
+            Self::new(scalar.unwrap_or(NonZeroScalar::placeholder())),
+            is_some,
+        )
+    }
+
+    /// Convert into the inner [`NonZeroScalar`].
+    pub fn into_inner(self) -> NonZeroScalar<C> {
+        self.scalar
+    }
+}
+
+impl<C> AsRef<NonZeroScalar<C>> for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn as_ref(&self) -> &NonZeroScalar<C> {
+        &self.scalar
+    }
+}
+
+impl<C> From<NonZeroScalar<C>> for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn from(scalar: NonZeroScalar<C>) -> Self {
+        Self::new(scalar)
+    }
+}
+
+impl<C> From<PublicScalar<C>> for FieldBytes<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn from(scalar: PublicScalar<C>) -> FieldBytes<C> {
+        scalar.scalar.into()
+    }
+}
+
+impl<C> TryFrom<&[u8]> for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        NonZeroScalar::try_from(bytes).map(Self::new)
+    }
+}
+
+/// Formats the scalar as a big endian hex string.
+impl<C> fmt::Debug for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PublicScalar<{:?}>({:x})", C::default(), self)
+    }
+}
+
+impl<C> LowerHex for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.scalar.scalar.to_repr().iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C> UpperHex for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.scalar.scalar.to_repr().iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the scalar as a big endian hex string.
+impl<C> Display for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+/// Serializes the scalar as big endian bytes.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<C> Serialize for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.scalar.scalar.to_repr())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, C> Deserialize<'de> for PublicScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(PublicScalarVisitor(PhantomData))
+    }
+}
+
+/// Deserialize [`PublicScalar`] from either bytes or a sequence of bytes,
+/// which is how formats without native byte strings (e.g. JSON) encode them.
+#[cfg(feature = "serde")]
+struct PublicScalarVisitor<C>(PhantomData<C>);
+
+#[cfg(feature = "serde")]
+impl<'de, C> de::Visitor<'de> for PublicScalarVisitor<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+{
+    type Value = PublicScalar<C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes of a non-zero scalar", C::FieldSize::to_usize())
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<PublicScalar<C>, E> {
+        PublicScalar::try_from(bytes)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(bytes), &self))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<PublicScalar<C>, A::Error> {
+        let mut repr = FieldBytes::<C>::default();
+
+        for (i, byte) in repr.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(repr.len() + 1, &self));
+        }
+
+        Option::from(PublicScalar::from_repr(repr))
+            .ok_or_else(|| de::Error::custom("invalid or zero scalar"))
+    }
+}
+
+/// Secret scalar value, e.g. a private key or an ephemeral nonce.
+///
+/// Wrapper for [`NonZeroScalar`] which makes accidental exposure of the
+/// secret harder: its [`Debug`] output is redacted, and it impls neither
+/// [`Display`] nor `serde` traits. The value can only be accessed explicitly
+/// via [`SecretScalar::expose_secret`]. It is zeroized when dropped.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+#[derive(Clone)]
+pub struct SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    scalar: NonZeroScalar<C>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    /// Wrap the given secret scalar.
+    pub fn new(scalar: NonZeroScalar<C>) -> Self {
+        Self { scalar }
+    }
+
+    /// Generate a random [`SecretScalar`].
+    pub fn random(rng: impl CryptoRng + RngCore) -> Self {
+        Self::new(NonZeroScalar::random(rng))
+    }
+
+    /// Decode a [`SecretScalar`] from a serialized field element.
+    ///
+    /// Returns a [`CtOption`] which is none if the serialized value is zero
+    /// or not a canonical encoding of a field element.
+    pub fn from_repr(repr: FieldBytes<C>) -> CtOption<Self> {
+        let scalar = NonZeroScalar::from_repr(repr);
+        let is_some = scalar.is_some();
+        CtOption::new(
+            Self::new(scalar.unwrap_or(NonZeroScalar::placeholder())),
+            is_some,
+        )
+    }
+
+    /// Borrow the secret scalar value.
+    ///
+    /// # Warning
+    ///
+    /// This value is key material. Don't log or serialize it.
+    pub fn expose_secret(&self) -> &NonZeroScalar<C> {
+        &self.scalar
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> From<NonZeroScalar<C>> for SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn from(scalar: NonZeroScalar<C>) -> Self {
+        Self::new(scalar)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> From<SecretScalar<C>> for FieldBytes<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn from(scalar: SecretScalar<C>) -> FieldBytes<C> {
+        scalar.expose_secret().to_repr()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> fmt::Debug for SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretScalar<{:?}>{{ ... }}", C::default())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> Zeroize for SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> Drop for SecretScalar<C>
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{PublicScalar, SecretScalar};
+    use crate::dev::{FieldBytes, MockCurve, NonZeroScalar, REPR_CONVERSIONS};
+    use alloc::{format, string::ToString};
+    use core::fmt::Display;
+    use hex_literal::hex;
+
+    /// Does `$ty` impl `$trait`? Evaluated via autoref-based method
+    /// resolution, so it doesn't fail to compile when the impl is missing.
+    macro_rules! impls {
+        ($ty:ty: $trait:path) => {{
+            struct Probe<T>(core::marker::PhantomData<T>);
+
+            #[allow(dead_code)]
+            trait Missing {
+                fn impls(&self) -> bool {
+                    false
+                }
+            }
+
+            impl<T> Missing for &Probe<T> {}
+
+            #[allow(dead_code)]
+            trait Present {
+                fn impls(&self) -> bool {
+                    true
+                }
+            }
+
+            impl<T: $trait> Present for Probe<T> {}
+
+            (&Probe::<$ty>(core::marker::PhantomData)).impls()
+        }};
+    }
+
+    /// Implement [`serde::Serializer`] methods taking a single value by
+    /// rejecting the value.
+    #[cfg(feature = "serde")]
+    macro_rules! unsupported {
+        ($($method:ident($ty:ty)),+) => {
+            $(
+                fn $method(self, _: $ty) -> Result<Self::Ok, Self::Error> {
+                    Err(serde::ser::Error::custom("expected bytes"))
+                }
+            )+
+        };
+    }
+
+    /// Scalar modulus of the mock curve (NIST P-256)
+    const MODULUS: [u8; 32] =
+        hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+    #[test]
+    fn from_repr_rejects_zero() {
+        let repr = FieldBytes::default();
+        assert!(bool::from(NonZeroScalar::from_repr(repr).is_none()));
+    }
+
+    #[test]
+    fn from_repr_rejects_out_of_range() {
+        let repr = FieldBytes::clone_from_slice(&MODULUS);
+        assert!(bool::from(NonZeroScalar::from_repr(repr).is_none()));
+    }
+
+    #[test]
+    fn from_repr_accepts_nonzero() {
+        let mut repr = FieldBytes::default();
+        repr[31] = 1;
+        let scalar = NonZeroScalar::from_repr(repr).unwrap();
+        assert_eq!(FieldBytes::from(scalar), repr);
+    }
+
+    /// Number of mock scalar conversions performed by `NonZeroScalar::from_repr`
+    fn from_repr_conversions(repr: FieldBytes) -> usize {
+        REPR_CONVERSIONS.with(|n| n.set(0));
+        let _ = NonZeroScalar::from_repr(repr);
+        REPR_CONVERSIONS.with(|n| n.get())
+    }
+
+    /// Deterministic counterpart to the timing test in `dev::timing`: every
+    /// check is evaluated regardless of which one fails. This can't detect
+    /// data-dependent timing within the checks themselves.
+    #[test]
+    fn from_repr_does_not_return_early() {
+        let mut one = FieldBytes::default();
+        one[31] = 1;
+        let expected = from_repr_conversions(one);
+
+        assert_eq!(from_repr_conversions(FieldBytes::default()), expected);
+        assert_eq!(
+            from_repr_conversions(FieldBytes::clone_from_slice(&MODULUS)),
+            expected
+        );
+    }
+
+    #[test]
+    fn public_scalar_display() {
+        let mut repr = FieldBytes::default();
+        repr[31] = 0xab;
+        let scalar = PublicScalar::<MockCurve>::from_repr(repr).unwrap();
+        let hex = format!("{:062}ab", 0);
+        assert_eq!(scalar.to_string(), hex);
+        assert_eq!(format!("{:X}", scalar), hex.to_uppercase());
+    }
+
+    #[test]
+    fn secret_scalar_debug_is_redacted() {
+        let mut repr = FieldBytes::default();
+        repr[31] = 0xab;
+        let scalar = SecretScalar::<MockCurve>::from_repr(repr).unwrap();
+        assert_eq!(format!("{:?}", scalar), "SecretScalar<MockCurve>{ ... }");
+    }
+
+    #[test]
+    fn secret_scalar_from_repr_rejects_zero() {
+        let repr = FieldBytes::default();
+        assert!(bool::from(
+            SecretScalar::<MockCurve>::from_repr(repr).is_none()
+        ));
+    }
+
+    #[test]
+    fn only_public_scalars_impl_display() {
+        assert!(impls!(PublicScalar<MockCurve>: Display));
+        assert!(!impls!(NonZeroScalar: Display));
+        assert!(!impls!(SecretScalar<MockCurve>: Display));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn only_public_scalars_impl_serde() {
+        assert!(impls!(PublicScalar<MockCurve>: serde::Serialize));
+        assert!(!impls!(NonZeroScalar: serde::Serialize));
+        assert!(!impls!(SecretScalar<MockCurve>: serde::Serialize));
+    }
+
+    /// Serializer which only accepts byte strings, returning their contents.
+    #[cfg(feature = "serde")]
+    struct BytesSerializer;
+
+    #[cfg(feature = "serde")]
+    impl serde::Serializer for BytesSerializer {
+        type Ok = alloc::vec::Vec<u8>;
+        type Error = serde::de::value::Error;
+        type SerializeSeq = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeTuple = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleStruct = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleVariant = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeMap = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeStruct = serde::ser::Impossible<Self::Ok, Self::Error>;
+        type SerializeStructVariant = serde::ser::Impossible<Self::Ok, Self::Error>;
+
+        fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+            Ok(v.to_vec())
+        }
+
+        fn collect_str<T: Display + ?Sized>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        unsupported! {
+            serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32),
+            serialize_i64(i64), serialize_u8(u8), serialize_u16(u16), serialize_u32(u32),
+            serialize_u64(u64), serialize_f32(f32), serialize_f64(f64), serialize_char(char),
+            serialize_str(&str), serialize_unit_struct(&'static str)
+        }
+
+        fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_some<T: serde::Serialize + ?Sized>(
+            self,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_unit_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_newtype_struct<T: serde::Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_newtype_variant<T: serde::Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(serde::ser::Error::custom("expected bytes"))
+        }
+    }
+
+    /// Deserialize a [`PublicScalar`] through the `visit_bytes` path.
+    #[cfg(feature = "serde")]
+    fn from_bytes(bytes: &[u8]) -> Result<PublicScalar<MockCurve>, serde::de::value::Error> {
+        serde::Deserialize::deserialize(serde::de::value::BytesDeserializer::new(bytes))
+    }
+
+    /// Deserialize a [`PublicScalar`] through the `visit_seq` path, as used
+    /// by formats without byte strings such as JSON.
+    #[cfg(feature = "serde")]
+    fn from_seq(bytes: &[u8]) -> Result<PublicScalar<MockCurve>, serde::de::value::Error> {
+        serde::Deserialize::deserialize(serde::de::value::SeqDeserializer::new(
+            bytes.iter().copied(),
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn public_scalar_serde_round_trip() {
+        let mut repr = FieldBytes::default();
+        repr[0] = 0x12;
+        repr[31] = 0xab;
+        let scalar = PublicScalar::<MockCurve>::from_repr(repr).unwrap();
+
+        let bytes = serde::Serialize::serialize(&scalar, BytesSerializer).unwrap();
+        assert_eq!(bytes, repr.as_slice());
+        assert_eq!(FieldBytes::from(from_bytes(&bytes).unwrap()), repr);
+        assert_eq!(FieldBytes::from(from_seq(&bytes).unwrap()), repr);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn public_scalar_serde_rejects_invalid() {
+        // out of range, zero, too short and too long
+        let mut too_long = [1u8; 33];
+        too_long[0] = 0;
+        for bytes in &[&MODULUS[..], &[0; 32], &[1; 31], &too_long] {
+            assert!(from_bytes(bytes).is_err());
+            assert!(from_seq(bytes).is_err());
+        }
+    }
+}
@@ -14,15 +14,27 @@ use generic_array::{
     typenum::{Unsigned, U1},
     ArrayLength, GenericArray,
 };
-use subtle::{Choice, ConditionallySelectable};
+use subtle::{Choice, ConditionallySelectable, CtOption};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "pkcs8")]
+use {
+    crate::{AlgorithmParameters, ALGORITHM_OID},
+    pkcs8::FromPublicKey,
+};
+
+#[cfg(feature = "pem")]
+use {alloc::vec::Vec, pkcs8::ToPublicKey};
+
 #[cfg(feature = "arithmetic")]
 use crate::{
-    ff::PrimeField, weierstrass::point::Decompress, AffinePoint, ProjectiveArithmetic, Scalar,
+    ff::PrimeField, public_key::PublicKey, weierstrass::point::Decompress, AffinePoint,
+    ProjectiveArithmetic, ProjectivePoint, Scalar,
 };
+#[cfg(feature = "arithmetic")]
+use core::convert::TryFrom;
 
 #[cfg(all(feature = "arithmetic", feature = "zeroize"))]
 use crate::{
@@ -136,7 +148,7 @@ where
         AffinePoint<C>: ToEncodedPoint<C>,
         Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
     {
-        (C::ProjectivePoint::generator() * secret_key.secret_scalar().as_ref())
+        (C::ProjectivePoint::generator() * secret_key.secret_scalar().expose_secret().as_ref())
             .to_affine()
             .to_encoded_point(compress)
     }
@@ -166,20 +178,37 @@ where
 
     /// Serialize point as raw uncompressed coordinates without tag byte, i.e.
     /// encoded as the concatenated `x || y` coordinates.
+    ///
+    /// Returns a [`CtOption`] which is none if this point is the identity or
+    /// fails to decompress. See [`EncodedPoint::decompress`].
     #[cfg(feature = "arithmetic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
-    pub fn to_untagged_bytes(&self) -> Option<GenericArray<u8, UntaggedPointSize<C>>>
+    pub fn to_untagged_bytes(&self) -> CtOption<GenericArray<u8, UntaggedPointSize<C>>>
     where
         C: Curve + ProjectiveArithmetic,
         FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
         Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
         AffinePoint<C>: ConditionallySelectable + Default + Decompress<C> + ToEncodedPoint<C>,
     {
-        self.decompress().map(|point| {
-            let mut bytes = GenericArray::<u8, UntaggedPointSize<C>>::default();
-            bytes.copy_from_slice(&point.as_bytes()[1..]);
-            bytes
-        })
+        // Decompress via `AffinePoint` rather than `decompress`, since
+        // unwrapping a `CtOption<EncodedPoint>` in constant time would need
+        // `EncodedPoint: Copy`
+        let (point, is_some) = match self.coordinates() {
+            Coordinates::Identity => (self.clone(), Choice::from(0)),
+            Coordinates::Compressed { x, y_is_odd } => {
+                let point = AffinePoint::<C>::decompress(x, Choice::from(y_is_odd as u8));
+                let is_some = point.is_some();
+                let point = point.unwrap_or(AffinePoint::<C>::default());
+                (point.to_encoded_point(false), is_some)
+            }
+            Coordinates::Uncompressed { .. } => (self.clone(), Choice::from(1)),
+        };
+
+        // Copy from the full-size buffer so the identity placeholder doesn't
+        // change the amount of work performed
+        let mut bytes = GenericArray::<u8, UntaggedPointSize<C>>::default();
+        bytes.copy_from_slice(&point.bytes[1..]);
+        CtOption::new(bytes, is_some)
     }
 
     /// Is this [`EncodedPoint`] the additive identity? (a.k.a. point at infinity)
@@ -201,9 +230,14 @@ where
     }
 
     /// Decompress this [`EncodedPoint`], returning a new [`EncodedPoint`].
+    ///
+    /// Returns a [`CtOption`] which is none if this point is the identity or
+    /// its x-coordinate is not on the curve. Decompression is delegated to
+    /// [`Decompress`], whose implementations are expected to run in constant
+    /// time with respect to the coordinates; only the SEC1 tag is branched on.
     #[cfg(feature = "arithmetic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
-    pub fn decompress(&self) -> Option<Self>
+    pub fn decompress(&self) -> CtOption<Self>
     where
         C: Curve + ProjectiveArithmetic,
         FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
@@ -211,13 +245,12 @@ where
         AffinePoint<C>: ConditionallySelectable + Default + Decompress<C> + ToEncodedPoint<C>,
     {
         match self.coordinates() {
-            Coordinates::Identity => None,
+            Coordinates::Identity => CtOption::new(self.clone(), Choice::from(0)),
             Coordinates::Compressed { x, y_is_odd } => {
                 AffinePoint::<C>::decompress(x, Choice::from(y_is_odd as u8))
                     .map(|s| s.to_encoded_point(false))
-                    .into()
             }
-            Coordinates::Uncompressed { .. } => Some(self.clone()),
+            Coordinates::Uncompressed { .. } => CtOption::new(self.clone(), Choice::from(1)),
         }
     }
 
@@ -234,7 +267,7 @@ where
     where
         T: FromEncodedPoint<C>,
     {
-        T::from_encoded_point(self).ok_or(Error)
+        Option::from(T::from_encoded_point(self)).ok_or(Error)
     }
 
     /// Get the SEC1 tag for this [`EncodedPoint`]
@@ -475,8 +508,15 @@ where
     ///
     /// # Returns
     ///
-    /// `None` if the [`EncodedPoint`] is invalid.
-    fn from_encoded_point(public_key: &EncodedPoint<C>) -> Option<Self>;
+    /// A [`CtOption`] which is none if the [`EncodedPoint`] is invalid.
+    ///
+    /// # Constant-time contract
+    ///
+    /// Implementations must not branch on, or return early based on, the
+    /// coordinates of the point: validity is accumulated as a [`Choice`] and
+    /// reported through the returned [`CtOption`]. Only the SEC1 tag, which
+    /// determines the length of the encoding, may be branched on.
+    fn from_encoded_point(point: &EncodedPoint<C>) -> CtOption<Self>;
 }
 
 /// Trait for serializing a value to a SEC1 encoded curve point.
@@ -493,6 +533,101 @@ where
     fn to_encoded_point(&self, compress: bool) -> EncodedPoint<C>;
 }
 
+/// Validate that a SEC1 encoded point is a valid public key for a curve.
+///
+/// This is a byte-level hook which allows curve crates to validate public
+/// keys without enabling the `arithmetic` feature, e.g. by delegating the
+/// check to a hardware security module. It's used when parsing keys, e.g.
+/// by the PKCS#8 support for `SecretKey` and [`EncodedPoint`].
+///
+/// When the `arithmetic` feature is enabled, this trait is impl'd for all
+/// curves, checking that the point is on the curve and isn't the identity.
+pub trait ValidatePublicKey: Curve
+where
+    UntaggedPointSize<Self>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<Self>: ArrayLength<u8>,
+{
+    /// Validate the given public key.
+    ///
+    /// The default implementation only rejects the identity, since
+    /// [`EncodedPoint`] already ensures the encoding is well-formed.
+    fn validate_public_key(public_key: &EncodedPoint<Self>) -> Result<(), Error> {
+        if public_key.is_identity() {
+            Err(Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> ValidatePublicKey for C
+where
+    C: Curve + ProjectiveArithmetic,
+    FieldBytes<C>: From<Scalar<C>> + for<'r> From<&'r Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>>,
+    AffinePoint<C>: Copy
+        + Clone
+        + ConditionallySelectable
+        + Debug
+        + Default
+        + FromEncodedPoint<C>
+        + ToEncodedPoint<C>,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn validate_public_key(public_key: &EncodedPoint<C>) -> Result<(), Error> {
+        PublicKey::<C>::try_from(public_key).map(|_| ())
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<C> FromPublicKey for EncodedPoint<C>
+where
+    C: Curve + AlgorithmParameters + ValidatePublicKey,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn from_spki(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> pkcs8::Result<Self> {
+        if spki.algorithm.oid != ALGORITHM_OID || spki.algorithm.parameters_oid() != Some(C::OID) {
+            return Err(pkcs8::Error::Decode);
+        }
+
+        // Look for a leading `0x00` byte in the bitstring
+        if spki.subject_public_key.first().cloned() != Some(0x00) {
+            return Err(pkcs8::Error::Decode);
+        }
+
+        let point =
+            Self::from_bytes(&spki.subject_public_key[1..]).map_err(|_| pkcs8::Error::Decode)?;
+        C::validate_public_key(&point).map_err(|_| pkcs8::Error::Decode)?;
+        Ok(point)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<C> ToPublicKey for EncodedPoint<C>
+where
+    C: Curve + AlgorithmParameters,
+    UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    fn to_public_key_der(&self) -> pkcs8::PublicKeyDocument {
+        let mut public_key_bytes = Vec::with_capacity(1 + self.len());
+        public_key_bytes.push(0);
+        public_key_bytes.extend_from_slice(self.as_bytes());
+
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm: C::algorithm_identifier(),
+            subject_public_key: &public_key_bytes,
+        }
+        .to_der()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Coordinates, Tag};
@@ -12,10 +12,12 @@ mod pkcs8;
 
 use crate::{error::Error, Curve, FieldBytes};
 use core::{
-    convert::{TryFrom, TryInto},
+    convert::TryFrom,
     fmt::{self, Debug},
     ops::Deref,
 };
+use generic_array::typenum::Unsigned;
+use subtle::CtOption;
 use zeroize::Zeroize;
 
 #[cfg(feature = "arithmetic")]
@@ -23,9 +25,12 @@ use crate::{
     ff::PrimeField,
     public_key::PublicKey,
     rand_core::{CryptoRng, RngCore},
-    scalar::{NonZeroScalar, Scalar},
+    rerandomize::{RerandomizableKeypair, Rerandomize, Tweak},
+    scalar::{NonZeroScalar, Scalar, SecretScalar},
     weierstrass, AffinePoint, ProjectiveArithmetic, ProjectivePoint,
 };
+#[cfg(feature = "arithmetic")]
+use zeroize::Zeroizing;
 
 #[cfg(all(docsrs, feature = "pkcs8"))]
 use {crate::pkcs8::FromPrivateKey, core::str::FromStr};
@@ -73,12 +78,12 @@ where
     #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
     pub fn random(rng: impl CryptoRng + RngCore) -> Self
     where
-        C: ProjectiveArithmetic + SecretValue<Secret = NonZeroScalar<C>>,
+        C: ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
         FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
         Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
     {
         Self {
-            secret_value: NonZeroScalar::<C>::random(rng),
+            secret_value: SecretScalar::random(rng),
         }
     }
 
@@ -89,11 +94,14 @@ where
 
     /// Deserialize raw private scalar as a big endian integer
     pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, Error> {
-        bytes
-            .as_ref()
-            .try_into()
-            .ok()
-            .and_then(C::from_secret_bytes)
+        let bytes = bytes.as_ref();
+
+        // `&FieldBytes<C>: From<&[u8]>` panics on a length mismatch
+        if bytes.len() != C::FieldSize::to_usize() {
+            return Err(Error);
+        }
+
+        Option::from(C::from_secret_bytes(FieldBytes::<C>::from_slice(bytes)))
             .map(|secret_value| SecretKey { secret_value })
             .ok_or(Error)
     }
@@ -103,7 +111,9 @@ where
         self.secret_value.clone().into()
     }
 
-    /// Borrow the inner secret [`Scalar`] value.
+    /// Borrow the inner [`SecretScalar`] value.
+    ///
+    /// The scalar itself can be accessed via [`SecretScalar::expose_secret`].
     ///
     /// # Warning
     ///
@@ -112,27 +122,78 @@ where
     /// Please treat it with the care it deserves!
     #[cfg(feature = "arithmetic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
-    pub fn secret_scalar(&self) -> &NonZeroScalar<C>
+    pub fn secret_scalar(&self) -> &SecretScalar<C>
     where
-        C: ProjectiveArithmetic + SecretValue<Secret = NonZeroScalar<C>>,
+        C: ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
         FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
         Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
     {
         &self.secret_value
     }
 
+    /// Copy the inner [`SecretScalar`] value.
+    #[cfg(feature = "arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+    pub fn to_secret_scalar(&self) -> SecretScalar<C>
+    where
+        C: ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
+        FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
+        Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+    {
+        self.secret_value.clone()
+    }
+
     /// Get the [`PublicKey`] which corresponds to this secret key
     #[cfg(feature = "arithmetic")]
     #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
     pub fn public_key(&self) -> PublicKey<C>
     where
-        C: weierstrass::Curve + ProjectiveArithmetic + SecretValue<Secret = NonZeroScalar<C>>,
+        C: weierstrass::Curve + ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
         FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
         Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
         AffinePoint<C>: Copy + Clone + Debug + Default,
         ProjectivePoint<C>: From<AffinePoint<C>>,
     {
-        PublicKey::from_secret_scalar(self.secret_scalar())
+        PublicKey::from_secret_scalar(self.secret_scalar().expose_secret())
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+impl<C, T> Rerandomize<C, T> for SecretKey<C>
+where
+    C: Curve + ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
+    FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+    T: Tweak<C>,
+{
+    fn rerandomize(&self, tweak: &T) -> CtOption<Self> {
+        let secret_scalar = self.secret_scalar().expose_secret();
+        let mut scalar = tweak.tweak_scalar(secret_scalar.as_ref());
+        let result = NonZeroScalar::new(scalar);
+        scalar.zeroize();
+
+        let is_some = result.is_some();
+        let tweaked = Zeroizing::new(result.unwrap_or(*secret_scalar));
+        CtOption::new(Self::new(SecretScalar::new(*tweaked)), is_some)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+impl<C, T> RerandomizableKeypair<C, T> for SecretKey<C>
+where
+    C: weierstrass::Curve + ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
+    FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+    AffinePoint<C>: Copy + Clone + Debug + Default,
+    ProjectivePoint<C>: From<AffinePoint<C>>,
+    T: Tweak<C>,
+{
+    type PublicKey = PublicKey<C>;
+
+    fn public_key(&self) -> PublicKey<C> {
+        SecretKey::public_key(self)
     }
 }
 
@@ -149,6 +210,19 @@ where
     }
 }
 
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+impl<C> From<SecretScalar<C>> for SecretKey<C>
+where
+    C: Curve + ProjectiveArithmetic + SecretValue<Secret = SecretScalar<C>>,
+    FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
+    Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
+{
+    fn from(secret_scalar: SecretScalar<C>) -> SecretKey<C> {
+        Self::new(secret_scalar)
+    }
+}
+
 impl<C> Debug for SecretKey<C>
 where
     C: Curve + SecretValue,
@@ -188,9 +262,12 @@ pub trait SecretValue: Curve {
     /// be aware you will experience breakages!
     type Secret: Into<FieldBytes<Self>> + Zeroize;
 
-    /// Parse the secret value from bytes
-    // TODO(tarcieri): make this constant time?
-    fn from_secret_bytes(bytes: &FieldBytes<Self>) -> Option<Self::Secret>;
+    /// Parse the secret value from bytes.
+    ///
+    /// Returns a [`CtOption`] which is none if the bytes are not a valid
+    /// secret value. Implementations must not return early based on the
+    /// contents of `bytes`.
+    fn from_secret_bytes(bytes: &FieldBytes<Self>) -> CtOption<Self::Secret>;
 }
 
 #[cfg(feature = "arithmetic")]
@@ -200,10 +277,10 @@ where
     FieldBytes<C>: From<Scalar<C>> + for<'a> From<&'a Scalar<C>>,
     Scalar<C>: PrimeField<Repr = FieldBytes<C>> + Zeroize,
 {
-    type Secret = NonZeroScalar<C>;
+    type Secret = SecretScalar<C>;
 
-    fn from_secret_bytes(repr: &FieldBytes<C>) -> Option<NonZeroScalar<C>> {
-        NonZeroScalar::from_repr(repr.clone())
+    fn from_secret_bytes(repr: &FieldBytes<C>) -> CtOption<SecretScalar<C>> {
+        SecretScalar::from_repr(repr.clone())
     }
 }
 
@@ -244,3 +321,18 @@ impl<C: Curve> Zeroize for SecretBytes<C> {
         self.0.as_mut().zeroize();
     }
 }
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::SecretKey;
+
+    #[test]
+    fn from_bytes_rejects_wrong_length() {
+        let mut bytes = [0u8; 33];
+        bytes[31] = 1;
+        assert!(SecretKey::from_bytes(&bytes[..32]).is_ok());
+        assert!(SecretKey::from_bytes(&bytes[..31]).is_err());
+        assert!(SecretKey::from_bytes(&bytes[..]).is_err());
+        assert!(SecretKey::from_bytes(&[]).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 use super::{SecretKey, SecretValue};
 use crate::{
-    sec1::{self, UncompressedPointSize, UntaggedPointSize},
+    sec1::{self, UncompressedPointSize, UntaggedPointSize, ValidatePublicKey},
     weierstrass, AlgorithmParameters, FieldBytes, ALGORITHM_OID,
 };
 use core::ops::Add;
@@ -43,7 +43,7 @@ const ENCODING_ERROR_MSG: &str = "DER encoding error";
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
 impl<C> FromPrivateKey for SecretKey<C>
 where
-    C: weierstrass::Curve + AlgorithmParameters + SecretValue,
+    C: weierstrass::Curve + AlgorithmParameters + SecretValue + ValidatePublicKey,
     C::Secret: Clone + Zeroize,
     FieldBytes<C>: From<C::Secret>,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
@@ -91,12 +91,13 @@ where
                 .into());
             }
 
-            // TODO(tarcieri): add validations for public key
-            sec1::EncodedPoint::<C>::from_bytes(&public_key_bitstring[1..]).map_err(|_| {
-                der::Error::from(der::ErrorKind::Value {
-                    tag: der::Tag::BitString,
-                })
-            })?;
+            sec1::EncodedPoint::<C>::from_bytes(&public_key_bitstring[1..])
+                .and_then(|public_key| C::validate_public_key(&public_key))
+                .map_err(|_| {
+                    der::Error::from(der::ErrorKind::Value {
+                        tag: der::Tag::BitString,
+                    })
+                })?;
 
             Ok(secret_key)
         })?;
@@ -164,7 +165,7 @@ where
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 impl<C> FromStr for SecretKey<C>
 where
-    C: weierstrass::Curve + AlgorithmParameters + SecretValue,
+    C: weierstrass::Curve + AlgorithmParameters + SecretValue + ValidatePublicKey,
     C::Secret: Clone + Zeroize,
     FieldBytes<C>: From<C::Secret>,
     UntaggedPointSize<C>: Add<U1> + ArrayLength<u8>,
@@ -0,0 +1,78 @@
+//! Tests for curves which only implement the byte-level traits, i.e. which
+//! don't provide arithmetic (e.g. curves backed by an HSM)
+
+#![cfg(all(feature = "pkcs8", feature = "zeroize"))]
+
+use elliptic_curve::{
+    consts::U32,
+    sec1::{self, ValidatePublicKey},
+    secret_key::{SecretBytes, SecretValue},
+    subtle::{Choice, CtOption},
+    weierstrass, AlgorithmParameters, Curve, FieldBytes,
+};
+use hex_literal::hex;
+use pkcs8::{FromPrivateKey, FromPublicKey};
+
+/// DER-encoded PKCS#8 private key
+const PKCS8_PRIVATE_KEY_DER: &[u8; 138] = include_bytes!("examples/pkcs8-private-key.der");
+
+/// DER-encoded PKCS#8 public key
+const PKCS8_PUBLIC_KEY_DER: &[u8; 91] = include_bytes!("examples/pkcs8-public-key.der");
+
+/// NIST P-256 without arithmetic
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+struct HsmCurve;
+
+impl Curve for HsmCurve {
+    type FieldSize = U32;
+}
+
+impl weierstrass::Curve for HsmCurve {}
+
+impl AlgorithmParameters for HsmCurve {
+    const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new(&[1, 2, 840, 10045, 3, 1, 7]);
+}
+
+impl SecretValue for HsmCurve {
+    type Secret = SecretBytes<Self>;
+
+    fn from_secret_bytes(bytes: &FieldBytes<Self>) -> CtOption<SecretBytes<Self>> {
+        CtOption::new((*bytes).into(), Choice::from(1))
+    }
+}
+
+impl ValidatePublicKey for HsmCurve {}
+
+type EncodedPoint = sec1::EncodedPoint<HsmCurve>;
+type SecretKey = elliptic_curve::SecretKey<HsmCurve>;
+
+#[test]
+fn decode_pkcs8_private_key_from_der() {
+    let secret_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    let expected_scalar = hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+    assert_eq!(secret_key.to_bytes().as_slice(), &expected_scalar[..]);
+}
+
+#[test]
+fn decode_pkcs8_public_key_from_der() {
+    let public_key = EncodedPoint::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let expected_sec1_point = hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+    assert_eq!(public_key.as_bytes(), &expected_sec1_point[..]);
+}
+
+#[test]
+fn reject_identity_public_key() {
+    assert!(HsmCurve::validate_public_key(&EncodedPoint::identity()).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn encode_pkcs8_public_key_to_der() {
+    use pkcs8::ToPublicKey;
+
+    let public_key = EncodedPoint::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(
+        public_key.to_public_key_der().as_ref(),
+        &PKCS8_PUBLIC_KEY_DER[..]
+    );
+}
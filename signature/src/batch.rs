@@ -0,0 +1,119 @@
+//! Batch signature verification
+
+use crate::{error::Error, verifier::Verifier, Signature};
+use core::fmt::{self, Display};
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Verify many signatures at once, using `Self` as the verifying key type.
+///
+/// Signature algorithms which support batch verification (e.g. Ed25519 or
+/// Schnorr signatures) can override [`BatchVerifier::verify_batch`] with an
+/// implementation which is faster than verifying each signature separately.
+/// The default implementation does exactly that.
+pub trait BatchVerifier<S: Signature>: Verifier<S> + Sized {
+    /// Verify a batch of `(message, key, signature)` items.
+    ///
+    /// Returns `Error` if any of the signatures is inauthentic, without
+    /// indicating which one.
+    fn verify_batch(items: &[(&[u8], &Self, &S)]) -> Result<(), Error> {
+        for (msg, key, signature) in items {
+            key.verify(msg, signature)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned by [`VerifyAccumulator::finish`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndexedError {
+    index: Option<usize>,
+}
+
+impl IndexedError {
+    /// Index of the first item (in order of being pushed) whose signature
+    /// failed to verify.
+    ///
+    /// Returns `None` if the batch was rejected as a whole, but every item
+    /// verified successfully on its own.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl Display for IndexedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "signature error in batch item {}", index),
+            None => f.write_str("signature error in batch"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexedError {}
+
+/// Queue of signatures to be verified together with [`BatchVerifier`].
+///
+/// Items are verified when [`VerifyAccumulator::finish`] is called. If batch
+/// verification fails, the items are verified one by one to find out which
+/// of them failed.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct VerifyAccumulator<'a, V, S> {
+    items: Vec<(&'a [u8], &'a V, &'a S)>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, V, S> VerifyAccumulator<'a, V, S>
+where
+    V: BatchVerifier<S>,
+    S: Signature,
+{
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Queue a signature for verification.
+    pub fn push(&mut self, msg: &'a [u8], key: &'a V, signature: &'a S) {
+        self.items.push((msg, key, signature));
+    }
+
+    /// Number of queued signatures.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Is the accumulator empty?
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verify all queued signatures.
+    pub fn finish(self) -> Result<(), IndexedError> {
+        if V::verify_batch(&self.items).is_ok() {
+            return Ok(());
+        }
+
+        let index = self
+            .items
+            .iter()
+            .position(|(msg, key, signature)| key.verify(msg, signature).is_err());
+
+        Err(IndexedError { index })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, V, S> Default for VerifyAccumulator<'a, V, S>
+where
+    V: BatchVerifier<S>,
+    S: Signature,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,195 @@
+//! Development-related functionality.
+//!
+//! Test macros in this module are driven by [`blobby`] files containing
+//! rows of `[signing key, verifying key, message, signature]`, and are
+//! intended for signature systems which produce deterministic signatures
+//! (e.g. Ed25519, ECDSA with RFC6979, RSASSA-PKCS1-v1_5).
+//!
+//! Signing and verifying keys are constructed from their serialized form
+//! using user-provided functions with `fn(&[u8]) -> T` signature, since this
+//! crate does not define traits for key serialization.
+
+pub use blobby;
+
+/// Define test which signs messages from test vectors, compares resulting
+/// signatures with expected ones and verifies them.
+///
+/// Also checks that signatures round-trip through their byte representation.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! sign_verify_test {
+    ($name:ident, $test_name:expr, $sig:ty, $signer_new:expr, $verifier_new:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            use $crate::dev::blobby::Blob4Iterator;
+            use $crate::{Signature, Signer, Verifier};
+
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            for (i, row) in Blob4Iterator::new(data).unwrap().enumerate() {
+                let [sk, vk, msg, expected] = row.unwrap();
+                let signer = $signer_new(sk);
+                let verifier = $verifier_new(vk);
+
+                let sig: $sig = signer.try_sign(msg).unwrap_or_else(|e| {
+                    panic!("Failed test №{}: signing error: {}", i, e);
+                });
+                if sig.as_bytes() != expected {
+                    panic!(
+                        "\n\
+                         Failed test №{}: signature mismatch\n\
+                         message:\t{:?}\n\
+                         expected:\t{:?}\n\
+                         actual:\t{:?}\n",
+                        i,
+                        msg,
+                        expected,
+                        sig.as_bytes(),
+                    );
+                }
+
+                let decoded = <$sig>::from_bytes(expected).unwrap_or_else(|e| {
+                    panic!("Failed test №{}: signature decoding error: {}", i, e);
+                });
+                if decoded.as_bytes() != expected {
+                    panic!("Failed test №{}: signature encoding round-trip", i);
+                }
+
+                if verifier.verify(msg, &decoded).is_err() {
+                    panic!("Failed test №{}: verification of valid signature", i);
+                }
+            }
+        }
+    };
+}
+
+/// Define test which signs prehashed messages from test vectors using
+/// [`DigestSigner`][crate::DigestSigner] and verifies them using
+/// [`DigestVerifier`][crate::DigestVerifier].
+#[cfg(feature = "digest-preview")]
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "dev", feature = "digest-preview"))))]
+macro_rules! digest_signer_test {
+    (
+        $name:ident,
+        $test_name:expr,
+        $sig:ty,
+        $digest:ty,
+        $signer_new:expr,
+        $verifier_new:expr $(,)?
+    ) => {
+        #[test]
+        fn $name() {
+            use $crate::dev::blobby::Blob4Iterator;
+            use $crate::digest::Digest;
+            use $crate::{DigestSigner, DigestVerifier, Signature};
+
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            for (i, row) in Blob4Iterator::new(data).unwrap().enumerate() {
+                let [sk, vk, msg, expected] = row.unwrap();
+                let signer = $signer_new(sk);
+                let verifier = $verifier_new(vk);
+
+                let sig: $sig = signer
+                    .try_sign_digest(<$digest>::new().chain(msg))
+                    .unwrap_or_else(|e| {
+                        panic!("Failed test №{}: signing error: {}", i, e);
+                    });
+                if sig.as_bytes() != expected {
+                    panic!(
+                        "\n\
+                         Failed test №{}: signature mismatch\n\
+                         message:\t{:?}\n\
+                         expected:\t{:?}\n\
+                         actual:\t{:?}\n",
+                        i,
+                        msg,
+                        expected,
+                        sig.as_bytes(),
+                    );
+                }
+
+                let res = verifier.verify_digest(<$digest>::new().chain(msg), &sig);
+                if res.is_err() {
+                    panic!("Failed test №{}: verification of valid signature", i);
+                }
+            }
+        }
+    };
+}
+
+/// Define test which checks that signatures from test vectors are rejected
+/// when verified against a modified message.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! wrong_message_test {
+    ($name:ident, $test_name:expr, $sig:ty, $verifier_new:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            use $crate::dev::blobby::Blob4Iterator;
+            use $crate::{Signature, Verifier};
+
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            for (i, row) in Blob4Iterator::new(data).unwrap().enumerate() {
+                let [_, vk, msg, sig] = row.unwrap();
+                let verifier = $verifier_new(vk);
+                let sig = <$sig>::from_bytes(sig).unwrap();
+
+                let mut extended = msg.to_vec();
+                extended.push(0);
+                if verifier.verify(&extended, &sig).is_ok() {
+                    panic!("Failed test №{}: extended message must be rejected", i);
+                }
+
+                for j in 0..msg.len() {
+                    let mut tampered = msg.to_vec();
+                    tampered[j] ^= 1;
+                    if verifier.verify(&tampered, &sig).is_ok() {
+                        panic!(
+                            "Failed test №{}: message modified at byte {} must be rejected",
+                            i, j,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Define test which checks that signatures from test vectors are rejected
+/// when verified using a verifying key from another test vector.
+///
+/// Rows which share the verifying key with the following row are skipped.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! wrong_key_test {
+    ($name:ident, $test_name:expr, $sig:ty, $verifier_new:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            use $crate::dev::blobby::Blob4Iterator;
+            use $crate::{Signature, Verifier};
+
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            let rows: Vec<_> = Blob4Iterator::new(data)
+                .unwrap()
+                .map(|row| row.unwrap())
+                .collect();
+            assert!(rows.len() > 1, "at least two test vectors are required");
+
+            for (i, [_, vk, msg, sig]) in rows.iter().enumerate() {
+                let [_, other_vk, _, _] = rows[(i + 1) % rows.len()];
+                if vk == &other_vk {
+                    continue;
+                }
+
+                let verifier = $verifier_new(other_vk);
+                let sig = <$sig>::from_bytes(sig).unwrap();
+                if verifier.verify(msg, &sig).is_ok() {
+                    panic!(
+                        "Failed test №{}: signature under wrong key must be rejected",
+                        i
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,103 @@
+//! Serialization of verifying keys in negotiable formats
+
+use crate::error::Error;
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use std::vec::Vec;
+
+/// Encoding formats for verifying keys.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum KeyFormat {
+    /// Algorithm-specific raw encoding, e.g. a SEC1 encoded point for ECDSA
+    /// or 32 bytes for Ed25519.
+    Raw,
+
+    /// DER encoded X.509 `SubjectPublicKeyInfo` (RFC 5280).
+    SpkiDer,
+
+    /// PEM encoded X.509 `SubjectPublicKeyInfo`, i.e. with a
+    /// `-----BEGIN PUBLIC KEY-----` header (RFC 7468).
+    Pem,
+
+    /// JSON Web Key (RFC 7517), serialized as UTF-8 JSON.
+    Jwk,
+}
+
+impl KeyFormat {
+    /// Get the identifier of this format, e.g. for use in configuration files
+    /// or protocol messages.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyFormat::Raw => "raw",
+            KeyFormat::SpkiDer => "spki-der",
+            KeyFormat::Pem => "pem",
+            KeyFormat::Jwk => "jwk",
+        }
+    }
+
+    /// Pick the first format from `preferred` which is also contained in
+    /// `supported`.
+    pub fn negotiate(preferred: &[KeyFormat], supported: &[KeyFormat]) -> Option<KeyFormat> {
+        preferred
+            .iter()
+            .find(|format| supported.contains(format))
+            .cloned()
+    }
+}
+
+impl Display for KeyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KeyFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "raw" => Ok(KeyFormat::Raw),
+            "spki-der" => Ok(KeyFormat::SpkiDer),
+            "pem" => Ok(KeyFormat::Pem),
+            "jwk" => Ok(KeyFormat::Jwk),
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+/// Import and export of verifying keys in several [`KeyFormat`]s.
+///
+/// This provides a single entry point for key management tooling which is
+/// generic over signature algorithms. Implementations will typically gate
+/// support for individual formats behind their own cargo features, and
+/// advertise the formats available in a given build via
+/// [`SUPPORTED_FORMATS`][KeyEncoding::SUPPORTED_FORMATS].
+pub trait KeyEncoding: Sized {
+    /// Formats supported by this key type, in order of preference.
+    const SUPPORTED_FORMATS: &'static [KeyFormat];
+
+    /// Encode this key in the given format.
+    ///
+    /// Returns an error if the format is not supported.
+    fn to_encoded(&self, format: KeyFormat) -> Result<Vec<u8>, Error>;
+
+    /// Decode a key from the given format.
+    ///
+    /// Returns an error if the format is not supported or `bytes` are not
+    /// a valid encoding of a key in this format.
+    fn from_encoded(format: KeyFormat, bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Is the given format supported by this key type?
+    fn supports_format(format: KeyFormat) -> bool {
+        Self::SUPPORTED_FORMATS.contains(&format)
+    }
+
+    /// Pick the first format from `preferred` (e.g. formats accepted by a
+    /// peer, in its order of preference) which is supported by this key type.
+    fn negotiate_format(preferred: &[KeyFormat]) -> Option<KeyFormat> {
+        KeyFormat::negotiate(preferred, Self::SUPPORTED_FORMATS)
+    }
+}
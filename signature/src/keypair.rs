@@ -0,0 +1,38 @@
+//! Signing keys which are paired with a verifying key
+
+use crate::{Signature, Verifier};
+
+/// Signing keypair with an associated verifying key.
+///
+/// This represents a type which holds both a signing key and a verifying key.
+pub trait Keypair<S: Signature>: AsRef<Self::VerifyingKey> {
+    /// Verifying key type for this keypair.
+    type VerifyingKey: Verifier<S>;
+
+    /// Get the verifying key which can verify signatures produced by the
+    /// signing key portion of this keypair.
+    fn verifying_key(&self) -> &Self::VerifyingKey {
+        self.as_ref()
+    }
+}
+
+/// Keypair whose provenance is backed by attestation evidence, e.g. keys held
+/// in a TPM, secure enclave, HSM, or security key.
+///
+/// Attestation evidence (e.g. a certificate chain or signed statement issued
+/// by the device) vouches that the verifying key belongs to a key generated
+/// and held in hardware. This trait exposes the evidence as an opaque blob
+/// along with an identifier of its format, so it can be passed along with
+/// the verifying key (e.g. to a WebAuthn relying party or an enterprise
+/// policy engine) without this crate knowing about any particular format.
+///
+/// Parsing and verifying the evidence is left to the consumer.
+pub trait AttestedKeypair<S: Signature>: Keypair<S> {
+    /// Identifier of the attestation format, e.g. a WebAuthn attestation
+    /// statement format identifier such as `"packed"` or `"tpm"`.
+    fn attestation_format(&self) -> &str;
+
+    /// Serialized attestation evidence for the verifying key, encoded as
+    /// specified by the [attestation format][AttestedKeypair::attestation_format].
+    fn attestation(&self) -> &[u8];
+}
@@ -168,6 +168,10 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "dev")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+pub mod dev;
+
 #[cfg(feature = "derive-preview")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive-preview")))]
 pub use signature_derive::{Signer, Verifier};
@@ -178,9 +182,20 @@ pub use digest;
 #[cfg(feature = "rand-preview")]
 pub use rand_core;
 
+mod batch;
+#[cfg(feature = "std")]
+mod encoding;
 mod error;
+mod keypair;
+mod migration;
 mod signature;
 mod signer;
 mod verifier;
 
-pub use crate::{error::*, signature::*, signer::*, verifier::*};
+pub use crate::{
+    batch::*, error::*, keypair::*, migration::*, signature::*, signer::*, verifier::*,
+};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::encoding::{KeyEncoding, KeyFormat};
@@ -0,0 +1,168 @@
+//! Verification during signature algorithm migrations
+
+use crate::{error::Error, verifier::Verifier, Signature};
+
+#[cfg(feature = "digest-preview")]
+use crate::{digest::Digest, verifier::DigestVerifier};
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Signature produced by either the legacy algorithm `S1` or the current
+/// algorithm `S2` during a migration between the two.
+///
+/// The untagged encoding (i.e. [`AsRef`] and [`Signature::from_bytes`]) is
+/// the encoding of the inner signature. Parsing it tries `S2` first and
+/// falls back to `S1`, which is only unambiguous if the encodings of the two
+/// algorithms can't be confused, e.g. because they differ in length. When
+/// that isn't the case, protocols should either transmit the algorithm out
+/// of band or use the tagged encoding provided by
+/// [`EitherSignature::from_tagged_bytes`], which prefixes the signature
+/// with a [`EitherSignature::tag`] byte.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EitherSignature<S1, S2> {
+    /// Signature produced by the legacy algorithm.
+    Legacy(S1),
+
+    /// Signature produced by the current algorithm.
+    Current(S2),
+}
+
+impl<S1: Signature, S2: Signature> EitherSignature<S1, S2> {
+    /// Tag byte identifying legacy signatures in the tagged encoding.
+    pub const LEGACY_TAG: u8 = 0x01;
+
+    /// Tag byte identifying current signatures in the tagged encoding.
+    pub const CURRENT_TAG: u8 = 0x02;
+
+    /// Parse a signature of the algorithm identified by `tag`.
+    pub fn from_tag_and_bytes(tag: u8, bytes: &[u8]) -> Result<Self, Error> {
+        if tag == Self::LEGACY_TAG {
+            S1::from_bytes(bytes).map(EitherSignature::Legacy)
+        } else if tag == Self::CURRENT_TAG {
+            S2::from_bytes(bytes).map(EitherSignature::Current)
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Parse a signature in the tagged encoding, i.e. `tag || signature`.
+    pub fn from_tagged_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.split_first() {
+            Some((&tag, signature)) => Self::from_tag_and_bytes(tag, signature),
+            None => Err(Error::new()),
+        }
+    }
+
+    /// Serialize this signature in the tagged encoding.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_tagged_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.as_ref().len());
+        bytes.push(self.tag());
+        bytes.extend_from_slice(self.as_ref());
+        bytes
+    }
+
+    /// Get the tag byte identifying the algorithm of this signature.
+    pub fn tag(&self) -> u8 {
+        match self {
+            EitherSignature::Legacy(_) => Self::LEGACY_TAG,
+            EitherSignature::Current(_) => Self::CURRENT_TAG,
+        }
+    }
+
+    /// Is this a signature produced by the legacy algorithm?
+    pub fn is_legacy(&self) -> bool {
+        match self {
+            EitherSignature::Legacy(_) => true,
+            EitherSignature::Current(_) => false,
+        }
+    }
+}
+
+impl<S1: Signature, S2: Signature> AsRef<[u8]> for EitherSignature<S1, S2> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            EitherSignature::Legacy(signature) => signature.as_ref(),
+            EitherSignature::Current(signature) => signature.as_ref(),
+        }
+    }
+}
+
+impl<S1: Signature, S2: Signature> Signature for EitherSignature<S1, S2> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        S2::from_bytes(bytes)
+            .map(EitherSignature::Current)
+            .or_else(|_| S1::from_bytes(bytes).map(EitherSignature::Legacy))
+    }
+}
+
+/// [`Verifier`] which accepts signatures of both the legacy algorithm
+/// (verified with `V1`) and the current algorithm (verified with `V2`).
+///
+/// This allows services to accept both kinds of signatures while signers
+/// are rolled over to the new algorithm. Once the rollout is complete, the
+/// legacy algorithm is dropped by replacing `MigrationVerifier<V1, V2>` with
+/// `V2` and [`EitherSignature<S1, S2>`] with `S2`, so the compiler points
+/// out every place which still handles legacy signatures.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MigrationVerifier<V1, V2> {
+    legacy: V1,
+    current: V2,
+}
+
+impl<V1, V2> MigrationVerifier<V1, V2> {
+    /// Create a verifier from the legacy and current verifying keys.
+    pub fn new(legacy: V1, current: V2) -> Self {
+        Self { legacy, current }
+    }
+
+    /// Borrow the legacy verifier.
+    pub fn legacy(&self) -> &V1 {
+        &self.legacy
+    }
+
+    /// Borrow the current verifier.
+    pub fn current(&self) -> &V2 {
+        &self.current
+    }
+
+    /// Drop the legacy verifier, returning the current one.
+    pub fn into_current(self) -> V2 {
+        self.current
+    }
+}
+
+impl<S1, S2, V1, V2> Verifier<EitherSignature<S1, S2>> for MigrationVerifier<V1, V2>
+where
+    S1: Signature,
+    S2: Signature,
+    V1: Verifier<S1>,
+    V2: Verifier<S2>,
+{
+    fn verify(&self, msg: &[u8], signature: &EitherSignature<S1, S2>) -> Result<(), Error> {
+        match signature {
+            EitherSignature::Legacy(signature) => self.legacy.verify(msg, signature),
+            EitherSignature::Current(signature) => self.current.verify(msg, signature),
+        }
+    }
+}
+
+#[cfg(feature = "digest-preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest-preview")))]
+impl<D, S1, S2, V1, V2> DigestVerifier<D, EitherSignature<S1, S2>> for MigrationVerifier<V1, V2>
+where
+    D: Digest,
+    S1: Signature,
+    S2: Signature,
+    V1: DigestVerifier<D, S1>,
+    V2: DigestVerifier<D, S2>,
+{
+    fn verify_digest(&self, digest: D, signature: &EitherSignature<S1, S2>) -> Result<(), Error> {
+        match signature {
+            EitherSignature::Legacy(signature) => self.legacy.verify_digest(digest, signature),
+            EitherSignature::Current(signature) => self.current.verify_digest(digest, signature),
+        }
+    }
+}
@@ -66,3 +66,43 @@ pub trait PrehashSignature: Signature {
     /// Preferred `Digest` algorithm to use when computing this signature type.
     type Digest: digest::Digest;
 }
+
+/// Signatures which have a canonical encoding, e.g. ECDSA signatures with a
+/// "low-S" value or Ed25519 signatures with a reduced `S` scalar.
+///
+/// Protocols which need signatures to be non-malleable (e.g. consensus
+/// systems, which hash signed transactions including their signatures) can
+/// reject non-canonical signatures using [`StrictVerifier`][crate::StrictVerifier].
+pub trait CanonicalSignature: Signature {
+    /// Is this signature encoded canonically?
+    fn is_canonical(&self) -> bool;
+
+    /// Convert this signature to its canonical form.
+    ///
+    /// The canonical form of a valid signature is a valid signature for the
+    /// same message and key.
+    fn normalize(&self) -> Self;
+}
+
+/// Marker trait for signature algorithms believed to be secure against
+/// attacks by quantum computers, e.g. Dilithium or SPHINCS+.
+///
+/// This trait is impl'd by "provider" crates on their [`Signature`] types
+/// (and, where useful, on their signing and verifying key types), allowing
+/// applications to require post-quantum security at compile time:
+///
+/// ```ignore
+/// fn sign_document<S: Signature + PostQuantum>(signer: &impl Signer<S>, doc: &[u8]) -> S {
+///     signer.sign(doc)
+/// }
+/// ```
+pub trait PostQuantum {}
+
+/// Marker trait for hybrid signature algorithms which combine a classical
+/// and a post-quantum algorithm, and remain secure as long as either of
+/// them is unbroken.
+///
+/// Hybrid algorithms are post-quantum secure, so a `PostQuantum` bound
+/// accepts both purely post-quantum and hybrid algorithms, while a `Hybrid`
+/// bound accepts only the latter.
+pub trait Hybrid: PostQuantum {}
@@ -107,3 +107,29 @@ where
         digest: D,
     ) -> Result<S, Error>;
 }
+
+/// Sign the provided message using a signature scheme with message recovery
+/// (e.g. ISO/IEC 9796-2), where part of the message is embedded in the
+/// signature itself.
+///
+/// Use [`VerifierWithRecovery`][crate::VerifierWithRecovery] to verify such
+/// signatures and recover the embedded part of the message.
+pub trait SignerWithRecovery<S: Signature> {
+    /// Sign the given message, embedding as much of its leading bytes in the
+    /// signature as the scheme permits.
+    ///
+    /// Returns the signature along with the number of leading message bytes
+    /// which were embedded in it. The remaining (non-recoverable) bytes,
+    /// i.e. `&msg[n..]`, need to be transmitted along with the signature.
+    ///
+    /// Panics in the event of a signing error.
+    fn sign_with_recovery(&self, msg: &[u8]) -> (S, usize) {
+        self.try_sign_with_recovery(msg)
+            .expect("signature operation failed")
+    }
+
+    /// Attempt to sign the given message, returning a digital signature and
+    /// the number of embedded message bytes on success, or an error if
+    /// something went wrong.
+    fn try_sign_with_recovery(&self, msg: &[u8]) -> Result<(S, usize), Error>;
+}
@@ -1,6 +1,6 @@
 //! Trait for verifying digital signatures
 
-use crate::{error::Error, Signature};
+use crate::{error::Error, CanonicalSignature, Signature};
 
 #[cfg(feature = "digest-preview")]
 use crate::digest::Digest;
@@ -44,3 +44,83 @@ where
     /// Verify the signature against the given [`Digest`] output.
     fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error>;
 }
+
+/// Verify signatures produced by a scheme with message recovery, recovering
+/// the part of the message embedded in the signature.
+///
+/// See [`SignerWithRecovery`][crate::SignerWithRecovery].
+pub trait VerifierWithRecovery<S: Signature> {
+    /// Verify the signature for the given non-recoverable part of the
+    /// message (which may be empty), and write the recovered part of the
+    /// message into `recovered`.
+    ///
+    /// On success returns the recovered message bytes, which precede
+    /// `nonrecoverable` in the full signed message. Returns `Error` if the
+    /// signature is inauthentic or `recovered` is too small to hold the
+    /// recovered message.
+    fn verify_with_recovery<'a>(
+        &self,
+        nonrecoverable: &[u8],
+        signature: &S,
+        recovered: &'a mut [u8],
+    ) -> Result<&'a [u8], Error>;
+}
+
+/// [`Verifier`] wrapper which rejects signatures that aren't encoded
+/// canonically, before verifying them with the inner verifier `V`.
+///
+/// This expresses a malleability policy in a single place: any code which
+/// verifies signatures through a `StrictVerifier` only accepts canonical
+/// signatures. See [`CanonicalSignature`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StrictVerifier<V> {
+    verifier: V,
+}
+
+impl<V> StrictVerifier<V> {
+    /// Wrap the given verifier.
+    pub fn new(verifier: V) -> Self {
+        Self { verifier }
+    }
+
+    /// Borrow the inner verifier.
+    pub fn inner(&self) -> &V {
+        &self.verifier
+    }
+
+    /// Unwrap the inner verifier.
+    pub fn into_inner(self) -> V {
+        self.verifier
+    }
+}
+
+impl<S, V> Verifier<S> for StrictVerifier<V>
+where
+    S: CanonicalSignature,
+    V: Verifier<S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        if !signature.is_canonical() {
+            return Err(Error::new());
+        }
+
+        self.verifier.verify(msg, signature)
+    }
+}
+
+#[cfg(feature = "digest-preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest-preview")))]
+impl<D, S, V> DigestVerifier<D, S> for StrictVerifier<V>
+where
+    D: Digest,
+    S: CanonicalSignature,
+    V: DigestVerifier<D, S>,
+{
+    fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error> {
+        if !signature.is_canonical() {
+            return Err(Error::new());
+        }
+
+        self.verifier.verify_digest(digest, signature)
+    }
+}
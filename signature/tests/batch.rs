@@ -0,0 +1,61 @@
+/// Tests for `VerifyAccumulator`
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use signature::{BatchVerifier, Error, Signature, Verifier, VerifyAccumulator};
+
+    /// Dummy signature which contains the key and the message length
+    #[derive(Debug)]
+    struct DummySignature([u8; 2]);
+
+    impl Signature for DummySignature {
+        fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+            match bytes {
+                [key, len] => Ok(DummySignature([*key, *len])),
+                _ => Err(Error::new()),
+            }
+        }
+    }
+
+    impl AsRef<[u8]> for DummySignature {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    /// Dummy verifying key
+    struct DummyVerifier(u8);
+
+    impl Verifier<DummySignature> for DummyVerifier {
+        fn verify(&self, msg: &[u8], signature: &DummySignature) -> Result<(), Error> {
+            if signature.0 == [self.0, msg.len() as u8] {
+                Ok(())
+            } else {
+                Err(Error::new())
+            }
+        }
+    }
+
+    impl BatchVerifier<DummySignature> for DummyVerifier {}
+
+    #[test]
+    fn accumulator() {
+        let keys = [DummyVerifier(1), DummyVerifier(2)];
+        let sigs = [
+            DummySignature([1, 3]),
+            DummySignature([2, 5]),
+            DummySignature([1, 4]),
+        ];
+
+        let mut acc = VerifyAccumulator::new();
+        acc.push(b"abc", &keys[0], &sigs[0]);
+        acc.push(b"hello", &keys[1], &sigs[1]);
+        assert_eq!(acc.len(), 2);
+        assert!(acc.finish().is_ok());
+
+        let mut acc = VerifyAccumulator::new();
+        acc.push(b"abc", &keys[0], &sigs[0]);
+        acc.push(b"hello", &keys[0], &sigs[1]);
+        acc.push(b"abcd", &keys[0], &sigs[2]);
+        assert_eq!(acc.finish().unwrap_err().index(), Some(1));
+    }
+}
@@ -0,0 +1,71 @@
+/// Tests for `KeyEncoding`
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use signature::{Error, KeyEncoding, KeyFormat};
+
+    /// Dummy verifying key which supports raw and hex-in-JWK encodings
+    #[derive(Debug, Eq, PartialEq)]
+    struct DummyKey([u8; 2]);
+
+    impl KeyEncoding for DummyKey {
+        const SUPPORTED_FORMATS: &'static [KeyFormat] = &[KeyFormat::Jwk, KeyFormat::Raw];
+
+        fn to_encoded(&self, format: KeyFormat) -> Result<Vec<u8>, Error> {
+            match format {
+                KeyFormat::Raw => Ok(self.0.to_vec()),
+                KeyFormat::Jwk => Ok(format!(
+                    r#"{{"kty":"dummy","x":"{:02x}{:02x}"}}"#,
+                    self.0[0], self.0[1]
+                )
+                .into_bytes()),
+                _ => Err(Error::new()),
+            }
+        }
+
+        fn from_encoded(format: KeyFormat, bytes: &[u8]) -> Result<Self, Error> {
+            match (format, bytes) {
+                (KeyFormat::Raw, [a, b]) => Ok(DummyKey([*a, *b])),
+                _ => Err(Error::new()),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = DummyKey([1, 2]);
+        let raw = key.to_encoded(KeyFormat::Raw).unwrap();
+        assert_eq!(DummyKey::from_encoded(KeyFormat::Raw, &raw).unwrap(), key);
+        assert_eq!(
+            key.to_encoded(KeyFormat::Jwk).unwrap(),
+            br#"{"kty":"dummy","x":"0102"}"#
+        );
+        assert!(key.to_encoded(KeyFormat::Pem).is_err());
+    }
+
+    #[test]
+    fn negotiation() {
+        assert!(DummyKey::supports_format(KeyFormat::Raw));
+        assert!(!DummyKey::supports_format(KeyFormat::SpkiDer));
+        assert_eq!(
+            DummyKey::negotiate_format(&[KeyFormat::Pem, KeyFormat::Raw, KeyFormat::Jwk]),
+            Some(KeyFormat::Raw)
+        );
+        assert_eq!(
+            DummyKey::negotiate_format(&[KeyFormat::Pem, KeyFormat::SpkiDer]),
+            None
+        );
+    }
+
+    #[test]
+    fn format_identifiers() {
+        for &format in &[
+            KeyFormat::Raw,
+            KeyFormat::SpkiDer,
+            KeyFormat::Pem,
+            KeyFormat::Jwk,
+        ] {
+            assert_eq!(format.as_str().parse::<KeyFormat>().unwrap(), format);
+        }
+        assert!("der".parse::<KeyFormat>().is_err());
+    }
+}
@@ -0,0 +1,106 @@
+/// Tests for `MigrationVerifier`
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use signature::{EitherSignature, Error, MigrationVerifier, Signature, Verifier};
+
+    /// Dummy signature which contains the key and the message length
+    #[derive(Debug, Eq, PartialEq)]
+    struct LegacySignature([u8; 2]);
+
+    impl Signature for LegacySignature {
+        fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+            match bytes {
+                [key, len] => Ok(LegacySignature([*key, *len])),
+                _ => Err(Error::new()),
+            }
+        }
+    }
+
+    impl AsRef<[u8]> for LegacySignature {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    /// Dummy signature which contains the key, the message length and the
+    /// first byte of the message
+    #[derive(Debug, Eq, PartialEq)]
+    struct CurrentSignature([u8; 3]);
+
+    impl Signature for CurrentSignature {
+        fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+            match bytes {
+                [key, len, first] => Ok(CurrentSignature([*key, *len, *first])),
+                _ => Err(Error::new()),
+            }
+        }
+    }
+
+    impl AsRef<[u8]> for CurrentSignature {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    /// Dummy legacy verifying key
+    struct LegacyVerifier(u8);
+
+    impl Verifier<LegacySignature> for LegacyVerifier {
+        fn verify(&self, msg: &[u8], signature: &LegacySignature) -> Result<(), Error> {
+            if signature.0 == [self.0, msg.len() as u8] {
+                Ok(())
+            } else {
+                Err(Error::new())
+            }
+        }
+    }
+
+    /// Dummy current verifying key
+    struct CurrentVerifier(u8);
+
+    impl Verifier<CurrentSignature> for CurrentVerifier {
+        fn verify(&self, msg: &[u8], signature: &CurrentSignature) -> Result<(), Error> {
+            if signature.0 == [self.0, msg.len() as u8, msg[0]] {
+                Ok(())
+            } else {
+                Err(Error::new())
+            }
+        }
+    }
+
+    type Sig = EitherSignature<LegacySignature, CurrentSignature>;
+
+    #[test]
+    fn verify_both_algorithms() {
+        let verifier = MigrationVerifier::new(LegacyVerifier(1), CurrentVerifier(2));
+
+        let legacy = Sig::from_bytes(&[1, 3]).unwrap();
+        assert!(legacy.is_legacy());
+        assert!(verifier.verify(b"abc", &legacy).is_ok());
+        assert!(verifier.verify(b"abcd", &legacy).is_err());
+
+        let current = Sig::from_bytes(&[2, 3, b'a']).unwrap();
+        assert!(!current.is_legacy());
+        assert!(verifier.verify(b"abc", &current).is_ok());
+        assert!(verifier.verify(b"xyz", &current).is_err());
+
+        assert!(Sig::from_bytes(&[1]).is_err());
+    }
+
+    #[test]
+    fn tagged_encoding() {
+        let legacy = Sig::Legacy(LegacySignature([1, 3]));
+        let tagged = legacy.to_tagged_bytes();
+        assert_eq!(tagged, [Sig::LEGACY_TAG, 1, 3]);
+        assert_eq!(Sig::from_tagged_bytes(&tagged).unwrap(), legacy);
+
+        let current = Sig::Current(CurrentSignature([2, 3, b'a']));
+        let tagged = current.to_tagged_bytes();
+        assert_eq!(tagged, [Sig::CURRENT_TAG, 2, 3, b'a']);
+        assert_eq!(Sig::from_tagged_bytes(&tagged).unwrap(), current);
+
+        assert!(Sig::from_tagged_bytes(&[Sig::LEGACY_TAG, 2, 3, b'a']).is_err());
+        assert!(Sig::from_tagged_bytes(&[0xff, 1, 3]).is_err());
+        assert!(Sig::from_tagged_bytes(&[]).is_err());
+    }
+}
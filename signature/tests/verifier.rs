@@ -0,0 +1,144 @@
+/// Tests for `StrictVerifier`
+#[cfg(test)]
+mod tests {
+    use signature::{CanonicalSignature, Error, Signature, StrictVerifier, Verifier};
+
+    /// Dummy signature which contains the key and a 7-bit tag of the
+    /// message. The high bit of the tag is ignored by verification, so
+    /// signatures with it set are valid but non-canonical.
+    #[derive(Debug, Eq, PartialEq)]
+    struct DummySignature([u8; 2]);
+
+    impl Signature for DummySignature {
+        fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+            match bytes {
+                [key, tag] => Ok(DummySignature([*key, *tag])),
+                _ => Err(Error::new()),
+            }
+        }
+    }
+
+    impl AsRef<[u8]> for DummySignature {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl CanonicalSignature for DummySignature {
+        fn is_canonical(&self) -> bool {
+            self.0[1] & 0x80 == 0
+        }
+
+        fn normalize(&self) -> Self {
+            DummySignature([self.0[0], self.0[1] & 0x7f])
+        }
+    }
+
+    /// Dummy verifying key which accepts non-canonical signatures
+    #[derive(Debug, Default)]
+    struct DummyKey(u8);
+
+    impl DummyKey {
+        /// Sign a message, tagged with its length.
+        fn sign(&self, msg: &[u8]) -> DummySignature {
+            DummySignature([self.0, msg.len() as u8 & 0x7f])
+        }
+
+        fn check(&self, tag: u8, signature: &DummySignature) -> Result<(), Error> {
+            if signature.0[0] == self.0 && signature.0[1] & 0x7f == tag & 0x7f {
+                Ok(())
+            } else {
+                Err(Error::new())
+            }
+        }
+    }
+
+    impl Verifier<DummySignature> for DummyKey {
+        fn verify(&self, msg: &[u8], signature: &DummySignature) -> Result<(), Error> {
+            self.check(msg.len() as u8, signature)
+        }
+    }
+
+    const MSG: &[u8] = b"strict";
+
+    fn non_canonical(signature: &DummySignature) -> DummySignature {
+        DummySignature([signature.0[0], signature.0[1] | 0x80])
+    }
+
+    #[test]
+    fn rejects_non_canonical() {
+        let key = DummyKey(1);
+        let signature = key.sign(MSG);
+        let malleated = non_canonical(&signature);
+        assert!(!malleated.is_canonical());
+        assert_eq!(malleated.normalize(), signature);
+
+        // the lenient verifier accepts both encodings
+        assert!(key.verify(MSG, &signature).is_ok());
+        assert!(key.verify(MSG, &malleated).is_ok());
+
+        let strict = StrictVerifier::new(key);
+        assert!(strict.verify(MSG, &signature).is_ok());
+        assert!(strict.verify(MSG, &malleated).is_err());
+        assert!(strict.verify(MSG, &malleated.normalize()).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let strict = StrictVerifier::new(DummyKey(1));
+        assert!(strict.verify(MSG, &DummyKey(2).sign(MSG)).is_err());
+        assert!(strict.verify(b"other", &DummyKey(1).sign(MSG)).is_err());
+    }
+
+    #[cfg(feature = "digest-preview")]
+    mod digest {
+        use super::{non_canonical, DummyKey, DummySignature, MSG};
+        use sha2::{Digest, Sha256};
+        use signature::{CanonicalSignature, DigestVerifier, Error, StrictVerifier};
+
+        impl DummyKey {
+            /// Sign a prehashed message, tagged with the first byte of its
+            /// digest.
+            fn sign_digest(&self, digest: Sha256) -> DummySignature {
+                DummySignature([self.0, digest.finalize()[0] & 0x7f])
+            }
+        }
+
+        impl DigestVerifier<Sha256, DummySignature> for DummyKey {
+            fn verify_digest(
+                &self,
+                digest: Sha256,
+                signature: &DummySignature,
+            ) -> Result<(), Error> {
+                self.check(digest.finalize()[0], signature)
+            }
+        }
+
+        #[test]
+        fn rejects_non_canonical() {
+            let key = DummyKey(1);
+            let signature = key.sign_digest(Sha256::new().chain(MSG));
+            let malleated = non_canonical(&signature);
+            assert!(!malleated.is_canonical());
+
+            // the lenient verifier accepts both encodings
+            assert!(key
+                .verify_digest(Sha256::new().chain(MSG), &signature)
+                .is_ok());
+            assert!(key
+                .verify_digest(Sha256::new().chain(MSG), &malleated)
+                .is_ok());
+
+            let strict = StrictVerifier::new(key);
+            assert!(strict
+                .verify_digest(Sha256::new().chain(MSG), &signature)
+                .is_ok());
+            assert!(strict
+                .verify_digest(Sha256::new().chain(MSG), &malleated)
+                .is_err());
+            assert!(strict
+                .verify_digest(Sha256::new().chain(b"other"), &signature)
+                .is_err());
+        }
+    }
+}